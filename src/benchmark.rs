@@ -0,0 +1,273 @@
+use crate::formats::har::HarCapture;
+use crate::outcomes::HtrsError;
+use crate::request::{build_request, execute_request, Exchange, PreparedRequest};
+use reqwest::blocking::Client;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Sends the same request repeatedly from several threads sharing one client, stopping once
+/// `repeat` requests have been sent or `duration` has passed, whichever comes first
+pub struct Benchmark {
+    pub request: PreparedRequest,
+    pub repeat: Option<u64>,
+    pub duration: Option<Duration>,
+    pub concurrency: u64,
+    /// Requests per second across all threads, otherwise requests are sent as fast as possible
+    pub rate: Option<u64>,
+    pub json_report: bool,
+    /// Records every request that got a response
+    pub har: Option<HarCapture>,
+}
+
+enum Outcome {
+    Status(u16),
+    Error(&'static str),
+}
+
+struct Sample {
+    outcome: Outcome,
+    latency: Duration,
+    /// Only kept when the benchmark is recorded to a HAR file
+    exchange: Option<Exchange>,
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct BenchmarkReport {
+    pub requests: u64,
+    pub succeeded: u64,
+    pub failed: u64,
+    pub elapsed_seconds: f64,
+    pub requests_per_second: f64,
+    pub statuses: BTreeMap<u16, u64>,
+    pub errors: BTreeMap<String, u64>,
+    pub latency_ms: Option<LatencySummary>,
+}
+
+/// Latencies of the requests that got a response, in milliseconds
+#[derive(Serialize, PartialEq, Debug)]
+pub struct LatencySummary {
+    pub min: f64,
+    pub mean: f64,
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+    pub max: f64,
+}
+
+impl Benchmark {
+    pub fn run(&self) -> Result<(), HtrsError> {
+        let client = Client::new();
+        let next_index = AtomicU64::new(0);
+        let started = Instant::now();
+        let deadline = self.duration.map(|d| started + d);
+
+        let samples: Vec<Sample> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..self.concurrency)
+                .map(|_| scope.spawn(|| self.run_worker(&client, &next_index, started, deadline)))
+                .collect();
+            handles.into_iter()
+                .flat_map(|handle| handle.join().expect("Benchmark thread panicked"))
+                .collect()
+        });
+
+        let report = summarise(&samples, started.elapsed());
+        match self.json_report {
+            true => match serde_json::to_string_pretty(&report) {
+                Ok(json) => println!("{}", json),
+                Err(e) => return Err(HtrsError::new(format!("Failed to serialize output: {}", e).as_str())),
+            },
+            false => println!("{}", format_report(&self.request, &report)),
+        }
+
+        if let Some(har) = &self.har {
+            let mut exchanges: Vec<Exchange> = samples.into_iter().filter_map(|s| s.exchange).collect();
+            exchanges.sort_by_key(|e| e.started);
+            har.write(&exchanges)?;
+        }
+        Ok(())
+    }
+
+    /// Sends requests until there are none left to send, each request takes the next index so that
+    /// requests can be spaced out across threads when a rate is given
+    fn run_worker(&self, client: &Client, next_index: &AtomicU64, started: Instant, deadline: Option<Instant>) -> Vec<Sample> {
+        let mut samples = vec![];
+        loop {
+            let index = next_index.fetch_add(1, Ordering::SeqCst);
+            if self.repeat.is_some_and(|repeat| index >= repeat) {
+                break;
+            }
+            if let Some(rate) = self.rate {
+                let send_at = started + Duration::from_secs_f64(index as f64 / rate as f64);
+                if deadline.is_some_and(|deadline| send_at >= deadline) {
+                    break;
+                }
+                thread::sleep(send_at.saturating_duration_since(Instant::now()));
+            }
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                break;
+            }
+            samples.push(self.send(client));
+        }
+        samples
+    }
+
+    fn send(&self, client: &Client) -> Sample {
+        let timer = Instant::now();
+        let (outcome, exchange) = match build_request(client, &self.request.method, &self.request.url, &self.request.headers, self.request.body.as_deref()) {
+            Err(_) => (Outcome::Error("request"), None),
+            Ok(request) => match execute_request(client, request) {
+                Err(e) => (Outcome::Error(get_error_kind(&e)), None),
+                // The body is read so that latency covers the full response
+                Ok(pending) => match pending.try_read_body() {
+                    Ok(exchange) => (Outcome::Status(exchange.status.as_u16()), Some(exchange)),
+                    Err(_) => (Outcome::Error("body"), None),
+                },
+            },
+        };
+        Sample { outcome, latency: timer.elapsed(), exchange: exchange.filter(|_| self.har.is_some()) }
+    }
+}
+
+fn get_error_kind(error: &reqwest::Error) -> &'static str {
+    if error.is_timeout() {
+        "timeout"
+    } else if error.is_connect() {
+        "connect"
+    } else if error.is_redirect() {
+        "redirect"
+    } else if error.is_body() || error.is_decode() {
+        "body"
+    } else {
+        "request"
+    }
+}
+
+fn summarise(samples: &[Sample], elapsed: Duration) -> BenchmarkReport {
+    let mut statuses = BTreeMap::new();
+    let mut errors = BTreeMap::new();
+    let mut latencies = vec![];
+    for sample in samples {
+        match sample.outcome {
+            Outcome::Status(status) => {
+                *statuses.entry(status).or_insert(0) += 1;
+                latencies.push(sample.latency.as_secs_f64() * 1000.0);
+            },
+            Outcome::Error(kind) => *errors.entry(kind.to_string()).or_insert(0) += 1,
+        }
+    }
+
+    let requests = samples.len() as u64;
+    let succeeded = statuses.iter()
+        .filter(|(status, _)| **status < 400)
+        .map(|(_, count)| count)
+        .sum();
+    BenchmarkReport {
+        requests,
+        succeeded,
+        failed: requests - succeeded,
+        elapsed_seconds: elapsed.as_secs_f64(),
+        requests_per_second: match elapsed.is_zero() {
+            true => 0.0,
+            false => requests as f64 / elapsed.as_secs_f64(),
+        },
+        statuses,
+        errors,
+        latency_ms: summarise_latencies(latencies),
+    }
+}
+
+fn summarise_latencies(mut latencies: Vec<f64>) -> Option<LatencySummary> {
+    if latencies.is_empty() {
+        return None;
+    }
+    latencies.sort_by(|a, b| a.total_cmp(b));
+
+    // Nearest-rank percentile
+    let percentile = |p: f64| latencies[((p / 100.0 * latencies.len() as f64).ceil() as usize).clamp(1, latencies.len()) - 1];
+    Some(LatencySummary {
+        min: latencies[0],
+        mean: latencies.iter().sum::<f64>() / latencies.len() as f64,
+        p50: percentile(50.0),
+        p90: percentile(90.0),
+        p99: percentile(99.0),
+        max: latencies[latencies.len() - 1],
+    })
+}
+
+fn format_report(request: &PreparedRequest, report: &BenchmarkReport) -> String {
+    let mut text = format!("{} {}\n", request.method, request.url);
+    text.push_str(format!(
+        "Requests: {} ({} succeeded, {} failed) in {:.2}s\n",
+        report.requests,
+        report.succeeded,
+        report.failed,
+        report.elapsed_seconds,
+    ).as_str());
+    text.push_str(format!("Throughput: {:.2} requests/s\n", report.requests_per_second).as_str());
+
+    if !report.statuses.is_empty() {
+        text.push_str("Statuses:\n");
+        for (status, count) in &report.statuses {
+            text.push_str(format!(" - {}: {}\n", status, count).as_str());
+        }
+    }
+    if !report.errors.is_empty() {
+        text.push_str("Errors:\n");
+        for (kind, count) in &report.errors {
+            text.push_str(format!(" - {}: {}\n", kind, count).as_str());
+        }
+    }
+    if let Some(latency) = &report.latency_ms {
+        text.push_str("Latency:\n");
+        for (name, value) in [("min", latency.min), ("mean", latency.mean), ("p50", latency.p50), ("p90", latency.p90), ("p99", latency.p99), ("max", latency.max)] {
+            text.push_str(format!(" - {}: {:.1}ms\n", name, value).as_str());
+        }
+    }
+
+    text.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod benchmark_tests {
+    use crate::benchmark::{summarise, summarise_latencies, LatencySummary, Outcome, Sample};
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    #[test]
+    fn given_latencies_when_summarise_then_should_use_nearest_rank_percentiles() {
+        let latencies: Vec<f64> = (1..=100).map(|l| l as f64).collect();
+
+        assert_eq!(summarise_latencies(latencies), Some(LatencySummary {
+            min: 1.0,
+            mean: 50.5,
+            p50: 50.0,
+            p90: 90.0,
+            p99: 99.0,
+            max: 100.0,
+        }));
+        assert_eq!(summarise_latencies(vec![]), None);
+    }
+
+    #[test]
+    fn given_samples_when_summarise_then_should_count_statuses_and_errors() {
+        let samples = vec![
+            Sample { outcome: Outcome::Status(200), latency: Duration::from_millis(10), exchange: None },
+            Sample { outcome: Outcome::Status(200), latency: Duration::from_millis(20), exchange: None },
+            Sample { outcome: Outcome::Status(503), latency: Duration::from_millis(30), exchange: None },
+            Sample { outcome: Outcome::Error("timeout"), latency: Duration::from_millis(40), exchange: None },
+        ];
+
+        let report = summarise(&samples, Duration::from_secs(2));
+
+        assert_eq!(report.requests, 4);
+        assert_eq!(report.succeeded, 2);
+        assert_eq!(report.failed, 2);
+        assert_eq!(report.requests_per_second, 2.0);
+        assert_eq!(report.statuses, BTreeMap::from([(200, 2), (503, 1)]));
+        assert_eq!(report.errors, BTreeMap::from([("timeout".to_string(), 1)]));
+        assert_eq!(report.latency_ms.map(|l| l.max), Some(30.0));
+    }
+}
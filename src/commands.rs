@@ -6,15 +6,42 @@ mod list_command;
 mod set_command;
 mod bindings;
 mod view_command;
+mod import_command;
+mod export_command;
+mod output_format;
+mod output_view;
+mod completions_command;
+mod complete_command;
+mod shell_command;
+mod copy_command;
+mod history_command;
+mod compare_command;
+mod test_command;
+mod run_command;
+mod ping_command;
+mod mock_command;
 
 use crate::commands::call_command::CallServiceEndpointCommand;
+use crate::commands::compare_command::CompareCommand;
+use crate::commands::complete_command::CompleteCommand;
+use crate::commands::completions_command::CompletionsCommand;
+use crate::commands::copy_command::CopyCommand;
 use crate::commands::delete_command::DeleteCommand;
 use crate::commands::edit_command::EditCommand;
+use crate::commands::export_command::ExportCommand;
+use crate::commands::history_command::HistoryCommand;
+use crate::commands::import_command::ImportCommand;
 use crate::commands::list_command::ListCommand;
+use crate::commands::mock_command::MockCommand;
 use crate::commands::new_command::NewCommand;
+use crate::commands::ping_command::PingCommand;
+use crate::commands::run_command::RunCommand;
 use crate::commands::set_command::SetCommand;
+use crate::commands::shell_command::ShellCommand;
+use crate::commands::test_command::TestCommand;
 use crate::commands::view_command::ViewCommand;
-use crate::commands::RootCommand::{Call, Delete, Edit, List, New, Set, View};
+use crate::commands::RootCommand::{Call, Compare, Complete, Completions, Copy, Delete, Edit, Export, History, Import, List, Mock, New, Ping, Run, Set, Shell, Test, View};
+use crate::completions::COMPLETE_COMMAND;
 use crate::config::current_config::HtrsConfig;
 use crate::htrs_binding_error::HtrsBindingError;
 use crate::outcomes::{HtrsAction, HtrsError};
@@ -22,13 +49,25 @@ use clap::{ArgMatches, Command};
 
 pub enum RootCommand {
     Call(CallServiceEndpointCommand),
+    Compare(CompareCommand),
+    Test(TestCommand),
+    Run(RunCommand),
+    Ping(PingCommand),
+    Mock(MockCommand),
 
     New(NewCommand),
     Edit(EditCommand),
     Delete(DeleteCommand),
+    Copy(CopyCommand),
     List(ListCommand),
     Set(SetCommand),
-    View(ViewCommand)
+    View(ViewCommand),
+    Import(ImportCommand),
+    Export(ExportCommand),
+    History(HistoryCommand),
+    Completions(CompletionsCommand),
+    Shell(ShellCommand),
+    Complete(CompleteCommand),
 }
 
 impl RootCommand {
@@ -38,12 +77,24 @@ impl RootCommand {
             .about("A flexible http cli client")
             .arg_required_else_help(true)
             .subcommand(CallServiceEndpointCommand::get_command(config))
+            .subcommand(CompareCommand::get_command(config))
+            .subcommand(TestCommand::get_command())
+            .subcommand(RunCommand::get_command())
+            .subcommand(PingCommand::get_command())
+            .subcommand(MockCommand::get_command())
             .subcommand(NewCommand::get_command())
             .subcommand(EditCommand::get_command())
             .subcommand(DeleteCommand::get_command())
+            .subcommand(CopyCommand::get_command())
             .subcommand(ListCommand::get_command())
             .subcommand(SetCommand::get_command())
             .subcommand(ViewCommand::get_command())
+            .subcommand(ImportCommand::get_command())
+            .subcommand(ExportCommand::get_command())
+            .subcommand(HistoryCommand::get_command())
+            .subcommand(CompletionsCommand::get_command())
+            .subcommand(ShellCommand::get_command())
+            .subcommand(CompleteCommand::get_command())
     }
 
     pub fn bind_from_matches(args: &ArgMatches, config: &HtrsConfig) -> Result<RootCommand, HtrsBindingError> {
@@ -54,6 +105,31 @@ impl RootCommand {
                     call_service_endpoint_cmd
                 ))
             },
+            Some(("compare", compare_matches)) => {
+                Ok(Compare(
+                    CompareCommand::bind_from_matches(config, compare_matches)?
+                ))
+            },
+            Some(("test", test_matches)) => {
+                Ok(Test(
+                    TestCommand::bind_from_matches(test_matches)
+                ))
+            },
+            Some(("run", run_matches)) => {
+                Ok(Run(
+                    RunCommand::bind_from_matches(run_matches)
+                ))
+            },
+            Some(("ping", ping_matches)) => {
+                Ok(Ping(
+                    PingCommand::bind_from_matches(ping_matches)
+                ))
+            },
+            Some(("mock", mock_matches)) => {
+                Ok(Mock(
+                    MockCommand::bind_from_matches(mock_matches)
+                ))
+            },
             Some(("new", new_matches)) => {
                 Ok(New(
                     NewCommand::bind_from_matches(new_matches)
@@ -69,6 +145,11 @@ impl RootCommand {
                     DeleteCommand::bind_from_matches(delete_matches)
                 ))
             }
+            Some(("copy" | "cp", copy_matches)) => {
+                Ok(Copy(
+                    CopyCommand::bind_from_matches(copy_matches)
+                ))
+            }
             Some(("list" | "ls", list_matches)) => {
                 Ok(List(
                     ListCommand::bind_from_matches(list_matches)
@@ -83,7 +164,33 @@ impl RootCommand {
                 Ok(View(
                     ViewCommand::bind_from_matches(view_matches)
                 ))
-            }
+            },
+            Some(("import", import_matches)) => {
+                Ok(Import(
+                    ImportCommand::bind_from_matches(import_matches)
+                ))
+            },
+            Some(("export", export_matches)) => {
+                Ok(Export(
+                    ExportCommand::bind_from_matches(export_matches)
+                ))
+            },
+            Some(("history", history_matches)) => {
+                Ok(History(
+                    HistoryCommand::bind_from_matches(history_matches)
+                ))
+            },
+            Some(("completions", completions_matches)) => {
+                Ok(Completions(
+                    CompletionsCommand::bind_from_matches(completions_matches)
+                ))
+            },
+            Some(("shell", _)) => Ok(Shell(ShellCommand)),
+            Some((COMPLETE_COMMAND, complete_matches)) => {
+                Ok(Complete(
+                    CompleteCommand::bind_from_matches(complete_matches)
+                ))
+            },
             _ => unreachable!()
         }
     }
@@ -93,12 +200,24 @@ impl RootCommand {
             Call(call_command) => {
                 call_command.execute_command(config)
             },
+            Compare(compare_command) => compare_command.execute(config),
+            Test(test_command) => test_command.execute(config),
+            Run(run_command) => run_command.execute(config),
+            Ping(ping_command) => ping_command.execute(config),
+            Mock(mock_command) => mock_command.execute(config),
             New(new_command) => new_command.execute(config),
             Edit(edit_command) => edit_command.execute(config),
             Delete(delete_command) => delete_command.execute(config),
+            Copy(copy_command) => copy_command.execute(config),
             List(list_command) => list_command.execute(config),
             Set(set_command) => set_command.execute(config),
             View(view_command) => view_command.execute(config),
+            Import(import_command) => import_command.execute(config),
+            Export(export_command) => export_command.execute(config),
+            History(history_command) => history_command.execute(config),
+            Completions(completions_command) => completions_command.execute(),
+            Shell(shell_command) => shell_command.execute(),
+            Complete(complete_command) => complete_command.execute(config),
         }
     }
 }
\ No newline at end of file
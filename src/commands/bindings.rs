@@ -40,3 +40,18 @@ impl MatchBinding<Vec<String>> for ArgMatches {
         binding_value.cloned().collect()
     }
 }
+
+impl MatchBinding<u64> for ArgMatches {
+    fn bind_field(&self, field_id: &str) -> u64 {
+        let Some(field_value) = self.get_one::<u64>(field_id) else {
+            panic!("Unexpected binding - no value found");
+        };
+        *field_value
+    }
+}
+
+impl MatchBinding<Option<u64>> for ArgMatches {
+    fn bind_field(&self, field_id: &str) -> Option<u64> {
+        Some(*self.get_one::<u64>(field_id)?)
+    }
+}
@@ -1,12 +1,24 @@
+use crate::benchmark::Benchmark;
 use crate::commands::bindings::MatchBinding;
-use crate::common::{get_params_from_path, merge_hashmaps};
-use crate::config::current_config::{Endpoint, HtrsConfig, Service};
+use crate::common::{get_params_from_path, merge_hashmaps, parse_duration, parse_key_value_string};
+use crate::config::current_config::{Assertion, Endpoint, HtrsConfig, Pagination, PaginationStrategy, Service};
+use crate::fixtures::{FixtureMode, Fixtures};
 use crate::htrs_binding_error::HtrsBindingError;
-use crate::outcomes::HtrsAction::MakeRequest;
+use crate::formats::curl::to_curl;
+use crate::formats::har::HarCapture;
+use crate::history::RequestOrigin;
+use crate::pagination::AllPages;
+use crate::outcomes::HtrsAction::{FetchAllPages, MakeRequest, PrintDialogue, RunBenchmark, WatchRequest};
 use crate::outcomes::{HtrsAction, HtrsError};
-use clap::{Arg, ArgAction, ArgMatches, Command};
+use crate::request::{apply_query_params_to_url, get_default_headers, PreparedRequest};
+use crate::testing::validate_status;
+use crate::watch::Watch;
+use clap::{value_parser, Arg, ArgAction, ArgGroup, ArgMatches, Command, ValueHint};
 use reqwest::{Method, Url};
+use serde_json::Value;
 use std::collections::HashMap;
+use std::io::{stderr, stdin, IsTerminal, Write};
+use std::path::PathBuf;
 use std::str::FromStr;
 
 pub struct CallServiceEndpointCommand {
@@ -14,12 +26,68 @@ pub struct CallServiceEndpointCommand {
     pub environment_name: Option<String>,
     pub endpoint_name: String,
     pub parameters: HashMap<String, String>,
-    pub headers: HashMap<String, String>,
     pub show_body: bool,
     pub preset: Option<String>,
+    pub as_curl: bool,
+    pub har: Option<String>,
+    pub redact_secrets: bool,
+    pub no_input: bool,
+    pub dry_run: bool,
+    pub save_response: bool,
+    pub timing: bool,
+    pub max_events: Option<u64>,
+    pub idle_timeout: Option<String>,
+    pub record: Option<String>,
+    pub replay: Option<String>,
+    pub strict: bool,
+    pub watch: Option<String>,
+    pub until: Vec<String>,
+    pub max_wait: Option<String>,
+    pub all_pages: bool,
+    pub max_pages: Option<u64>,
+    pub repeat: Option<u64>,
+    pub duration: Option<String>,
+    pub concurrency: Option<u64>,
+    pub rate: Option<u64>,
+    pub json_report: bool,
 }
 
 impl CallServiceEndpointCommand {
+    /// Creates a call that sends the request without prompting for missing parameters, for use by
+    /// commands that call endpoints on the user's behalf
+    pub fn new(service_name: String, environment_name: Option<String>, endpoint_name: String, parameters: HashMap<String, String>, preset: Option<String>) -> Self {
+        CallServiceEndpointCommand {
+            service_name,
+            environment_name,
+            endpoint_name,
+            parameters,
+            show_body: false,
+            preset,
+            as_curl: false,
+            har: None,
+            redact_secrets: false,
+            no_input: true,
+            dry_run: false,
+            save_response: false,
+            timing: false,
+            max_events: None,
+            idle_timeout: None,
+            record: None,
+            replay: None,
+            strict: false,
+            watch: None,
+            until: vec![],
+            max_wait: None,
+            all_pages: false,
+            max_pages: None,
+            repeat: None,
+            duration: None,
+            concurrency: None,
+            rate: None,
+            json_report: false,
+        }
+    }
+
     pub fn get_command(config: &HtrsConfig) -> Command {
         let mut command = Command::new("call")
             .about("Call a service endpoint")
@@ -56,38 +124,52 @@ impl CallServiceEndpointCommand {
         };
         let environment_name: Option<String> = endpoint_matches.bind_field("environment");
 
-        let mut headers = config.headers.clone();
-        merge(&mut headers, &service.headers);
-
-        let mut parameters = get_query_parameters_from_args(endpoint, endpoint_matches);
-        for template_param in get_params_from_path(endpoint.path_template.as_str()) {
-            if let Some(value) = endpoint_matches.bind_field(&template_param) {
-                parameters.insert(template_param.clone(), value);
-            }
-        }
-
-        let query_param_args: Vec<String> = endpoint_matches.bind_field("query_parameters");
-        for query_param_arg in query_param_args {
-            let (key, value) = parse_query_params_from_arg(query_param_arg.as_str())?;
-            parameters.insert(key, value);
-        }
+        let parameters = bind_parameters_from_matches(endpoint, endpoint_matches)?;
 
         Ok(CallServiceEndpointCommand {
             service_name: service_name.to_string(),
             environment_name,
             endpoint_name: endpoint_name.to_string(),
             parameters,
-            headers,
             show_body: endpoint_matches.bind_field("show_body"),
             preset: endpoint_matches.bind_field("preset"),
+            as_curl: endpoint_matches.bind_field("as_curl"),
+            har: endpoint_matches.bind_field("har"),
+            redact_secrets: endpoint_matches.bind_field("redact_secrets"),
+            no_input: endpoint_matches.bind_field("no_input"),
+            dry_run: endpoint_matches.bind_field("dry_run"),
+            save_response: endpoint_matches.bind_field("save_response"),
+            timing: endpoint_matches.bind_field("timing"),
+            max_events: endpoint_matches.bind_field("max_events"),
+            idle_timeout: endpoint_matches.bind_field("idle_timeout"),
+            record: endpoint_matches.bind_field("record"),
+            replay: endpoint_matches.bind_field("replay"),
+            strict: endpoint_matches.bind_field("strict"),
+            watch: endpoint_matches.bind_field("watch"),
+            until: endpoint_matches.bind_field("until"),
+            max_wait: endpoint_matches.bind_field("max_wait"),
+            all_pages: endpoint_matches.bind_field("all_pages"),
+            max_pages: endpoint_matches.bind_field("max_pages"),
+            repeat: endpoint_matches.bind_field("repeat"),
+            duration: endpoint_matches.bind_field("duration"),
+            concurrency: endpoint_matches.bind_field("concurrency"),
+            rate: endpoint_matches.bind_field("rate"),
+            json_report: endpoint_matches.bind_field("json_report"),
         })
     }
 
     pub fn execute_command(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
-        let service = config.get_service(&self.service_name).unwrap();
-        let endpoint = service.get_endpoint(self.endpoint_name.as_str()).unwrap();
+        let Some(service) = config.get_service(&self.service_name) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service_name).as_str()));
+        };
+        let Some(endpoint) = service.get_endpoint(self.endpoint_name.as_str()) else {
+            return Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", self.endpoint_name, service.name).as_str()));
+        };
         let environment = match &self.environment_name {
-            Some(environment_name) => service.get_environment(environment_name).unwrap(),
+            Some(environment_name) => match service.get_environment(environment_name) {
+                Some(environment) => environment,
+                None => return Err(HtrsError::new(format!("Unable to find environment with name or alias `{}` for service `{}`", environment_name, service.name).as_str())),
+            },
             None => {
                 let Some(environment) = service.get_default_environment() else {
                     return Err(HtrsError::new(&format!("No default environment defined for service {}", self.service_name)));
@@ -97,14 +179,24 @@ impl CallServiceEndpointCommand {
         };
 
         let mut parameters = HashMap::new();
+        let mut parameter_sources: HashMap<String, String> = HashMap::new();
         if let Some(preset_name) = &self.preset {
             let Some(preset) = config.get_preset(preset_name) else {
                 return Err(HtrsError::new(&format!("No preset found with name `{}`", preset_name)));
             };
             parameters = preset.values.clone();
+            for key in preset.values.keys() {
+                parameter_sources.insert(key.clone(), format!("preset `{}`", preset.name));
+            }
         }
 
         parameters = merge_hashmaps(parameters, self.parameters.clone());
+        for key in self.parameters.keys() {
+            parameter_sources.insert(key.clone(), "argument".to_string());
+        }
+        if !self.no_input && stdin().is_terminal() {
+            prompt_for_missing_parameters(endpoint, &mut parameters, self.preset.as_deref())?;
+        }
 
         let mut url = match Url::from_str(format!("http://{}/", environment.host).as_str()) {
             Ok(url) => url,
@@ -119,20 +211,203 @@ impl CallServiceEndpointCommand {
         for param in &endpoint.query_parameters {
             let value = parameters.get(&param.name);
             if value.is_none() && param.required {
-                return Err(HtrsError::new(&format!("Preset was missing required arguments for endpoint: {}", param.name)));
+                return Err(match self.preset {
+                    Some(_) => HtrsError::new(&format!("Preset was missing required arguments for endpoint: {}", param.name)),
+                    None => HtrsError::new(&format!("Query parameter `{}` is required but not provided from parameters", param.name)),
+                });
             } else if let Some(value) = value {
                 query_parameters.insert(param.name.clone(), value.clone());
             }
         }
 
+        let Ok(method) = Method::from_bytes(endpoint.method.as_bytes()) else {
+            return Err(HtrsError::new(&format!("Endpoint `{}` has an invalid http method `{}`", endpoint.name, endpoint.method)));
+        };
+
+        let headers = config.get_headers(Some(service), Some(environment), Some(endpoint));
+
+        if self.dry_run {
+            let mut text = String::new();
+            let request_url = apply_query_params_to_url(url.clone(), query_parameters.clone())?;
+            text.push_str(format!("{} {}\n", method, request_url).as_str());
+            text.push_str(format!("Host: {} (environment `{}`)\n", environment.host, environment.name).as_str());
+            text.push_str(format!("Path: {} (endpoint `{}`)\n", endpoint.path_template, endpoint.name).as_str());
+
+            let mut used_parameters = get_params_from_path(&endpoint.path_template);
+            let mut used_query_parameters: Vec<String> = query_parameters.keys().cloned().collect();
+            used_query_parameters.sort();
+            used_parameters.extend(used_query_parameters);
+
+            text.push_str("Parameters:\n");
+            if used_parameters.is_empty() {
+                text.push_str("  (no parameters)\n");
+            }
+            for name in &used_parameters {
+                let source = parameter_sources.get(name).map(|s| s.as_str()).unwrap_or("prompt");
+                text.push_str(format!(" - {} = {} ({})\n", name, parameters[name], source).as_str());
+            }
+
+            text.push_str("Headers:\n");
+            let scoped_headers = config.get_scoped_headers(Some(service), Some(environment), Some(endpoint));
+            let mut default_headers: Vec<(String, String)> = get_default_headers().into_iter()
+                .filter(|(name, _)| !headers.contains_key(name))
+                .collect();
+            default_headers.sort();
+            for (name, value) in default_headers {
+                text.push_str(format!(" - {}: {} (default)\n", name, value).as_str());
+            }
+            for header in scoped_headers {
+                text.push_str(format!(" - {}: {} ({} header)\n", header.name, header.value, header.scope).as_str());
+            }
+
+            if let Some(body) = &endpoint.body {
+                text.push_str(format!("Body (endpoint `{}`):\n{}\n", endpoint.name, body).as_str());
+            }
+
+            return Ok(PrintDialogue(text.trim_end().to_string()));
+        }
+
+        if self.as_curl {
+            let url = apply_query_params_to_url(url, query_parameters)?;
+            let headers: Vec<(String, String)> = merge_hashmaps(get_default_headers(), headers).into_iter().collect();
+            return Ok(PrintDialogue(to_curl(method.as_str(), &url, &headers, endpoint.body.as_deref())));
+        }
+
+        if self.repeat.is_some() || self.duration.is_some() {
+            let duration = match &self.duration {
+                Some(duration) => Some(parse_duration(duration)?),
+                None => None,
+            };
+            return Ok(RunBenchmark(Benchmark {
+                request: PreparedRequest {
+                    method,
+                    url: apply_query_params_to_url(url, query_parameters)?,
+                    headers,
+                    body: endpoint.body.clone(),
+                },
+                repeat: self.repeat,
+                duration,
+                concurrency: self.concurrency.unwrap_or(1),
+                rate: self.rate,
+                json_report: self.json_report,
+                har: self.har.as_ref().map(|path| HarCapture::new(path, self.redact_secrets, config)),
+            }));
+        }
+
+        if let Some(interval) = &self.watch {
+            return Ok(WatchRequest(Watch {
+                request: PreparedRequest {
+                    method,
+                    url: apply_query_params_to_url(url, query_parameters)?,
+                    headers,
+                    body: endpoint.body.clone(),
+                },
+                interval: parse_duration(interval)?,
+                conditions: self.until.iter()
+                    .map(|condition| parse_condition(condition))
+                    .collect::<Result<Vec<Assertion>, HtrsError>>()?,
+                max_wait: match &self.max_wait {
+                    Some(max_wait) => Some(parse_duration(max_wait)?),
+                    None => None,
+                },
+                show_body: self.show_body,
+                har: self.har.as_ref().map(|path| HarCapture::new(path, self.redact_secrets, config)),
+            }));
+        }
+
+        if self.all_pages {
+            let Some(pagination) = &endpoint.pagination else {
+                return Err(HtrsError::new(format!("Endpoint `{}` for service `{}` has no pagination set", endpoint.name, service.name).as_str()));
+            };
+            if let Some(parameter) = get_pagination_parameter(pagination) && let Some(value) = parameters.get(parameter) {
+                query_parameters.insert(parameter.clone(), value.clone());
+            }
+            return Ok(FetchAllPages(AllPages {
+                url,
+                query_parameters,
+                method,
+                headers,
+                body: endpoint.body.clone(),
+                pagination: pagination.clone(),
+                max_pages: self.max_pages,
+                show_body: self.show_body,
+                har: self.har.as_ref().map(|path| HarCapture::new(path, self.redact_secrets, config)),
+            }));
+        }
+
         Ok(MakeRequest {
             url,
             query_parameters,
-            method: Method::GET,
-            headers: self.headers.clone(),
-            show_body: self.show_body
+            method,
+            headers,
+            body: endpoint.body.clone(),
+            show_body: self.show_body,
+            timing: self.timing,
+            max_events: self.max_events,
+            idle_timeout: match &self.idle_timeout {
+                Some(idle_timeout) => Some(parse_duration(idle_timeout)?),
+                None => None,
+            },
+            fixtures: self.get_fixtures(),
+            origin: Some(RequestOrigin {
+                service: service.name.clone(),
+                endpoint: endpoint.name.clone(),
+                environment: environment.name.clone(),
+                parameters,
+                save_response: self.save_response,
+            }),
+            har: self.har.as_ref().map(|path| HarCapture::new(path, self.redact_secrets, config)),
         })
     }
+
+    fn get_fixtures(&self) -> Option<Fixtures> {
+        match (&self.record, &self.replay) {
+            (Some(directory), _) => Some(Fixtures { directory: PathBuf::from(directory), mode: FixtureMode::Record }),
+            (_, Some(directory)) => Some(Fixtures {
+                directory: PathBuf::from(directory),
+                mode: match self.strict {
+                    true => FixtureMode::StrictReplay,
+                    false => FixtureMode::Replay,
+                },
+            }),
+            _ => None,
+        }
+    }
+
+    /// Resolves the request the call would send without sending it
+    pub fn prepare_request(&self, config: &HtrsConfig) -> Result<PreparedRequest, HtrsError> {
+        match self.execute_command(config)? {
+            MakeRequest { url, query_parameters, method, headers, body, .. } => Ok(PreparedRequest {
+                method,
+                url: apply_query_params_to_url(url, query_parameters)?,
+                headers,
+                body,
+            }),
+            _ => Err(HtrsError::new("Call does not send a request")),
+        }
+    }
+}
+
+/// Gets the query parameter that pagination sends, which can be given a value for the first page
+/// with `--query-param` even when it isn't one of the endpoint's query parameters
+fn get_pagination_parameter(pagination: &Pagination) -> Option<&String> {
+    match &pagination.strategy {
+        PaginationStrategy::Link => None,
+        PaginationStrategy::Cursor { parameter, .. } | PaginationStrategy::Page { parameter } | PaginationStrategy::Offset { parameter } => Some(parameter),
+    }
+}
+
+/// Parses a `--until` condition, which is either `status=<status>` or `<json path>=<value>`
+fn parse_condition(condition: &str) -> Result<Assertion, HtrsError> {
+    let Ok((target, expected)) = parse_key_value_string(condition) else {
+        return Err(HtrsError::new(format!("Invalid condition `{}`, should be in format `status=<status>` or `<json path>=<value>`", condition).as_str()));
+    };
+    if target == "status" {
+        validate_status(&expected)?;
+        return Ok(Assertion::Status { expected });
+    }
+    let value = serde_json::from_str::<Value>(&expected).unwrap_or(Value::String(expected));
+    Ok(Assertion::JsonEquals { path: target, value })
 }
 
 fn get_command_for_service(service: &Service) -> Command {
@@ -168,15 +443,7 @@ fn get_command_for_endpoint(endpoint: &Endpoint) -> Command {
                 .long("environment")
                 .short('e')
         )
-        .arg(
-            Arg::new("query_parameters")
-                .value_name("query param")
-                .help("Set a query parameter for the request in the format `name=value`")
-                .required(false)
-                .action(ArgAction::Append)
-                .long("query-param")
-                .short('q')
-        )
+        .arg(get_query_parameters_arg())
         .arg(
             Arg::new("show_body")
                 .help("Print the response body")
@@ -189,25 +456,270 @@ fn get_command_for_endpoint(endpoint: &Endpoint) -> Command {
                 .help("Use a preset to populate endpoint's parameters")
                 .long("preset")
                 .short('p')
+        )
+        .arg(
+            Arg::new("as_curl")
+                .help("Print the equivalent curl command instead of sending the request")
+                .required(false)
+                .num_args(0)
+                .long("as-curl")
+        )
+        .arg(
+            Arg::new("dry_run")
+                .help("Print the resolved request & where each value came from instead of sending it")
+                .required(false)
+                .num_args(0)
+                .conflicts_with("as_curl")
+                .long("dry-run")
+        )
+        .arg(
+            Arg::new("har")
+                .value_name("file")
+                .help("Record the request & response to a HAR file")
+                .value_hint(ValueHint::FilePath)
+                .required(false)
+                .long("har")
+        )
+        .arg(
+            Arg::new("save_response")
+                .help("Save the response body in request history")
+                .required(false)
+                .num_args(0)
+                .long("save-response")
+        )
+        .arg(
+            Arg::new("timing")
+                .help("Print time to connect, time to first byte, total time & response sizes to stderr")
+                .required(false)
+                .num_args(0)
+                .long("timing")
+        )
+        .arg(
+            Arg::new("max_events")
+                .value_name("count")
+                .help("Stop after printing this many events when the response is an event stream")
+                .required(false)
+                .value_parser(value_parser!(u64).range(1..))
+                .conflicts_with_all(["as_curl", "dry_run"])
+                .long("max-events")
+        )
+        .arg(
+            Arg::new("idle_timeout")
+                .value_name("duration")
+                .help("Stop reading the response once nothing has been received for this long, e.g. `30s`")
+                .required(false)
+                .conflicts_with_all(["as_curl", "dry_run", "replay"])
+                .long("idle-timeout")
+        )
+        .arg(
+            Arg::new("record")
+                .value_name("directory")
+                .help("Save the response to a directory so it can be replayed with `--replay`")
+                .value_hint(ValueHint::DirPath)
+                .required(false)
+                .conflicts_with_all(["as_curl", "dry_run"])
+                .long("record")
+        )
+        .arg(
+            Arg::new("replay")
+                .value_name("directory")
+                .help("Print the response saved by `--record` instead of sending the request, if there is one. Replayed responses aren't added to history")
+                .value_hint(ValueHint::DirPath)
+                .required(false)
+                .conflicts_with_all(["record", "as_curl", "dry_run", "har", "timing"])
+                .long("replay")
+        )
+        .arg(
+            Arg::new("strict")
+                .help("Fail instead of sending the request when no response has been saved for it")
+                .required(false)
+                .num_args(0)
+                .requires("replay")
+                .long("strict")
+        )
+        .arg(
+            Arg::new("watch")
+                .value_name("interval")
+                .help("Send the request repeatedly at this interval, e.g. `2s`, printing the response when it changes")
+                .required(false)
+                .conflicts_with_all(["as_curl", "dry_run", "record", "replay", "max_events", "idle_timeout"])
+                .long("watch")
+        )
+        .arg(
+            Arg::new("until")
+                .value_name("condition")
+                .help("Stop watching once the response meets a condition, `status=<status>` or `<json path>=<value>`")
+                .required(false)
+                .action(ArgAction::Append)
+                .requires("watch")
+                .long("until")
+        )
+        .arg(
+            Arg::new("max_wait")
+                .value_name("duration")
+                .help("Stop watching after this long, failing if the `--until` conditions haven't been met")
+                .required(false)
+                .requires("watch")
+                .long("max-wait")
+        )
+        .arg(
+            Arg::new("all_pages")
+                .help("Keep requesting the next page until there are none left, using the endpoint's pagination")
+                .required(false)
+                .num_args(0)
+                .conflicts_with_all(["as_curl", "dry_run", "timing", "record", "replay", "watch", "max_events", "idle_timeout"])
+                .long("all-pages")
+        )
+        .arg(
+            Arg::new("max_pages")
+                .value_name("count")
+                .help("Stop after requesting this many pages")
+                .required(false)
+                .value_parser(value_parser!(u64).range(1..))
+                .requires("all_pages")
+                .long("max-pages")
+        )
+        .arg(
+            Arg::new("no_input")
+                .help("Fail instead of prompting for missing required parameters")
+                .required(false)
+                .num_args(0)
+                .long("no-input")
+        )
+        .arg(
+            Arg::new("redact_secrets")
+                .help("Redact the values of secret headers in the HAR file")
+                .required(false)
+                .num_args(0)
+                .requires("har")
+                .long("redact-secrets")
+        )
+        .arg(
+            Arg::new("repeat")
+                .value_name("count")
+                .help("Benchmark the endpoint by sending the request this many times")
+                .required(false)
+                .value_parser(value_parser!(u64).range(1..))
+                .long("repeat")
+        )
+        .arg(
+            Arg::new("duration")
+                .value_name("duration")
+                .help("Benchmark the endpoint by sending the request for this long, e.g. `30s`")
+                .required(false)
+                .long("duration")
+        )
+        .arg(
+            Arg::new("concurrency")
+                .value_name("count")
+                .help("Number of requests to send at once when benchmarking, defaults to 1")
+                .required(false)
+                .value_parser(value_parser!(u64).range(1..))
+                .requires("benchmark")
+                .long("concurrency")
+        )
+        .arg(
+            Arg::new("rate")
+                .value_name("requests per second")
+                .help("Limit how many requests are sent per second when benchmarking")
+                .required(false)
+                .value_parser(value_parser!(u64).range(1..))
+                .requires("benchmark")
+                .long("rate")
+        )
+        .arg(
+            Arg::new("json_report")
+                .help("Print the benchmark report as JSON")
+                .required(false)
+                .num_args(0)
+                .requires("benchmark")
+                .long("json-report")
+        )
+        .group(
+            ArgGroup::new("benchmark")
+                .args(["repeat", "duration"])
+                .multiple(true)
+                .conflicts_with_all(["as_curl", "dry_run", "save_response", "timing", "record", "replay", "watch", "all_pages", "max_events", "idle_timeout"])
         );
 
-    let templated_params = get_params_from_path(&endpoint.path_template);
-    for templated_param in templated_params {
-        command = command.arg(
-            Arg::new(&templated_param)
-                .allow_hyphen_values(true)
-                .long(&templated_param)
-                .required_unless_present("preset")
-        );
+    if let Some(description) = &endpoint.description {
+        command = command.about(description);
+    }
+    if let Some(alias) = &endpoint.alias {
+        command = command.visible_alias(alias);
+    }
+    if !endpoint.tags.is_empty() {
+        command = command.after_help(format!("Tags: {}", endpoint.tags.join(", ")));
     }
 
-    for param in &endpoint.query_parameters {
-        let mut arg = Arg::new(&param.name)
-            .allow_hyphen_values(true)
-            .long(&param.name);
+    add_parameter_args(command, endpoint)
+}
 
-        if param.required {
-            arg = arg.required_unless_present("preset");
+/// Adds the `--har` & `--redact-secrets` options to a command that sends several requests, which
+/// are all recorded to the one HAR file
+pub fn add_har_args(command: Command) -> Command {
+    command
+        .arg(
+            Arg::new("har")
+                .value_name("file")
+                .help("Record the requests & responses to a HAR file")
+                .value_hint(ValueHint::FilePath)
+                .required(false)
+                .long("har")
+        )
+        .arg(
+            Arg::new("redact_secrets")
+                .help("Redact the values of secret headers in the HAR file")
+                .required(false)
+                .num_args(0)
+                .requires("har")
+                .long("redact-secrets")
+        )
+}
+
+/// Argument for giving any query parameter as `name=value`, including those without their own flag
+pub fn get_query_parameters_arg() -> Arg {
+    Arg::new("query_parameters")
+        .value_name("query param")
+        .help("Set a query parameter for the request in the format `name=value`")
+        .required(false)
+        .action(ArgAction::Append)
+        .long("query-param")
+        .short('q')
+}
+
+/// Adds an argument for each path & query parameter of the endpoint, with required parameters
+/// marked in their help.
+///
+/// Parameter args are given ids under `param:` so they can't clash with the command's own args,
+/// parameters named the same as one of the command's flags don't get a flag of their own & are
+/// given with `--query-param` or a preset instead
+pub fn add_parameter_args(mut command: Command, endpoint: &Endpoint) -> Command {
+    // Clap never requires parameters, so that they can be prompted for when missing. Missing
+    // parameters fail the same way with or without a terminal once the call is executed
+    let path_params = get_params_from_path(&endpoint.path_template).into_iter()
+        .map(|name| (name, true));
+    let query_params = endpoint.query_parameters.iter()
+        .map(|param| (param.name.clone(), param.required));
+
+    for (name, required) in path_params.chain(query_params) {
+        let is_taken = RESERVED_FLAGS.contains(&name.as_str())
+            || command.get_arguments().any(|a| a.get_long() == Some(name.as_str()) || a.get_all_aliases().is_some_and(|aliases| aliases.contains(&name.as_str())));
+        if is_taken {
+            continue;
+        }
+
+        let mut arg = Arg::new(get_parameter_arg_id(&name))
+            .value_name(name.clone())
+            .allow_hyphen_values(true)
+            .long(name.clone());
+
+        let description = endpoint.parameter_descriptions.get(&name);
+        match (description, required) {
+            (Some(description), true) => arg = arg.help(format!("{} [required]", description)),
+            (Some(description), false) => arg = arg.help(description),
+            (None, true) => arg = arg.help("[required]"),
+            (None, false) => {},
         }
 
         command = command.arg(arg);
@@ -216,6 +728,52 @@ fn get_command_for_endpoint(endpoint: &Endpoint) -> Command {
     command
 }
 
+/// Flags that clap adds to every command, which parameters can't take for themselves
+const RESERVED_FLAGS: [&str; 1] = ["help"];
+
+fn get_parameter_arg_id(name: &str) -> String {
+    format!("param:{}", name)
+}
+
+/// Prompts on the terminal for each required path & query parameter that hasn't been given a value
+fn prompt_for_missing_parameters(endpoint: &Endpoint, parameters: &mut HashMap<String, String>, preset: Option<&str>) -> Result<(), HtrsError> {
+    let path_params = get_params_from_path(&endpoint.path_template).into_iter()
+        .map(|name| (name, "path parameter"));
+    let query_params = endpoint.query_parameters.iter()
+        .filter(|q| q.required)
+        .map(|q| (q.name.clone(), "query parameter"));
+
+    for (name, kind) in path_params.chain(query_params) {
+        if parameters.contains_key(&name) {
+            continue;
+        }
+
+        let prompt = match preset {
+            Some(preset) => format!("{} ({}, not set by preset `{}`): ", name, kind, preset),
+            None => format!("{} ({}): ", name, kind),
+        };
+        loop {
+            eprint!("{}", prompt);
+            let _ = stderr().flush();
+
+            let mut value = String::new();
+            match stdin().read_line(&mut value) {
+                Ok(0) => return Err(HtrsError::new(format!("No value given for parameter `{}`", name).as_str())),
+                Ok(_) => {},
+                Err(e) => return Err(HtrsError::new(format!("Failed to read value for parameter `{}`: {}", name, e).as_str())),
+            }
+
+            let value = value.trim();
+            if !value.is_empty() {
+                parameters.insert(name.clone(), value.to_string());
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn parse_query_params_from_arg(arg: &str) -> Result<(String, String), HtrsBindingError> {
     if let [name, value] = arg.split("=").collect::<Vec<&str>>().as_slice()
         && !name.is_empty() && !value.is_empty() {
@@ -241,20 +799,27 @@ fn build_path_from_template(path_template: &str, parameters: &HashMap<String, St
     Ok(path)
 }
 
-fn get_query_parameters_from_args(endpoint: &Endpoint, args: &ArgMatches) -> HashMap<String, String> {
-    let mut query_parameters = HashMap::new();
-    for parameter_name in &endpoint.query_parameters {
-        let parameter_value: Option<String> = args.bind_field(parameter_name.name.as_str());
-
-        if let Some(parameter_value) = parameter_value {
-            query_parameters.insert(parameter_name.name.to_string(), parameter_value);
+/// Gets the values given for the arguments added by [`add_parameter_args`], along with those given
+/// by [`get_query_parameters_arg`]
+pub fn bind_parameters_from_matches(endpoint: &Endpoint, args: &ArgMatches) -> Result<HashMap<String, String>, HtrsBindingError> {
+    let mut parameters = HashMap::new();
+    let path_params = get_params_from_path(endpoint.path_template.as_str());
+    let query_params = endpoint.query_parameters.iter().map(|param| param.name.clone());
+    for name in path_params.into_iter().chain(query_params) {
+        // Parameters that didn't get a flag of their own have no arg to read
+        let value = match args.try_get_one::<String>(&get_parameter_arg_id(&name)) {
+            Ok(value) => value.cloned(),
+            Err(_) => None,
+        };
+        if let Some(value) = value {
+            parameters.insert(name, value);
         }
     }
-    query_parameters
-}
 
-fn merge(into: &mut HashMap<String, String>, from: &HashMap<String, String>) {
-    for (k, v) in from.iter() {
-        into.insert(k.to_string(), v.to_string());
+    let query_param_args: Vec<String> = args.bind_field("query_parameters");
+    for query_param_arg in query_param_args {
+        let (key, value) = parse_query_params_from_arg(query_param_arg.as_str())?;
+        parameters.insert(key, value);
     }
+    Ok(parameters)
 }
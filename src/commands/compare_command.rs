@@ -0,0 +1,151 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::call_command::{add_har_args, add_parameter_args, bind_parameters_from_matches, get_query_parameters_arg, CallServiceEndpointCommand};
+use crate::compare::Comparison;
+use crate::config::current_config::{Endpoint, HtrsConfig, Service};
+use crate::htrs_binding_error::HtrsBindingError;
+use crate::formats::har::HarCapture;
+use crate::outcomes::HtrsAction::CompareResponses;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use std::collections::HashMap;
+
+pub struct CompareCommand {
+    pub service_name: String,
+    pub endpoint_name: String,
+    pub environments: Vec<String>,
+    pub parameters: HashMap<String, String>,
+    pub preset: Option<String>,
+    pub headers: Vec<String>,
+    pub ignore_paths: Vec<String>,
+    pub har: Option<String>,
+    pub redact_secrets: bool,
+}
+
+impl CompareCommand {
+    pub fn get_command(config: &HtrsConfig) -> Command {
+        let mut command = Command::new("compare")
+            .about("Compare an endpoint's response across environments")
+            .arg_required_else_help(true);
+
+        for service in &config.services {
+            command = command.subcommand(get_command_for_service(service));
+        }
+
+        command
+    }
+
+    pub fn bind_from_matches(config: &HtrsConfig, args: &ArgMatches) -> Result<CompareCommand, HtrsBindingError> {
+        let Some((service_name, service_matches)) = args.subcommand() else {
+            panic!("Bad service subcommand for CompareCommand");
+        };
+        let Some(service) = config.get_service(service_name) else {
+            panic!("Bad service name");
+        };
+        let Some((endpoint_name, endpoint_matches)) = service_matches.subcommand() else {
+            panic!("Bad endpoint subcommand for CompareCommand");
+        };
+        let Some(endpoint) = service.get_endpoint(endpoint_name) else {
+            panic!("Bad endpoint name");
+        };
+
+        Ok(CompareCommand {
+            service_name: service.name.clone(),
+            endpoint_name: endpoint.name.clone(),
+            environments: endpoint_matches.bind_field("environment"),
+            parameters: bind_parameters_from_matches(endpoint, endpoint_matches)?,
+            preset: endpoint_matches.bind_field("preset"),
+            headers: endpoint_matches.bind_field("header"),
+            ignore_paths: endpoint_matches.bind_field("ignore"),
+            har: endpoint_matches.bind_field("har"),
+            redact_secrets: endpoint_matches.bind_field("redact_secrets"),
+        })
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let service = config.get_service(&self.service_name).unwrap();
+        if self.environments.len() < 2 {
+            return Err(HtrsError::new("At least two environments are needed to compare, use `--env <environment>` for each"));
+        }
+
+        let mut requests = vec![];
+        for environment_name in &self.environments {
+            let Some(environment) = service.get_environment(environment_name) else {
+                return Err(HtrsError::new(format!("Unable to find environment with name or alias `{}` for service `{}`", environment_name, service.name).as_str()));
+            };
+            let call = CallServiceEndpointCommand::new(
+                service.name.clone(),
+                Some(environment.name.clone()),
+                self.endpoint_name.clone(),
+                self.parameters.clone(),
+                self.preset.clone(),
+            );
+            requests.push((environment.name.clone(), call.prepare_request(config)?));
+        }
+
+        Ok(CompareResponses(Comparison {
+            requests,
+            headers: self.headers.clone(),
+            ignore_paths: self.ignore_paths.clone(),
+            har: self.har.as_ref().map(|path| HarCapture::new(path, self.redact_secrets, config)),
+        }))
+    }
+}
+
+fn get_command_for_service(service: &Service) -> Command {
+    let mut command = Command::new(service.name.clone())
+        .arg_required_else_help(true);
+
+    if let Some(alias) = &service.alias {
+        command = command.visible_alias(alias);
+    }
+
+    for endpoint in &service.endpoints {
+        command = command.subcommand(get_command_for_endpoint(endpoint));
+    }
+
+    command
+}
+
+fn get_command_for_endpoint(endpoint: &Endpoint) -> Command {
+    let mut command = Command::new(endpoint.name.clone())
+        .arg(
+            Arg::new("environment")
+                .value_name("environment")
+                .help("An environment to call, the first is compared against each of the others")
+                .required(true)
+                .action(ArgAction::Append)
+                .long("env")
+                .short('e')
+        )
+        .arg(
+            Arg::new("header")
+                .help("A response header to compare")
+                .required(false)
+                .action(ArgAction::Append)
+                .long("header")
+        )
+        .arg(
+            Arg::new("ignore")
+                .value_name("path")
+                .help("Path of a json body value to ignore, e.g. `$.items[*].updated_at`")
+                .required(false)
+                .action(ArgAction::Append)
+                .long("ignore")
+        )
+        .arg(get_query_parameters_arg())
+        .arg(
+            Arg::new("preset")
+                .help("Use a preset to populate endpoint's parameters")
+                .long("preset")
+                .short('p')
+        );
+
+    if let Some(description) = &endpoint.description {
+        command = command.about(description);
+    }
+    if let Some(alias) = &endpoint.alias {
+        command = command.visible_alias(alias);
+    }
+
+    add_parameter_args(add_har_args(command), endpoint)
+}
@@ -0,0 +1,37 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::RootCommand;
+use crate::completions::{get_completions, COMPLETE_COMMAND};
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::HtrsAction::PrintDialogue;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+
+/// Called by completion scripts with the words of the command line being completed
+pub struct CompleteCommand {
+    pub words: Vec<String>,
+}
+
+impl CompleteCommand {
+    pub fn get_command() -> Command {
+        Command::new(COMPLETE_COMMAND)
+            .hide(true)
+            .disable_help_flag(true)
+            .arg(
+                Arg::new("words")
+                    .num_args(0..)
+                    .trailing_var_arg(true)
+                    .allow_hyphen_values(true)
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> CompleteCommand {
+        CompleteCommand {
+            words: args.bind_field("words"),
+        }
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let completions = get_completions(RootCommand::get_command(config), config, &self.words);
+        Ok(PrintDialogue(completions.join("\n")))
+    }
+}
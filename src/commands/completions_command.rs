@@ -0,0 +1,34 @@
+use crate::commands::bindings::MatchBinding;
+use crate::completions::{get_completion_script, SHELLS};
+use crate::outcomes::HtrsAction::PrintDialogue;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::builder::PossibleValuesParser;
+use clap::{Arg, ArgMatches, Command};
+
+pub struct CompletionsCommand {
+    pub shell: String,
+}
+
+impl CompletionsCommand {
+    pub fn get_command() -> Command {
+        Command::new("completions")
+            .about("Print a script that completes htrs commands from the current config")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("shell")
+                    .help("The shell to generate completions for")
+                    .required(true)
+                    .value_parser(PossibleValuesParser::new(SHELLS))
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> CompletionsCommand {
+        CompletionsCommand {
+            shell: args.bind_field("shell"),
+        }
+    }
+
+    pub fn execute(&self) -> Result<HtrsAction, HtrsError> {
+        Ok(PrintDialogue(get_completion_script(&self.shell)))
+    }
+}
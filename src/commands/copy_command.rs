@@ -0,0 +1,40 @@
+use crate::commands::copy_command::copy_environment_command::CopyEnvironmentCommand;
+use crate::commands::copy_command::copy_service_command::CopyServiceCommand;
+use crate::commands::copy_command::CopyCommand::{Environment, Service};
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{ArgMatches, Command};
+
+mod copy_service_command;
+mod copy_environment_command;
+
+pub enum CopyCommand {
+    Service(CopyServiceCommand),
+    Environment(CopyEnvironmentCommand),
+}
+
+impl CopyCommand {
+    pub fn get_command() -> Command {
+        Command::new("copy")
+            .visible_alias("cp")
+            .about("Copy an existing item in config to a new item")
+            .arg_required_else_help(true)
+            .subcommand(CopyServiceCommand::get_command())
+            .subcommand(CopyEnvironmentCommand::get_command())
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> CopyCommand {
+        match args.subcommand() {
+            Some(("service", copy_service_matches)) => Service(CopyServiceCommand::bind_from_matches(copy_service_matches)),
+            Some(("environment" | "env", copy_environment_matches)) => Environment(CopyEnvironmentCommand::bind_from_matches(copy_environment_matches)),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        match self {
+            Service(copy_service_command) => copy_service_command.execute(config),
+            Environment(copy_environment_command) => copy_environment_command.execute(config),
+        }
+    }
+}
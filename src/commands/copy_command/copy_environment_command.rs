@@ -0,0 +1,115 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+
+pub struct CopyEnvironmentCommand {
+    pub service: String,
+    pub source: String,
+    pub name: String,
+    pub to_service: Option<String>,
+    pub alias: Option<String>,
+    pub host: Option<String>,
+    pub default: bool,
+}
+
+impl CopyEnvironmentCommand {
+    pub fn get_command() -> Command {
+        Command::new("environment")
+            .visible_alias("env")
+            .about("Copy an environment, including its headers, to a new environment")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("environment")
+                    .help("Name or alias of the environment to copy")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("name")
+                    .help("The unique name for the new environment")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("service")
+                    .help("The service of the environment to copy")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+            .arg(
+                Arg::new("to_service")
+                    .help("The service to create the new environment for, defaults to the same service")
+                    .required(false)
+                    .long("to-service")
+            )
+            .arg(
+                Arg::new("host")
+                    .help("The host for the new environment, defaults to the host being copied")
+                    .required(false)
+                    .long("host")
+            )
+            .arg(
+                Arg::new("alias")
+                    .help("The unique alias for the new environment")
+                    .required(false)
+                    .long("alias")
+                    .short('a')
+            )
+            .arg(
+                Arg::new("default")
+                    .help("Flag to determine if the new environment should be the default")
+                    .required(false)
+                    .num_args(0)
+                    .long("default")
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> CopyEnvironmentCommand {
+        CopyEnvironmentCommand {
+            service: args.bind_field("service"),
+            source: args.bind_field("environment"),
+            name: args.bind_field("name"),
+            to_service: args.bind_field("to_service"),
+            alias: args.bind_field("alias"),
+            host: args.bind_field("host"),
+            default: args.bind_field("default"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service(&self.service) else {
+            return Err(HtrsError::new(format!("No service found with name or alias `{}`", self.service).as_str()));
+        };
+        let Some(source) = service.get_environment(&self.source) else {
+            return Err(HtrsError::new(format!("Unable to find environment with name or alias `{}` for service `{}`", self.source, service.name).as_str()));
+        };
+
+        // Aliases have to be unique so the source's alias is never carried over
+        let mut environment = source.clone();
+        environment.name = self.name.clone();
+        environment.alias = self.alias.clone();
+        environment.default = self.default;
+        if let Some(host) = &self.host {
+            environment.host = host.clone();
+        }
+
+        let target_name = self.to_service.as_ref().unwrap_or(&self.service);
+        let Some(target) = config.get_service_mut(target_name) else {
+            return Err(HtrsError::new(format!("No service found with name or alias `{}`", target_name).as_str()));
+        };
+        if target.get_environment(&self.name).is_some() {
+            return Err(HtrsError::new(format!("Service `{}` already has an environment with name or alias `{}`", target.name, self.name).as_str()));
+        }
+        if let Some(alias) = self.alias.as_ref() && target.get_environment(alias).is_some() {
+            return Err(HtrsError::new(format!("Service `{}` already has an environment with name or alias `{}`", target.name, alias).as_str()));
+        }
+
+        if self.default && let Some(existing_default_environment) = target.get_default_environment_mut() {
+            existing_default_environment.default = false;
+        }
+
+        target.environments.push(environment);
+        Ok(UpdateConfig)
+    }
+}
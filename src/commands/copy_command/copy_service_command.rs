@@ -0,0 +1,63 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+
+pub struct CopyServiceCommand {
+    pub source: String,
+    pub name: String,
+    pub alias: Option<String>,
+}
+
+impl CopyServiceCommand {
+    pub fn get_command() -> Command {
+        Command::new("service")
+            .about("Copy a service, including its environments, endpoints & headers, to a new service")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("service")
+                    .help("Name or alias of the service to copy")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("name")
+                    .help("The unique name of the service to create")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("alias")
+                    .help("The unique alias for the new service")
+                    .long("alias")
+                    .short('a')
+                    .required(false)
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> CopyServiceCommand {
+        CopyServiceCommand {
+            source: args.bind_field("service"),
+            name: args.bind_field("name"),
+            alias: args.bind_field("alias"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(source) = config.get_service(&self.source) else {
+            return Err(HtrsError::new(format!("No service found with name or alias `{}`", self.source).as_str()));
+        };
+        if config.get_service(&self.name).is_some() {
+            return Err(HtrsError::new(format!("A service already exists with the name or alias '{}'", self.name).as_str()));
+        }
+        if let Some(alias) = &self.alias && config.get_service(alias).is_some() {
+            return Err(HtrsError::new(format!("A service already exists with the name or alias '{alias}'").as_str()));
+        }
+
+        // Aliases have to be unique so the source's alias is never carried over
+        let mut service = source.clone();
+        service.name = self.name.clone();
+        service.alias = self.alias.clone();
+        config.services.push(service);
+        Ok(UpdateConfig)
+    }
+}
@@ -1,8 +1,13 @@
 use crate::commands::delete_command::delete_endpoint_command::DeleteEndpointCommand;
 use crate::commands::delete_command::delete_environment_command::DeleteEnvironmentCommand;
 use crate::commands::delete_command::delete_header_command::DeleteHeaderCommand;
+use crate::commands::delete_command::delete_mock_command::DeleteMockCommand;
+use crate::commands::delete_command::delete_pagination_command::DeletePaginationCommand;
 use crate::commands::delete_command::delete_preset_command::DeletePresetCommand;
-use crate::commands::delete_command::DeleteCommand::{Endpoint, Environment, Header, Preset, Service};
+use crate::commands::delete_command::delete_step_command::DeleteStepCommand;
+use crate::commands::delete_command::delete_test_command::DeleteTestCommand;
+use crate::commands::delete_command::delete_workflow_command::DeleteWorkflowCommand;
+use crate::commands::delete_command::DeleteCommand::{Endpoint, Environment, Header, Mock, Pagination, Preset, Service, Step, Test, Workflow};
 use crate::config::current_config::HtrsConfig;
 use crate::outcomes::{HtrsAction, HtrsError};
 use clap::{ArgMatches, Command};
@@ -13,6 +18,11 @@ mod delete_environment_command;
 mod delete_header_command;
 mod delete_endpoint_command;
 mod delete_preset_command;
+mod delete_test_command;
+mod delete_workflow_command;
+mod delete_step_command;
+mod delete_mock_command;
+mod delete_pagination_command;
 
 pub enum DeleteCommand {
     Service(DeleteServiceCommand),
@@ -20,6 +30,11 @@ pub enum DeleteCommand {
     Header(DeleteHeaderCommand),
     Endpoint(DeleteEndpointCommand),
     Preset(DeletePresetCommand),
+    Test(DeleteTestCommand),
+    Workflow(DeleteWorkflowCommand),
+    Step(DeleteStepCommand),
+    Mock(DeleteMockCommand),
+    Pagination(DeletePaginationCommand),
 }
 
 impl DeleteCommand {
@@ -33,6 +48,11 @@ impl DeleteCommand {
             .subcommand(DeleteHeaderCommand::get_command())
             .subcommand(DeleteEndpointCommand::get_command())
             .subcommand(DeletePresetCommand::get_command())
+            .subcommand(DeleteTestCommand::get_command())
+            .subcommand(DeleteWorkflowCommand::get_command())
+            .subcommand(DeleteStepCommand::get_command())
+            .subcommand(DeleteMockCommand::get_command())
+            .subcommand(DeletePaginationCommand::get_command())
     }
 
     pub fn bind_from_matches(args: &ArgMatches) -> DeleteCommand {
@@ -42,6 +62,11 @@ impl DeleteCommand {
             Some(("header", delete_header_matches)) => Header(DeleteHeaderCommand::bind_from_matches(delete_header_matches)),
             Some(("endpoint", delete_endpoint_matches)) => Endpoint(DeleteEndpointCommand::bind_from_matches(delete_endpoint_matches)),
             Some(("preset", delete_preset_matches)) => Preset(DeletePresetCommand::bind_from_matches(delete_preset_matches)),
+            Some(("test", delete_test_matches)) => Test(DeleteTestCommand::bind_from_matches(delete_test_matches)),
+            Some(("workflow", delete_workflow_matches)) => Workflow(DeleteWorkflowCommand::bind_from_matches(delete_workflow_matches)),
+            Some(("step", delete_step_matches)) => Step(DeleteStepCommand::bind_from_matches(delete_step_matches)),
+            Some(("mock", delete_mock_matches)) => Mock(DeleteMockCommand::bind_from_matches(delete_mock_matches)),
+            Some(("pagination", delete_pagination_matches)) => Pagination(DeletePaginationCommand::bind_from_matches(delete_pagination_matches)),
             _ => unreachable!(),
         }
     }
@@ -53,6 +78,11 @@ impl DeleteCommand {
             Header(delete_header_command) => delete_header_command.execute(config),
             Endpoint(delete_endpoint_command) => delete_endpoint_command.execute(config),
             Preset(delete_preset_command) => delete_preset_command.execute(config),
+            Test(delete_test_command) => delete_test_command.execute(config),
+            Workflow(delete_workflow_command) => delete_workflow_command.execute(config),
+            Step(delete_step_command) => delete_step_command.execute(config),
+            Mock(delete_mock_command) => delete_mock_command.execute(config),
+            Pagination(delete_pagination_command) => delete_pagination_command.execute(config),
         }
     }
 }
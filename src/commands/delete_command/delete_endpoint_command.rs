@@ -35,9 +35,17 @@ impl DeleteEndpointCommand {
     }
 
     pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
-        let Some(service) = config.get_service_mut(&self.service) else {
+        let Some(service) = config.get_service(&self.service) else {
             return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
         };
+        if let Some(endpoint) = service.get_endpoint(&self.name) {
+            let references = config.get_endpoint_references(service, endpoint);
+            if !references.is_empty() {
+                return Err(HtrsError::new(format!("Endpoint `{}` is still used by {}, change or delete them first", endpoint.name, references.join(", ")).as_str()));
+            }
+        }
+
+        let service = config.get_service_mut(&self.service).unwrap();
         match service.remove_endpoint(&self.name) {
             true => Ok(UpdateConfig),
             false => Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", self.name, service.name).as_str()))
@@ -69,6 +69,10 @@ impl DeleteHeaderCommand {
         };
 
         header_item.clear_header(self.header_name.clone());
+        // Left marked as a secret it would still redact any header of the same name added later
+        if !config.has_header(&self.header_name) {
+            config.remove_secret_header(&self.header_name);
+        }
         Ok(UpdateConfig)
     }
 }
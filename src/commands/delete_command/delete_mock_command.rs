@@ -0,0 +1,50 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+
+pub struct DeleteMockCommand {
+    pub endpoint: String,
+    pub service: String,
+}
+
+impl DeleteMockCommand {
+    pub fn get_command() -> Command {
+        Command::new("mock")
+            .about("Delete the response served for an endpoint by `htrs mock`")
+            .arg(
+                Arg::new("endpoint")
+                    .help("The endpoint to delete the response for")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("service")
+                    .help("The service the endpoint belongs to")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> DeleteMockCommand {
+        DeleteMockCommand {
+            endpoint: args.bind_field("endpoint"),
+            service: args.bind_field("service"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service_mut(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+        let service_name = service.name.clone();
+        let Some(endpoint) = service.get_endpoint_mut(&self.endpoint) else {
+            return Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", self.endpoint, service_name).as_str()));
+        };
+        match endpoint.mock.take() {
+            Some(_) => Ok(UpdateConfig),
+            None => Err(HtrsError::new(format!("Endpoint `{}` for service `{}` has no mock response", endpoint.name, service_name).as_str())),
+        }
+    }
+}
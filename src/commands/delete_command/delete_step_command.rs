@@ -0,0 +1,59 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use crate::workflow::{get_extracted_names, get_value_references};
+use clap::{Arg, ArgMatches, Command};
+
+pub struct DeleteStepCommand {
+    pub name: String,
+    pub workflow: String,
+}
+
+impl DeleteStepCommand {
+    pub fn get_command() -> Command {
+        Command::new("step")
+            .about("Delete a step from a workflow")
+            .arg(
+                Arg::new("name")
+                    .help("The name of the step")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("workflow")
+                    .help("The workflow that the step is part of")
+                    .required(true)
+                    .long("workflow")
+                    .short('w')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> DeleteStepCommand {
+        DeleteStepCommand {
+            name: args.bind_field("name"),
+            workflow: args.bind_field("workflow"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(workflow) = config.get_workflow_mut(&self.workflow) else {
+            return Err(HtrsError::new(format!("No workflow could be found with name `{}`", self.workflow).as_str()));
+        };
+        let Some(index) = workflow.steps.iter().position(|s| s.name == self.name) else {
+            return Err(HtrsError::new(format!("No step could be found with name `{}` for workflow `{}`", self.name, workflow.name).as_str()));
+        };
+
+        // Later steps can't be left using values that only the removed step took
+        let removed = workflow.steps.remove(index);
+        for (position, step) in workflow.steps.iter().enumerate().skip(index) {
+            let extracted_names = get_extracted_names(&workflow.steps[..position]);
+            let uses_removed_value = step.parameters.values()
+                .flat_map(|v| get_value_references(v))
+                .any(|n| !extracted_names.contains(&n.as_str()));
+            if uses_removed_value {
+                return Err(HtrsError::new(format!("Step `{}` uses a value taken by step `{}`", step.name, removed.name).as_str()));
+            }
+        }
+        Ok(UpdateConfig)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+
+pub struct DeleteTestCommand {
+    pub name: String,
+    pub service: String,
+}
+
+impl DeleteTestCommand {
+    pub fn get_command() -> Command {
+        Command::new("test")
+            .about("Delete an existing test case from config")
+            .arg(
+                Arg::new("name")
+                    .help("The name of the test")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("service")
+                    .help("The service name or alias that the test is defined for")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> DeleteTestCommand {
+        DeleteTestCommand {
+            name: args.bind_field("name"),
+            service: args.bind_field("service"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service_mut(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+        match service.remove_test(&self.name) {
+            true => Ok(UpdateConfig),
+            false => Err(HtrsError::new(format!("No test could be found with name `{}` for service `{}`", self.name, service.name).as_str()))
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+
+pub struct DeleteWorkflowCommand {
+    pub name: String,
+}
+
+impl DeleteWorkflowCommand {
+    pub fn get_command() -> Command {
+        Command::new("workflow")
+            .about("Delete an existing workflow from config")
+            .arg(
+                Arg::new("name")
+                    .help("The name of the workflow")
+                    .required(true)
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> DeleteWorkflowCommand {
+        DeleteWorkflowCommand {
+            name: args.bind_field("name"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        match config.remove_workflow(&self.name) {
+            true => Ok(UpdateConfig),
+            false => Err(HtrsError::new(format!("No workflow could be found with name `{}`", self.name).as_str()))
+        }
+    }
+}
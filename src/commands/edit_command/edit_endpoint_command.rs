@@ -1,4 +1,5 @@
 use crate::commands::bindings::MatchBinding;
+use crate::common::{parse_method, parse_parameter_descriptions};
 use crate::config::current_config::{HtrsConfig, QueryParameter};
 use crate::outcomes::HtrsAction::UpdateConfig;
 use crate::outcomes::{HtrsAction, HtrsError};
@@ -9,8 +10,14 @@ pub struct EditEndpointCommand {
     pub service: String,
     pub new_name: Option<String>,
     pub new_path: Option<String>,
+    pub new_method: Option<String>,
     pub new_query_parameters: Vec<String>,
     pub delete_query_parameters: Vec<String>,
+    pub new_description: Option<String>,
+    pub new_alias: Option<String>,
+    pub new_tags: Vec<String>,
+    pub delete_tags: Vec<String>,
+    pub parameter_descriptions: Vec<String>,
 }
 
 impl EditEndpointCommand {
@@ -41,6 +48,12 @@ impl EditEndpointCommand {
                     .required(false)
                     .long("new-path")
             )
+            .arg(
+                Arg::new("new_method")
+                    .help("The new http method for the endpoint")
+                    .required(false)
+                    .long("new-method")
+            )
             .arg(
                 Arg::new("new_query")
                     .help("A new query parameter for the endpoint")
@@ -55,6 +68,40 @@ impl EditEndpointCommand {
                     .action(ArgAction::Append)
                     .long("del-query")
             )
+            .arg(
+                Arg::new("new_description")
+                    .help("The new description for the endpoint")
+                    .required(false)
+                    .long("new-description")
+            )
+            .arg(
+                Arg::new("new_alias")
+                    .help("The new alias for the endpoint")
+                    .required(false)
+                    .long("new-alias")
+            )
+            .arg(
+                Arg::new("new_tag")
+                    .help("A new tag for the endpoint")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("new-tag")
+            )
+            .arg(
+                Arg::new("delete_tag")
+                    .help("An existing tag to be removed")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("del-tag")
+            )
+            .arg(
+                Arg::new("param_help")
+                    .value_name("name=text")
+                    .help("Help text for a path or query parameter, in the format `name=text`")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("param-help")
+            )
     }
 
     pub fn bind_from_matches(args: &ArgMatches) -> EditEndpointCommand {
@@ -63,8 +110,14 @@ impl EditEndpointCommand {
             service: args.bind_field("service"),
             new_name: args.bind_field("new_name"),
             new_path: args.bind_field("new_path"),
+            new_method: args.bind_field("new_method"),
             new_query_parameters: args.bind_field("new_query"),
             delete_query_parameters: args.bind_field("delete_query"),
+            new_description: args.bind_field("new_description"),
+            new_alias: args.bind_field("new_alias"),
+            new_tags: args.bind_field("new_tag"),
+            delete_tags: args.bind_field("delete_tag"),
+            parameter_descriptions: args.bind_field("param_help"),
         }
     }
 
@@ -79,8 +132,24 @@ impl EditEndpointCommand {
         if let Some(new_name) = &self.new_name && service.get_endpoint(new_name).is_some() {
             return Err(HtrsError::new(format!("An endpoint already exists with name `{}` for service `{}`", new_name, service.name).as_str()));
         };
+        let current_name = service.get_endpoint(&self.name).unwrap().name.clone();
+        if let Some(new_alias) = &self.new_alias {
+            let is_taken = self.new_name.as_ref() == Some(new_alias) || service.endpoints.iter()
+                .filter(|e| e.name != current_name)
+                .any(|e| &e.name == new_alias || e.alias.as_ref() == Some(new_alias));
+            if is_taken {
+                return Err(HtrsError::new(format!("An endpoint already exists with name `{}` for service `{}`", new_alias, service.name).as_str()));
+            }
+        }
+
+        let new_method = match &self.new_method {
+            Some(new_method) => Some(parse_method(new_method)?),
+            None => None,
+        };
 
+        let service_name = service.name.clone();
         let endpoint = service.get_endpoint_mut(&self.name).unwrap();
+        let old_names = vec![Some(endpoint.name.clone()), endpoint.alias.clone()];
 
         if let Some(new_name) = &self.new_name {
             endpoint.name = new_name.clone();
@@ -88,6 +157,9 @@ impl EditEndpointCommand {
         if let Some(new_path) = &self.new_path {
             endpoint.path_template = new_path.clone();
         };
+        if let Some(new_method) = new_method {
+            endpoint.method = new_method;
+        };
         if !self.new_query_parameters.is_empty() {
             endpoint.query_parameters.extend(self.new_query_parameters.iter().map(|q| QueryParameter::from_shorthand(q)));
         }
@@ -97,6 +169,30 @@ impl EditEndpointCommand {
                 .cloned()
                 .collect();
         }
+        let parameter_names = endpoint.get_parameter_names();
+        endpoint.parameter_descriptions.retain(|name, _| parameter_names.contains(name));
+        if let Some(new_description) = &self.new_description {
+            endpoint.description = Some(new_description.clone());
+        };
+        if let Some(new_alias) = &self.new_alias {
+            endpoint.alias = Some(new_alias.clone());
+        };
+        for tag in &self.new_tags {
+            if !endpoint.tags.contains(tag) {
+                endpoint.tags.push(tag.clone());
+            }
+        }
+        endpoint.tags.retain(|t| !self.delete_tags.contains(t));
+        let parameter_descriptions = parse_parameter_descriptions(endpoint, &self.parameter_descriptions)?;
+        endpoint.parameter_descriptions.extend(parameter_descriptions);
+
+        // Anything that called the endpoint by a name it no longer has follows it to its new name
+        let new_name = endpoint.name.clone();
+        let stale_names: Vec<String> = old_names.into_iter()
+            .flatten()
+            .filter(|name| name != &endpoint.name && endpoint.alias.as_ref() != Some(name))
+            .collect();
+        config.rename_endpoint_references(&service_name, &stale_names, &new_name);
         Ok(UpdateConfig)
     }
 }
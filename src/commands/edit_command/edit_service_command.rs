@@ -7,6 +7,7 @@ pub struct EditServiceCommand {
     pub name: String,
     pub new_name: Option<String>,
     pub new_alias: Option<String>,
+    pub health_endpoint: Option<String>,
 }
 
 impl EditServiceCommand {
@@ -32,6 +33,12 @@ impl EditServiceCommand {
                     .short('a')
                     .required(false)
             )
+            .arg(
+                Arg::new("health-endpoint")
+                    .help("The endpoint called by `htrs ping` to check the service is healthy")
+                    .long("health-endpoint")
+                    .required(false)
+            )
     }
 
     pub fn bind_from_matches(args: &ArgMatches) -> EditServiceCommand {
@@ -39,6 +46,7 @@ impl EditServiceCommand {
             name: args.bind_field("name"),
             new_name: args.bind_field("new-name"),
             new_alias: args.bind_field("new-alias"),
+            health_endpoint: args.bind_field("health-endpoint"),
         }
     }
 
@@ -60,6 +68,12 @@ impl EditServiceCommand {
         if self.new_alias.is_some() {
             service.alias = self.new_alias.clone();
         }
+        if let Some(health_endpoint) = &self.health_endpoint {
+            let Some(endpoint) = service.get_endpoint(health_endpoint) else {
+                return Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", health_endpoint, service.name).as_str()));
+            };
+            service.health_endpoint = Some(endpoint.name.clone());
+        }
         Ok(HtrsAction::UpdateConfig)
     }
 }
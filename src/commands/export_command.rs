@@ -0,0 +1,39 @@
+use crate::commands::export_command::export_http_file_command::ExportHttpFileCommand;
+use crate::commands::export_command::export_postman_command::ExportPostmanCommand;
+use crate::commands::export_command::ExportCommand::{HttpFile, Postman};
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{ArgMatches, Command};
+
+mod export_postman_command;
+mod export_http_file_command;
+
+pub enum ExportCommand {
+    Postman(ExportPostmanCommand),
+    HttpFile(ExportHttpFileCommand),
+}
+
+impl ExportCommand {
+    pub fn get_command() -> Command {
+        Command::new("export")
+            .about("Export items from config for use in other tools")
+            .arg_required_else_help(true)
+            .subcommand(ExportPostmanCommand::get_command())
+            .subcommand(ExportHttpFileCommand::get_command())
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ExportCommand {
+        match args.subcommand() {
+            Some(("postman", postman_matches)) => Postman(ExportPostmanCommand::bind_from_matches(postman_matches)),
+            Some(("http-file", http_file_matches)) => HttpFile(ExportHttpFileCommand::bind_from_matches(http_file_matches)),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        match self {
+            Postman(export_postman_command) => export_postman_command.execute(config),
+            HttpFile(export_http_file_command) => export_http_file_command.execute(config),
+        }
+    }
+}
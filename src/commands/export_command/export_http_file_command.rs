@@ -0,0 +1,72 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::HtrsConfig;
+use crate::formats::http_file::service_to_http_file;
+use crate::outcomes::HtrsAction::{PrintDialogue, WriteFiles};
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command, ValueHint};
+use std::path::PathBuf;
+
+pub struct ExportHttpFileCommand {
+    pub service: String,
+    pub environment: Option<String>,
+    pub output: Option<String>,
+}
+
+impl ExportHttpFileCommand {
+    pub fn get_command() -> Command {
+        Command::new("http-file")
+            .about("Export a service's endpoints as a .http file")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("service")
+                    .help("Name or alias of the service to export")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("environment")
+                    .help("Name or alias of the environment to use as the host, defaults to the service's default environment")
+                    .required(false)
+                    .long("environment")
+                    .short('e')
+            )
+            .arg(
+                Arg::new("output")
+                    .help("File to write the requests to, prints the requests if not given")
+                    .value_hint(ValueHint::FilePath)
+                    .required(false)
+                    .long("output")
+                    .short('o')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ExportHttpFileCommand {
+        ExportHttpFileCommand {
+            service: args.bind_field("service"),
+            environment: args.bind_field("environment"),
+            output: args.bind_field("output"),
+        }
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+
+        let environment = match &self.environment {
+            Some(environment_name) => match service.get_environment(environment_name) {
+                Some(environment) => environment,
+                None => return Err(HtrsError::new(format!("Unable to find environment with name or alias `{}` for service `{}`", environment_name, service.name).as_str())),
+            },
+            None => match service.get_default_environment() {
+                Some(environment) => environment,
+                None => return Err(HtrsError::new(format!("Service `{}` has no default environment, use `--environment <name>` to choose one", service.name).as_str())),
+            },
+        };
+
+        let http_file = service_to_http_file(service, environment, &config.headers);
+        match &self.output {
+            Some(output) => Ok(WriteFiles(vec![(PathBuf::from(output), http_file)])),
+            None => Ok(PrintDialogue(http_file.trim_end().to_string())),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::HtrsConfig;
+use crate::formats::postman::{environment_to_postman, service_to_collection};
+use crate::outcomes::HtrsAction::{PrintDialogue, WriteFiles};
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command, ValueHint};
+use serde::Serialize;
+use std::path::PathBuf;
+
+pub struct ExportPostmanCommand {
+    pub service: String,
+    pub output_dir: Option<String>,
+}
+
+impl ExportPostmanCommand {
+    pub fn get_command() -> Command {
+        Command::new("postman")
+            .about("Export a service as a postman collection")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("service")
+                    .help("Name or alias of the service to export")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("output_dir")
+                    .help("Directory to write the collection and an environment file per environment to, prints the collection if not given")
+                    .value_hint(ValueHint::DirPath)
+                    .required(false)
+                    .long("output-dir")
+                    .short('o')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ExportPostmanCommand {
+        ExportPostmanCommand {
+            service: args.bind_field("service"),
+            output_dir: args.bind_field("output_dir"),
+        }
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+
+        let collection = to_json(&service_to_collection(service, &config.headers))?;
+        let Some(output_dir) = &self.output_dir else {
+            return Ok(PrintDialogue(collection));
+        };
+
+        let output_dir = PathBuf::from(output_dir);
+        let mut files = vec![(output_dir.join(format!("{}.postman_collection.json", service.name)), collection)];
+        for environment in &service.environments {
+            files.push((
+                output_dir.join(format!("{}.{}.postman_environment.json", service.name, environment.name)),
+                to_json(&environment_to_postman(environment))?,
+            ));
+        }
+        Ok(WriteFiles(files))
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String, HtrsError> {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => Ok(json),
+        Err(e) => Err(HtrsError::new(format!("Failed to serialize postman export: {}", e).as_str())),
+    }
+}
@@ -0,0 +1,59 @@
+use crate::commands::history_command::clear_history_command::ClearHistoryCommand;
+use crate::commands::history_command::list_history_command::ListHistoryCommand;
+use crate::commands::history_command::replay_history_command::ReplayHistoryCommand;
+use crate::commands::history_command::show_history_command::ShowHistoryCommand;
+use crate::commands::history_command::HistoryCommand::{Clear, List, Replay, Show};
+use crate::config::current_config::HtrsConfig;
+use crate::history::HistoryEntry;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{ArgMatches, Command};
+
+mod list_history_command;
+mod show_history_command;
+mod replay_history_command;
+mod clear_history_command;
+
+pub enum HistoryCommand {
+    List(ListHistoryCommand),
+    Show(ShowHistoryCommand),
+    Replay(ReplayHistoryCommand),
+    Clear(ClearHistoryCommand),
+}
+
+impl HistoryCommand {
+    pub fn get_command() -> Command {
+        Command::new("history")
+            .about("View & replay previously sent requests")
+            .arg_required_else_help(true)
+            .subcommand(ListHistoryCommand::get_command())
+            .subcommand(ShowHistoryCommand::get_command())
+            .subcommand(ReplayHistoryCommand::get_command())
+            .subcommand(ClearHistoryCommand::get_command())
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> HistoryCommand {
+        match args.subcommand() {
+            Some(("list", list_matches)) => List(ListHistoryCommand::bind_from_matches(list_matches)),
+            Some(("show", show_matches)) => Show(ShowHistoryCommand::bind_from_matches(show_matches)),
+            Some(("replay", replay_matches)) => Replay(ReplayHistoryCommand::bind_from_matches(replay_matches)),
+            Some(("clear", _)) => Clear(ClearHistoryCommand),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        match self {
+            List(list_command) => list_command.execute(),
+            Show(show_command) => show_command.execute(),
+            Replay(replay_command) => replay_command.execute(config),
+            Clear(clear_command) => clear_command.execute(),
+        }
+    }
+}
+
+fn get_history_entry(entries: Vec<HistoryEntry>, id: u64) -> Result<HistoryEntry, HtrsError> {
+    match entries.into_iter().find(|e| e.id == id) {
+        Some(entry) => Ok(entry),
+        None => Err(HtrsError::new(format!("No request could be found in history with id `{}`", id).as_str())),
+    }
+}
@@ -0,0 +1,18 @@
+use crate::history::save_history;
+use crate::outcomes::HtrsAction::PrintDialogue;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::Command;
+
+pub struct ClearHistoryCommand;
+
+impl ClearHistoryCommand {
+    pub fn get_command() -> Command {
+        Command::new("clear")
+            .about("Remove all requests from history")
+    }
+
+    pub fn execute(&self) -> Result<HtrsAction, HtrsError> {
+        save_history(&[])?;
+        Ok(PrintDialogue("Cleared request history".to_string()))
+    }
+}
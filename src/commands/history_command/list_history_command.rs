@@ -0,0 +1,63 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::HistoryEntryView;
+use crate::history::load_history;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{value_parser, Arg, ArgMatches, Command};
+
+pub struct ListHistoryCommand {
+    pub limit: u64,
+    pub service: Option<String>,
+    pub format: OutputFormat,
+}
+
+impl ListHistoryCommand {
+    pub fn get_command() -> Command {
+        Command::new("list")
+            .visible_alias("ls")
+            .about("List the most recently sent requests")
+            .arg(OutputFormat::get_arg())
+            .arg(
+                Arg::new("limit")
+                    .help("Maximum number of requests to list")
+                    .required(false)
+                    .value_parser(value_parser!(u64))
+                    .default_value("20")
+                    .long("limit")
+                    .short('n')
+            )
+            .arg(
+                Arg::new("service")
+                    .help("Only list requests made to this service")
+                    .required(false)
+                    .long("service")
+                    .short('s')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ListHistoryCommand {
+        ListHistoryCommand {
+            limit: args.bind_field("limit"),
+            service: args.bind_field("service"),
+            format: OutputFormat::bind_from_matches(args),
+        }
+    }
+
+    pub fn execute(&self) -> Result<HtrsAction, HtrsError> {
+        let mut entries = load_history()?;
+        if let Some(service) = &self.service {
+            entries.retain(|e| &e.service == service);
+        }
+        entries.reverse();
+        entries.truncate(self.limit as usize);
+
+        let views: Vec<HistoryEntryView> = entries.iter().map(HistoryEntryView::new).collect();
+        self.format.print(&views, || match entries.is_empty() {
+            true => "No requests in history".to_string(),
+            false => entries.iter()
+                .map(|e| format!(" {} | {} | {} | {} {} {} @ {} | {}ms", e.id, e.timestamp, e.status, e.method, e.service, e.endpoint, e.environment, e.duration_ms))
+                .collect::<Vec<String>>()
+                .join("\n"),
+        })
+    }
+}
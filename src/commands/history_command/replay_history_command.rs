@@ -0,0 +1,76 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::call_command::CallServiceEndpointCommand;
+use crate::commands::history_command::get_history_entry;
+use crate::config::current_config::HtrsConfig;
+use crate::history::load_history;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{value_parser, Arg, ArgMatches, Command};
+
+pub struct ReplayHistoryCommand {
+    pub id: u64,
+    pub environment: Option<String>,
+    pub show_body: bool,
+}
+
+impl ReplayHistoryCommand {
+    pub fn get_command() -> Command {
+        Command::new("replay")
+            .about("Send a request from history again, using the current config for the endpoint")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("id")
+                    .help("Id of the request to replay")
+                    .required(true)
+                    .value_parser(value_parser!(u64))
+            )
+            .arg(
+                Arg::new("environment")
+                    .help("Environment to send the request to instead of the original environment")
+                    .required(false)
+                    .long("environment")
+                    .short('e')
+            )
+            .arg(
+                Arg::new("show_body")
+                    .help("Print the response body")
+                    .required(false)
+                    .num_args(0)
+                    .long("body")
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ReplayHistoryCommand {
+        ReplayHistoryCommand {
+            id: args.bind_field("id"),
+            environment: args.bind_field("environment"),
+            show_body: args.bind_field("show_body"),
+        }
+    }
+
+    /// Replays a request by calling its endpoint again with the same parameters, so that secret
+    /// headers which were redacted in history are resolved from config
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let entry = get_history_entry(load_history()?, self.id)?;
+        let Some(service) = config.get_service(&entry.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", entry.service).as_str()));
+        };
+        let Some(endpoint) = service.get_endpoint(&entry.endpoint) else {
+            return Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", entry.endpoint, service.name).as_str()));
+        };
+        let environment_name = self.environment.as_ref().unwrap_or(&entry.environment);
+        let Some(environment) = service.get_environment(environment_name) else {
+            return Err(HtrsError::new(format!("Unable to find environment with name or alias `{}` for service `{}`", environment_name, service.name).as_str()));
+        };
+
+        let mut call_command = CallServiceEndpointCommand::new(
+            service.name.clone(),
+            Some(environment.name.clone()),
+            endpoint.name.clone(),
+            entry.parameters,
+            None,
+        );
+        call_command.show_body = self.show_body;
+        call_command.save_response = entry.response_body.is_some();
+        call_command.execute_command(config)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::history_command::get_history_entry;
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::HistoryEntryView;
+use crate::history::{load_history, HistoryEntry};
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{value_parser, Arg, ArgMatches, Command};
+
+pub struct ShowHistoryCommand {
+    pub id: u64,
+    pub format: OutputFormat,
+}
+
+impl ShowHistoryCommand {
+    pub fn get_command() -> Command {
+        Command::new("show")
+            .about("Show the details of a request in history")
+            .arg_required_else_help(true)
+            .arg(OutputFormat::get_arg())
+            .arg(
+                Arg::new("id")
+                    .help("Id of the request to show")
+                    .required(true)
+                    .value_parser(value_parser!(u64))
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ShowHistoryCommand {
+        ShowHistoryCommand {
+            id: args.bind_field("id"),
+            format: OutputFormat::bind_from_matches(args),
+        }
+    }
+
+    pub fn execute(&self) -> Result<HtrsAction, HtrsError> {
+        let entry = get_history_entry(load_history()?, self.id)?;
+        self.format.print(&HistoryEntryView::new(&entry), || Self::get_entry_string(&entry))
+    }
+
+    fn get_entry_string(entry: &HistoryEntry) -> String {
+        let mut text = String::new();
+        text.push_str(format!("Id: {}\n", entry.id).as_str());
+        text.push_str(format!("Sent: {}\n", entry.timestamp).as_str());
+        text.push_str(format!("Service: {}\n", entry.service).as_str());
+        text.push_str(format!("Endpoint: {}\n", entry.endpoint).as_str());
+        text.push_str(format!("Environment: {}\n", entry.environment).as_str());
+        text.push_str(format!("Request: {} {}\n", entry.method, entry.url).as_str());
+        text.push_str(format!("Status: {}\n", entry.status).as_str());
+        text.push_str(format!("Duration: {}ms\n", entry.duration_ms).as_str());
+        if let Some(connect_ms) = entry.connect_ms {
+            text.push_str(format!("Connect: {}ms\n", connect_ms).as_str());
+        }
+        if let Some(first_byte_ms) = entry.first_byte_ms {
+            text.push_str(format!("First byte: {}ms\n", first_byte_ms).as_str());
+        }
+        if let (Some(response_size), Some(decoded_size)) = (entry.response_size, entry.decoded_size) {
+            text.push_str(format!("Size: {} bytes ({} bytes decoded)\n", response_size, decoded_size).as_str());
+        }
+
+        text.push_str("Parameters:\n");
+        if entry.parameters.is_empty() {
+            text.push_str("  (no parameters)\n");
+        }
+        let mut parameters: Vec<(&String, &String)> = entry.parameters.iter().collect();
+        parameters.sort();
+        for (name, value) in parameters {
+            text.push_str(format!(" - {} = {}\n", name, value).as_str());
+        }
+
+        text.push_str("Headers:\n");
+        for (name, value) in &entry.request_headers {
+            text.push_str(format!(" - {}: {}\n", name, value).as_str());
+        }
+
+        if let Some(body) = &entry.request_body {
+            text.push_str(format!("Body:\n{}\n", body).as_str());
+        }
+        if let Some(body) = &entry.response_body {
+            text.push_str(format!("Response:\n{}\n", body).as_str());
+        }
+
+        text
+    }
+}
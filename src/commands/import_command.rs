@@ -0,0 +1,45 @@
+use crate::commands::import_command::import_curl_command::ImportCurlCommand;
+use crate::commands::import_command::import_http_file_command::ImportHttpFileCommand;
+use crate::commands::import_command::import_postman_command::ImportPostmanCommand;
+use crate::commands::import_command::ImportCommand::{Curl, HttpFile, Postman};
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{ArgMatches, Command};
+
+mod import_postman_command;
+mod import_curl_command;
+mod import_http_file_command;
+
+pub enum ImportCommand {
+    Postman(ImportPostmanCommand),
+    Curl(ImportCurlCommand),
+    HttpFile(ImportHttpFileCommand),
+}
+
+impl ImportCommand {
+    pub fn get_command() -> Command {
+        Command::new("import")
+            .about("Import items into config from other tools")
+            .arg_required_else_help(true)
+            .subcommand(ImportPostmanCommand::get_command())
+            .subcommand(ImportCurlCommand::get_command())
+            .subcommand(ImportHttpFileCommand::get_command())
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ImportCommand {
+        match args.subcommand() {
+            Some(("postman", postman_matches)) => Postman(ImportPostmanCommand::bind_from_matches(postman_matches)),
+            Some(("curl", curl_matches)) => Curl(ImportCurlCommand::bind_from_matches(curl_matches)),
+            Some(("http-file", http_file_matches)) => HttpFile(ImportHttpFileCommand::bind_from_matches(http_file_matches)),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        match self {
+            Postman(import_postman_command) => import_postman_command.execute(config),
+            Curl(import_curl_command) => import_curl_command.execute(config),
+            HttpFile(import_http_file_command) => import_http_file_command.execute(config),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::{Endpoint, Environment, HtrsConfig, Preset, QueryParameter};
+use crate::formats::curl::parse_curl;
+use crate::outcomes::HtrsAction::UpdateConfigWithDialogue;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+use std::collections::{HashMap, HashSet};
+
+pub struct ImportCurlCommand {
+    pub command: String,
+    pub service: String,
+    pub endpoint: String,
+    pub environment: Option<String>,
+    pub preset: Option<String>,
+}
+
+impl ImportCurlCommand {
+    pub fn get_command() -> Command {
+        Command::new("curl")
+            .about("Import a curl command as a new endpoint")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("command")
+                    .help("The curl command to import, quoted as a single argument")
+                    .allow_hyphen_values(true)
+                    .required(true)
+            )
+            .arg(
+                Arg::new("service")
+                    .help("The service the endpoint will be created for")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+            .arg(
+                Arg::new("endpoint")
+                    .help("Name of the endpoint to create")
+                    .required(true)
+                    .long("endpoint")
+            )
+            .arg(
+                Arg::new("environment")
+                    .help("Name of a new environment to create with the curl command's host, if no existing environment has that host")
+                    .required(false)
+                    .long("environment")
+                    .short('e')
+            )
+            .arg(
+                Arg::new("preset")
+                    .help("Name of a new preset to create with the curl command's query parameter values")
+                    .required(false)
+                    .long("preset")
+                    .short('p')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ImportCurlCommand {
+        ImportCurlCommand {
+            command: args.bind_field("command"),
+            service: args.bind_field("service"),
+            endpoint: args.bind_field("endpoint"),
+            environment: args.bind_field("environment"),
+            preset: args.bind_field("preset"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let request = parse_curl(&self.command)?;
+        if request.url.scheme() != "http" {
+            return Err(HtrsError::new(format!("Curl url `{}` uses scheme `{}`, but endpoints are always called over http", request.url, request.url.scheme()).as_str()));
+        }
+        let host = match (request.url.host_str(), request.url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(HtrsError::new(format!("No host found in curl url `{}`", request.url).as_str())),
+        };
+        let query_values: HashMap<String, String> = request.url.query_pairs()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+
+        if let Some(preset) = &self.preset {
+            if config.get_preset(preset).is_some() {
+                return Err(HtrsError::new(format!("A preset with name or alias `{}` already exists", preset).as_str()));
+            }
+            if query_values.is_empty() {
+                return Err(HtrsError::new("Curl command has no query parameters to store in a preset"));
+            }
+        }
+
+        let Some(service) = config.get_service_mut(&self.service) else {
+            return Err(HtrsError::new(format!("Unable to find service with name or alias `{}`", self.service).as_str()));
+        };
+        if service.get_endpoint(&self.endpoint).is_some() {
+            return Err(HtrsError::new(format!("Service `{}` already has an endpoint named `{}`", self.service, self.endpoint).as_str()));
+        }
+
+        let mut seen_names = HashSet::new();
+        let query_parameters: Vec<QueryParameter> = request.url.query_pairs()
+            .filter(|(name, _)| seen_names.insert(name.to_string()))
+            .map(|(name, _)| QueryParameter { name: name.to_string(), required: false })
+            .collect();
+
+        service.endpoints.push(Endpoint {
+            name: self.endpoint.clone(),
+            method: request.method,
+            path_template: request.url.path().to_string(),
+            query_parameters,
+            headers: request.headers.into_iter().collect(),
+            body: request.body,
+            description: None,
+            alias: None,
+            tags: vec![],
+            parameter_descriptions: HashMap::new(),
+            mock: None,
+            pagination: None,
+        });
+        let mut dialogue = vec![format!("Created endpoint `{}` for service `{}`", self.endpoint, service.name)];
+
+        let matching_environment = service.environments.iter().find(|e| e.host == host);
+        match (matching_environment, &self.environment) {
+            (Some(environment), _) => dialogue.push(format!("Host `{}` matches environment `{}`", host, environment.name)),
+            (None, Some(environment_name)) => {
+                if service.get_environment(environment_name).is_some() {
+                    return Err(HtrsError::new(format!("Service `{}` already has an environment with name or alias `{}`", service.name, environment_name).as_str()));
+                }
+                let is_default = service.environments.is_empty();
+                service.environments.push(Environment::new(environment_name.clone(), None, host.clone(), is_default));
+                dialogue.push(format!("Created environment `{}` with host `{}`", environment_name, host));
+            },
+            (None, None) => dialogue.push(format!("No environment for service `{}` has host `{}`, use `--environment <name>` to create one", service.name, host)),
+        }
+
+        if let Some(preset) = &self.preset {
+            config.presets.push(Preset {
+                name: preset.clone(),
+                alias: None,
+                values: query_values,
+            });
+            dialogue.push(format!("Created preset `{}`", preset));
+        }
+
+        Ok(UpdateConfigWithDialogue(dialogue.join("\n")))
+    }
+}
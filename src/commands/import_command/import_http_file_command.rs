@@ -0,0 +1,94 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::{Environment, HtrsConfig, Service};
+use crate::formats::http_file::{http_file_to_endpoints, parse_http_file};
+use crate::outcomes::HtrsAction::UpdateConfigWithDialogue;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command, ValueHint};
+use std::fs;
+
+pub struct ImportHttpFileCommand {
+    pub file: String,
+    pub service: String,
+    pub environment: String,
+}
+
+impl ImportHttpFileCommand {
+    pub fn get_command() -> Command {
+        Command::new("http-file")
+            .about("Import the requests of a .http file as endpoints")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("file")
+                    .help("Path to the .http file")
+                    .value_hint(ValueHint::FilePath)
+                    .required(true)
+            )
+            .arg(
+                Arg::new("service")
+                    .help("The service the endpoints will be created for, created if it doesn't exist")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+            .arg(
+                Arg::new("environment")
+                    .help("Name of the environment to create with the file's host, if no existing environment has that host")
+                    .required(false)
+                    .default_value("default")
+                    .long("environment")
+                    .short('e')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ImportHttpFileCommand {
+        ImportHttpFileCommand {
+            file: args.bind_field("file"),
+            service: args.bind_field("service"),
+            environment: args.bind_field("environment"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let contents = match fs::read_to_string(&self.file) {
+            Ok(contents) => contents,
+            Err(e) => return Err(HtrsError::new(format!("Failed to read file `{}`: {}", self.file, e).as_str())),
+        };
+        let file = parse_http_file(&contents)?;
+        let (endpoints, host) = http_file_to_endpoints(&file);
+        if endpoints.is_empty() {
+            return Err(HtrsError::new(format!("No requests found in `{}`", self.file).as_str()));
+        }
+
+        let mut dialogue = vec![];
+        if config.get_service(&self.service).is_none() {
+            config.services.push(Service::new(self.service.clone(), None));
+            dialogue.push(format!("Created service `{}`", self.service));
+        }
+        let service = config.get_service_mut(&self.service).unwrap();
+
+        for endpoint in &endpoints {
+            if service.get_endpoint(&endpoint.name).is_some() {
+                return Err(HtrsError::new(format!("Service `{}` already has an endpoint named `{}`", service.name, endpoint.name).as_str()));
+            }
+        }
+        dialogue.push(format!("Imported {} endpoints into service `{}`", endpoints.len(), service.name));
+        service.endpoints.extend(endpoints);
+
+        if let Some(host) = host {
+            let matching_environment = service.environments.iter().find(|e| e.host == host);
+            match matching_environment {
+                Some(environment) => dialogue.push(format!("Host `{}` matches environment `{}`", host, environment.name)),
+                None => {
+                    if service.get_environment(&self.environment).is_some() {
+                        return Err(HtrsError::new(format!("Service `{}` already has an environment with name or alias `{}`", service.name, self.environment).as_str()));
+                    }
+                    let is_default = service.environments.is_empty();
+                    service.environments.push(Environment::new(self.environment.clone(), None, host.clone(), is_default));
+                    dialogue.push(format!("Created environment `{}` with host `{}`", self.environment, host));
+                },
+            }
+        }
+
+        Ok(UpdateConfigWithDialogue(dialogue.join("\n")))
+    }
+}
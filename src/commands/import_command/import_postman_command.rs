@@ -0,0 +1,122 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::{HtrsConfig, Preset};
+use crate::formats::postman::{collection_to_service, get_query_values, PostmanCollection, PostmanEnvironment};
+use crate::formats::to_command_name;
+use crate::outcomes::HtrsAction::{UpdateConfig, UpdateConfigWithDialogue};
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgAction, ArgMatches, Command, ValueHint};
+use serde::de::DeserializeOwned;
+use std::fs::File;
+
+pub struct ImportPostmanCommand {
+    pub collection: String,
+    pub name: Option<String>,
+    pub alias: Option<String>,
+    pub environments: Vec<String>,
+    pub preset: Option<String>,
+}
+
+impl ImportPostmanCommand {
+    pub fn get_command() -> Command {
+        Command::new("postman")
+            .about("Import a postman collection as a new service")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("collection")
+                    .help("Path to the postman collection file")
+                    .value_hint(ValueHint::FilePath)
+                    .required(true)
+            )
+            .arg(
+                Arg::new("name")
+                    .help("Name of the service to create, defaults to the name of the collection")
+                    .required(false)
+                    .long("name")
+                    .short('n')
+            )
+            .arg(
+                Arg::new("alias")
+                    .help("The unique alias for the new service")
+                    .required(false)
+                    .long("alias")
+                    .short('a')
+            )
+            .arg(
+                Arg::new("environment")
+                    .help("Path to a postman environment file to import as an environment")
+                    .value_hint(ValueHint::FilePath)
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("environment")
+                    .short('e')
+            )
+            .arg(
+                Arg::new("preset")
+                    .help("Name of the preset to create with the collection's literal query parameter values, defaults to the name of the service")
+                    .required(false)
+                    .long("preset")
+                    .short('p')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ImportPostmanCommand {
+        ImportPostmanCommand {
+            collection: args.bind_field("collection"),
+            name: args.bind_field("name"),
+            alias: args.bind_field("alias"),
+            environments: args.bind_field("environment"),
+            preset: args.bind_field("preset"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let collection: PostmanCollection = read_json_file(&self.collection)?;
+        let environments = self.environments.iter()
+            .map(|path| read_json_file::<PostmanEnvironment>(path))
+            .collect::<Result<Vec<PostmanEnvironment>, HtrsError>>()?;
+
+        let name = match &self.name {
+            Some(name) => name.clone(),
+            None => to_command_name(&collection.info.name),
+        };
+        if name.is_empty() {
+            return Err(HtrsError::new(format!("Collection name `{}` can't be used as a service name, use `--name` to give one", collection.info.name).as_str()));
+        }
+        if config.get_service(&name).is_some() {
+            return Err(HtrsError::new(format!("A service already exists with the name or alias '{}'", name).as_str()));
+        }
+        if let Some(alias) = &self.alias && config.get_service(alias).is_some() {
+            return Err(HtrsError::new(format!("A service already exists with the name or alias '{alias}'").as_str()));
+        }
+
+        let query_values = get_query_values(&collection.item);
+        let preset = self.preset.clone().unwrap_or(name.clone());
+        if !query_values.is_empty() && config.get_preset(&preset).is_some() {
+            return Err(HtrsError::new(format!("A preset with name or alias `{}` already exists, use `--preset` to name the preset for the collection's query values", preset).as_str()));
+        }
+
+        let service = collection_to_service(&collection, name, self.alias.clone(), &environments)?;
+        config.services.push(service);
+        if query_values.is_empty() {
+            return Ok(UpdateConfig);
+        }
+        config.presets.push(Preset {
+            name: preset.clone(),
+            alias: None,
+            values: query_values,
+        });
+        Ok(UpdateConfigWithDialogue(format!("Created preset `{}` with the collection's query parameter values", preset)))
+    }
+}
+
+fn read_json_file<T: DeserializeOwned>(path: &str) -> Result<T, HtrsError> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) => return Err(HtrsError::new(format!("Failed to read file `{}`: {}", path, e).as_str())),
+    };
+
+    match serde_json::from_reader(file) {
+        Ok(value) => Ok(value),
+        Err(e) => Err(HtrsError::new(format!("Failed to parse file `{}`: {}", path, e).as_str())),
+    }
+}
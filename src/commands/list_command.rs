@@ -1,6 +1,11 @@
 use crate::commands::list_command::list_endpoint_command::ListEndpointsCommand;
 use crate::commands::list_command::list_environment_command::ListEnvironmentsCommand;
-use crate::commands::list_command::ListCommand::{Endpoint, Environment, Service};
+use crate::commands::list_command::list_header_command::ListHeadersCommand;
+use crate::commands::list_command::list_preset_command::ListPresetsCommand;
+use crate::commands::list_command::list_test_command::ListTestsCommand;
+use crate::commands::list_command::list_workflow_command::ListWorkflowsCommand;
+use crate::commands::list_command::ListCommand::{Endpoint, Environment, Header, Preset, Service, Test, Workflow};
+use crate::commands::output_format::OutputFormat;
 use crate::config::current_config::HtrsConfig;
 use crate::outcomes::{HtrsAction, HtrsError};
 use clap::{ArgMatches, Command};
@@ -9,11 +14,19 @@ use list_service_command::ListServicesCommand;
 mod list_service_command;
 mod list_environment_command;
 mod list_endpoint_command;
+mod list_preset_command;
+mod list_header_command;
+mod list_test_command;
+mod list_workflow_command;
 
 pub enum ListCommand {
     Service(ListServicesCommand),
     Environment(ListEnvironmentsCommand),
     Endpoint(ListEndpointsCommand),
+    Preset(ListPresetsCommand),
+    Header(ListHeadersCommand),
+    Test(ListTestsCommand),
+    Workflow(ListWorkflowsCommand),
 }
 
 impl ListCommand {
@@ -22,9 +35,14 @@ impl ListCommand {
             .about("List items defined in config")
             .visible_alias("ls")
             .arg_required_else_help(true)
+            .arg(OutputFormat::get_arg())
             .subcommand(ListServicesCommand::get_command())
             .subcommand(ListEnvironmentsCommand::get_command())
             .subcommand(ListEndpointsCommand::get_command())
+            .subcommand(ListPresetsCommand::get_command())
+            .subcommand(ListHeadersCommand::get_command())
+            .subcommand(ListTestsCommand::get_command())
+            .subcommand(ListWorkflowsCommand::get_command())
     }
 
     pub fn bind_from_matches(args: &ArgMatches) -> ListCommand {
@@ -32,6 +50,10 @@ impl ListCommand {
             Some(("service", service_matches)) => Service(ListServicesCommand::bind_from_matches(service_matches)),
             Some(("environment" | "env", environment_matches)) => Environment(ListEnvironmentsCommand::bind_from_matches(environment_matches)),
             Some(("endpoint", endpoint_matches)) => Endpoint(ListEndpointsCommand::bind_from_matches(endpoint_matches)),
+            Some(("preset", preset_matches)) => Preset(ListPresetsCommand::bind_from_matches(preset_matches)),
+            Some(("header", header_matches)) => Header(ListHeadersCommand::bind_from_matches(header_matches)),
+            Some(("test", test_matches)) => Test(ListTestsCommand::bind_from_matches(test_matches)),
+            Some(("workflow", workflow_matches)) => Workflow(ListWorkflowsCommand::bind_from_matches(workflow_matches)),
             _ => unreachable!(),
         }
     }
@@ -41,6 +63,10 @@ impl ListCommand {
             Service(list_services_command) => list_services_command.execute(config),
             Environment(list_environments_command) => list_environments_command.execute(config),
             Endpoint(list_endpoints_command) => list_endpoints_command.execute(config),
+            Preset(list_presets_command) => list_presets_command.execute(config),
+            Header(list_headers_command) => list_headers_command.execute(config),
+            Test(list_tests_command) => list_tests_command.execute(config),
+            Workflow(list_workflows_command) => list_workflows_command.execute(config),
         }
     }
 }
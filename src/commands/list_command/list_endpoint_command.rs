@@ -1,12 +1,15 @@
 use crate::commands::bindings::MatchBinding;
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::EndpointView;
 use crate::config::current_config::{Endpoint, HtrsConfig};
-use crate::outcomes::HtrsAction::PrintDialogue;
 use crate::outcomes::{HtrsAction, HtrsError};
 use clap::{Arg, ArgMatches, Command};
 
 pub struct ListEndpointsCommand {
     pub service: String,
     pub filter: Option<String>,
+    pub tag: Option<String>,
+    pub format: OutputFormat,
 }
 
 impl ListEndpointsCommand {
@@ -27,12 +30,21 @@ impl ListEndpointsCommand {
                     .long("filter")
                     .short('f')
             )
+            .arg(
+                Arg::new("tag")
+                    .help("Only list endpoints with this tag")
+                    .required(false)
+                    .long("tag")
+                    .short('t')
+            )
     }
 
     pub fn bind_from_matches(args: &ArgMatches) -> ListEndpointsCommand {
         ListEndpointsCommand {
             service: args.bind_field("service"),
             filter: args.bind_field("filter"),
+            tag: args.bind_field("tag"),
+            format: OutputFormat::bind_from_matches(args),
         }
     }
 
@@ -45,20 +57,23 @@ impl ListEndpointsCommand {
 
         if let Some(filter) = &self.filter {
             endpoints.retain(|e| e.name.to_lowercase().contains(filter));
-
-            if endpoints.is_empty() {
-                return Ok(PrintDialogue(format!("No endpoints found for service `{}` with name containing `{}`", service.name, filter)));
-            }
         }
-
-        match endpoints.is_empty() {
-            true => Ok(PrintDialogue("No endpoints defined".to_string())),
-            false => Ok(PrintDialogue(
-                endpoints.iter()
-                    .map(|e| format!(" - {}", e.name))
-                    .collect::<Vec<String>>()
-                    .join("\n")
-            ))
+        if let Some(tag) = &self.tag {
+            endpoints.retain(|e| e.tags.contains(tag));
         }
+
+        let views: Vec<EndpointView> = endpoints.iter().map(EndpointView::new).collect();
+        self.format.print(&views, || match (endpoints.is_empty(), &self.filter, &self.tag) {
+            (true, Some(filter), _) => format!("No endpoints found for service `{}` with name containing `{}`", service.name, filter),
+            (true, None, Some(tag)) => format!("No endpoints found for service `{}` with tag `{}`", service.name, tag),
+            (true, None, None) => "No endpoints defined".to_string(),
+            (false, _, _) => endpoints.iter()
+                .map(|e| match &e.description {
+                    Some(description) => format!(" - {} ~ {}", e.display_name(), description),
+                    None => format!(" - {}", e.display_name()),
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+        })
     }
 }
@@ -1,12 +1,14 @@
 use crate::commands::bindings::MatchBinding;
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::EnvironmentView;
 use crate::config::current_config::{Environment, HtrsConfig};
-use crate::outcomes::HtrsAction::PrintDialogue;
 use crate::outcomes::{HtrsAction, HtrsError};
 use clap::{Arg, ArgMatches, Command};
 
 pub struct ListEnvironmentsCommand {
     pub service: String,
     pub filter: Option<String>,
+    pub format: OutputFormat,
 }
 
 impl ListEnvironmentsCommand {
@@ -34,6 +36,7 @@ impl ListEnvironmentsCommand {
         ListEnvironmentsCommand {
             service: args.bind_field("service"),
             filter: args.bind_field("filter"),
+            format: OutputFormat::bind_from_matches(args),
         }
     }
 
@@ -46,21 +49,17 @@ impl ListEnvironmentsCommand {
 
         if let Some(filter) = &self.filter {
             environments.retain(|e| environment_matches_filter(e, filter));
-
-            if environments.is_empty() {
-                return Ok(PrintDialogue(format!("No environments found for service `{}` with name or alias containing `{}`", service.name, filter)))
-            }
         }
 
-        match environments.is_empty() {
-            true => Ok(PrintDialogue("No environments defined".to_string())),
-            false => Ok(PrintDialogue(
-                environments.iter()
-                    .map(|e| format!(" - {}", e.display_name()))
-                    .collect::<Vec<String>>()
-                    .join("\n")
-            )),
-        }
+        let views: Vec<EnvironmentView> = environments.iter().map(EnvironmentView::new).collect();
+        self.format.print(&views, || match (environments.is_empty(), &self.filter) {
+            (true, Some(filter)) => format!("No environments found for service `{}` with name or alias containing `{}`", service.name, filter),
+            (true, None) => "No environments defined".to_string(),
+            (false, _) => environments.iter()
+                .map(|e| format!(" - {}", e.display_name()))
+                .collect::<Vec<String>>()
+                .join("\n"),
+        })
     }
 }
 
@@ -0,0 +1,87 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::output_format::OutputFormat;
+use crate::config::current_config::HtrsConfig;
+use crate::config::HeaderScope;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+
+pub struct ListHeadersCommand {
+    pub service: Option<String>,
+    pub environment: Option<String>,
+    pub effective: bool,
+    pub format: OutputFormat,
+}
+
+impl ListHeadersCommand {
+    pub fn get_command() -> Command {
+        Command::new("header")
+            .about("List headers set globally or for a service or environment")
+            .arg(
+                Arg::new("service")
+                    .help("Service to list headers for, lists global headers if not given")
+                    .required(false)
+                    .long("service")
+                    .short('s')
+            )
+            .arg(
+                Arg::new("environment")
+                    .help("Environment of the service to list headers for")
+                    .required(false)
+                    .requires("service")
+                    .long("environment")
+                    .short('e')
+            )
+            .arg(
+                Arg::new("effective")
+                    .help("List the merged headers that a call would send, along with the scope of each value")
+                    .required(false)
+                    .num_args(0)
+                    .long("effective")
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ListHeadersCommand {
+        ListHeadersCommand {
+            service: args.bind_field("service"),
+            environment: args.bind_field("environment"),
+            effective: args.bind_field("effective"),
+            format: OutputFormat::bind_from_matches(args),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let service = match &self.service {
+            Some(service_name) => match config.get_service(service_name) {
+                Some(service) => Some(service),
+                None => return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", service_name).as_str())),
+            },
+            None => None,
+        };
+        let environment = match (&self.environment, service) {
+            (Some(environment_name), Some(service)) => match service.get_environment(environment_name) {
+                Some(environment) => Some(environment),
+                None => return Err(HtrsError::new(format!("Unable to find environment with name or alias `{}` for service `{}`", environment_name, service.name).as_str())),
+            },
+            _ => None,
+        };
+
+        let scope = match (service, environment) {
+            (_, Some(_)) => HeaderScope::Environment,
+            (Some(_), None) => HeaderScope::Service,
+            (None, None) => HeaderScope::Global,
+        };
+        let mut headers = config.get_scoped_headers(service, environment, None);
+        headers.retain(|header| self.effective || header.scope == scope);
+
+        self.format.print(&headers, || match headers.is_empty() {
+            true => "No headers defined".to_string(),
+            false => headers.iter()
+                .map(|header| match self.effective {
+                    true => format!(" - {}: {} ({})", header.name, header.value, header.scope),
+                    false => format!(" - {}: {}", header.name, header.value),
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::PresetView;
+use crate::config::current_config::{HtrsConfig, Preset};
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+
+pub struct ListPresetsCommand {
+    pub filter: Option<String>,
+    pub format: OutputFormat,
+}
+
+impl ListPresetsCommand {
+    pub fn get_command() -> Command {
+        Command::new("preset")
+            .about("List all presets")
+            .arg(
+                Arg::new("filter")
+                    .help("Filter for preset name, alias or value key")
+                    .long("filter")
+                    .short('f')
+                    .required(false)
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ListPresetsCommand {
+        ListPresetsCommand {
+            filter: args.bind_field("filter"),
+            format: OutputFormat::bind_from_matches(args),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let mut presets: Vec<Preset> = config.presets.to_vec();
+
+        if let Some(filter) = &self.filter {
+            presets.retain(|p| preset_matches_filter(p, filter));
+        }
+
+        let views: Vec<PresetView> = presets.iter().map(PresetView::new).collect();
+        self.format.print(&views, || match (presets.is_empty(), &self.filter) {
+            (true, Some(filter)) => format!("No presets found with name, alias or value key containing `{}`", filter),
+            (true, None) => "No presets found".to_string(),
+            (false, _) => presets.iter()
+                .map(|p| format!(" - {}", p.display_name()))
+                .collect::<Vec<String>>()
+                .join("\n"),
+        })
+    }
+}
+
+fn preset_matches_filter(preset: &Preset, filter: &str) -> bool {
+    if preset.name.to_lowercase().contains(filter) {
+        return true;
+    }
+
+    if preset.alias.as_ref().is_some_and(|alias| alias.to_lowercase().contains(filter)) {
+        return true;
+    }
+
+    preset.values.keys().any(|key| key.to_lowercase().contains(filter))
+}
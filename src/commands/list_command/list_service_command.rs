@@ -1,11 +1,13 @@
 use crate::commands::bindings::MatchBinding;
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::ServiceView;
 use crate::config::current_config::{HtrsConfig, Service};
-use crate::outcomes::HtrsAction::PrintDialogue;
 use crate::outcomes::{HtrsAction, HtrsError};
 use clap::{Arg, ArgMatches, Command};
 
 pub struct ListServicesCommand {
     pub filter: Option<String>,
+    pub format: OutputFormat,
 }
 
 impl ListServicesCommand {
@@ -24,6 +26,7 @@ impl ListServicesCommand {
     pub fn bind_from_matches(args: &ArgMatches) -> ListServicesCommand {
         ListServicesCommand {
             filter: args.bind_field("filter"),
+            format: OutputFormat::bind_from_matches(args),
         }
     }
 
@@ -32,21 +35,17 @@ impl ListServicesCommand {
 
         if let Some(filter) = &self.filter {
             services.retain(|s| service_matches_filter(s, filter));
-
-            if services.is_empty() {
-                return Ok(PrintDialogue(format!("No services found with name or alias containing `{}`", filter)));
-            }
         };
 
-        match services.is_empty() {
-            true => Ok(PrintDialogue("No services found".to_string())),
-            false => Ok(PrintDialogue(
-                services.iter()
-                    .map(|s| format!(" - {}", s.display_name()))
-                    .collect::<Vec<String>>()
-                    .join("\n")
-            )),
-        }
+        let views: Vec<ServiceView> = services.iter().map(ServiceView::new).collect();
+        self.format.print(&views, || match (services.is_empty(), &self.filter) {
+            (true, Some(filter)) => format!("No services found with name or alias containing `{}`", filter),
+            (true, None) => "No services found".to_string(),
+            (false, _) => services.iter()
+                .map(|s| format!(" - {}", s.display_name()))
+                .collect::<Vec<String>>()
+                .join("\n"),
+        })
     }
 }
 
@@ -0,0 +1,51 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::TestCaseView;
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+
+pub struct ListTestsCommand {
+    pub service: String,
+    pub format: OutputFormat,
+}
+
+impl ListTestsCommand {
+    pub fn get_command() -> Command {
+        Command::new("test")
+            .about("List test cases for a service")
+            .arg(
+                Arg::new("service")
+                    .help("Service to list tests for")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ListTestsCommand {
+        ListTestsCommand {
+            service: args.bind_field("service"),
+            format: OutputFormat::bind_from_matches(args),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()))
+        };
+
+        let views: Vec<TestCaseView> = service.tests.iter().map(TestCaseView::new).collect();
+        self.format.print(&views, || match service.tests.is_empty() {
+            true => format!("No tests defined for service `{}`", service.name),
+            false => service.tests.iter()
+                .map(|t| {
+                    let mut lines = vec![format!(" - {} ({})", t.name, t.endpoint)];
+                    lines.extend(t.assertions.iter().map(|a| format!("    {}", a)));
+                    lines.join("\n")
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+        })
+    }
+}
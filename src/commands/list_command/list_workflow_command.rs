@@ -0,0 +1,52 @@
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::WorkflowView;
+use crate::config::current_config::{HtrsConfig, WorkflowStep};
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{ArgMatches, Command};
+
+pub struct ListWorkflowsCommand {
+    pub format: OutputFormat,
+}
+
+impl ListWorkflowsCommand {
+    pub fn get_command() -> Command {
+        Command::new("workflow")
+            .about("List all workflows & their steps")
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> ListWorkflowsCommand {
+        ListWorkflowsCommand {
+            format: OutputFormat::bind_from_matches(args),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let views: Vec<WorkflowView> = config.workflows.iter().map(WorkflowView::new).collect();
+        self.format.print(&views, || match config.workflows.is_empty() {
+            true => "No workflows defined".to_string(),
+            false => config.workflows.iter()
+                .map(|w| {
+                    let mut lines = vec![format!(" - {}", w.name)];
+                    lines.extend(w.steps.iter().enumerate().map(|(i, s)| format_step(i + 1, s)));
+                    lines.join("\n")
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+        })
+    }
+}
+
+fn format_step(number: usize, step: &WorkflowStep) -> String {
+    let mut text = format!("    {}. {}: {} {}", number, step.name, step.service, step.endpoint);
+    let mut parameters: Vec<String> = step.parameters.iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect();
+    parameters.sort();
+    if !parameters.is_empty() {
+        text += format!(" ({})", parameters.join(", ")).as_str();
+    }
+    for extraction in &step.extractions {
+        text += format!("\n       {}", extraction).as_str();
+    }
+    text
+}
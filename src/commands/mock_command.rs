@@ -0,0 +1,62 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::HtrsConfig;
+use crate::mock::MockServer;
+use crate::outcomes::HtrsAction::ServeMock;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{value_parser, Arg, ArgMatches, Command};
+
+pub struct MockCommand {
+    pub service: String,
+    pub port: u64,
+    pub host: String,
+}
+
+impl MockCommand {
+    pub fn get_command() -> Command {
+        Command::new("mock")
+            .about("Serve canned responses for a service's endpoints")
+            .arg(
+                Arg::new("service")
+                    .help("Name or alias of the service to mock")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("port")
+                    .help("Port to listen on, use 0 to pick any free port")
+                    .required(false)
+                    .default_value("8080")
+                    .value_parser(value_parser!(u64).range(0..65536))
+                    .long("port")
+            )
+            .arg(
+                Arg::new("host")
+                    .help("Address to listen on")
+                    .required(false)
+                    .default_value("127.0.0.1")
+                    .long("host")
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> MockCommand {
+        MockCommand {
+            service: args.bind_field("service"),
+            port: args.bind_field("port"),
+            host: args.bind_field("host"),
+        }
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+        if service.endpoints.is_empty() {
+            return Err(HtrsError::new(format!("Service `{}` has no endpoints to mock", service.name).as_str()));
+        }
+
+        Ok(ServeMock(MockServer {
+            service: service.clone(),
+            host: self.host.clone(),
+            port: self.port as u16,
+        }))
+    }
+}
@@ -2,11 +2,17 @@ mod new_environment_command;
 mod new_service_command;
 mod new_endpoint_command;
 mod new_preset_command;
+mod new_test_command;
+mod new_workflow_command;
+mod new_step_command;
 
 use crate::commands::new_command::new_endpoint_command::NewEndpointCommand;
 use crate::commands::new_command::new_environment_command::NewEnvironmentCommand;
 use crate::commands::new_command::new_preset_command::NewPresetCommand;
-use crate::commands::new_command::NewCommand::{Endpoint, Environment, Preset, Service};
+use crate::commands::new_command::new_step_command::NewStepCommand;
+use crate::commands::new_command::new_test_command::NewTestCommand;
+use crate::commands::new_command::new_workflow_command::NewWorkflowCommand;
+use crate::commands::new_command::NewCommand::{Endpoint, Environment, Preset, Service, Step, Test, Workflow};
 use crate::config::current_config::HtrsConfig;
 use crate::outcomes::{HtrsAction, HtrsError};
 use clap::{ArgMatches, Command};
@@ -17,6 +23,9 @@ pub enum NewCommand {
     Environment(NewEnvironmentCommand),
     Endpoint(NewEndpointCommand),
     Preset(NewPresetCommand),
+    Test(NewTestCommand),
+    Workflow(NewWorkflowCommand),
+    Step(NewStepCommand),
 }
 
 impl NewCommand {
@@ -28,6 +37,9 @@ impl NewCommand {
             .subcommand(NewEnvironmentCommand::get_command())
             .subcommand(NewEndpointCommand::get_command())
             .subcommand(NewPresetCommand::get_command())
+            .subcommand(NewTestCommand::get_command())
+            .subcommand(NewWorkflowCommand::get_command())
+            .subcommand(NewStepCommand::get_command())
     }
 
     pub fn bind_from_matches(args: &ArgMatches) -> NewCommand {
@@ -36,6 +48,9 @@ impl NewCommand {
             Some(("environment" | "env", environment_matches)) => Environment(NewEnvironmentCommand::bind_from_matches(environment_matches)),
             Some(("endpoint", endpoint_matches)) => Endpoint(NewEndpointCommand::bind_from_matches(endpoint_matches)),
             Some(("preset", preset_matches)) => Preset(NewPresetCommand::bind_from_matches(preset_matches)),
+            Some(("test", test_matches)) => Test(NewTestCommand::bind_from_matches(test_matches)),
+            Some(("workflow", workflow_matches)) => Workflow(NewWorkflowCommand::bind_from_matches(workflow_matches)),
+            Some(("step", step_matches)) => Step(NewStepCommand::bind_from_matches(step_matches)),
             _ => unreachable!(),
         }
     }
@@ -46,6 +61,9 @@ impl NewCommand {
             Environment(create_new_environment_command) => create_new_environment_command.execute(config),
             Endpoint(create_new_endpoint_command) => create_new_endpoint_command.execute(config),
             Preset(create_new_preset_command) => create_new_preset_command.execute(config),
+            Test(create_new_test_command) => create_new_test_command.execute(config),
+            Workflow(create_new_workflow_command) => create_new_workflow_command.execute(config),
+            Step(create_new_step_command) => create_new_step_command.execute(config),
         }
     }
 }
@@ -1,15 +1,21 @@
 use crate::commands::bindings::MatchBinding;
-use crate::common::{get_duplicates_from_vec, get_params_from_path};
+use crate::common::{get_duplicates_from_vec, get_params_from_path, parse_method, parse_parameter_descriptions};
 use crate::config::current_config::{Endpoint, HtrsConfig, QueryParameter};
 use crate::outcomes::HtrsAction::UpdateConfig;
 use crate::outcomes::{HtrsAction, HtrsError};
 use clap::{Arg, ArgAction, ArgMatches, Command};
+use std::collections::HashMap;
 
 pub struct NewEndpointCommand {
     pub service: String,
     pub name: String,
     pub path_template: String,
+    pub method: String,
     pub query_parameters: Vec<String>,
+    pub description: Option<String>,
+    pub alias: Option<String>,
+    pub tags: Vec<String>,
+    pub parameter_descriptions: Vec<String>,
 }
 
 impl NewEndpointCommand {
@@ -25,6 +31,14 @@ impl NewEndpointCommand {
                     .help("The path of the endpoint")
                     .required(true)
             )
+            .arg(
+                Arg::new("method")
+                    .help("The http method used to call the endpoint")
+                    .required(false)
+                    .default_value("GET")
+                    .long("method")
+                    .short('m')
+            )
             .arg(
                 Arg::new("query")
                     .help("Query parameter for endpoint")
@@ -34,6 +48,36 @@ impl NewEndpointCommand {
                     .long("query")
                     .short('q')
             )
+            .arg(
+                Arg::new("description")
+                    .help("A description of the endpoint, shown in help for calling it")
+                    .required(false)
+                    .long("description")
+                    .short('d')
+            )
+            .arg(
+                Arg::new("alias")
+                    .help("The unique alias for the new endpoint")
+                    .required(false)
+                    .long("alias")
+                    .short('a')
+            )
+            .arg(
+                Arg::new("tag")
+                    .help("A tag to group the endpoint by")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("tag")
+                    .short('t')
+            )
+            .arg(
+                Arg::new("param_help")
+                    .value_name("name=text")
+                    .help("Help text for a path or query parameter, in the format `name=text`")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("param-help")
+            )
             .arg(
                 Arg::new("service")
                     .help("The service endpoint will be created for")
@@ -48,7 +92,12 @@ impl NewEndpointCommand {
             service: args.bind_field("service"),
             name: args.bind_field("name"),
             path_template: args.bind_field("path"),
+            method: args.bind_field("method"),
             query_parameters: args.bind_field("query"),
+            description: args.bind_field("description"),
+            alias: args.bind_field("alias"),
+            tags: args.bind_field("tag"),
+            parameter_descriptions: args.bind_field("param_help"),
         }
     }
 
@@ -59,6 +108,7 @@ impl NewEndpointCommand {
         if self.path_template.is_empty() {
             return Err(HtrsError::new("Endpoint path cannot be empty"));
         }
+        let method = parse_method(&self.method)?;
         let path_parameters = get_params_from_path(&self.path_template);
         let duplicates = get_duplicates_from_vec(path_parameters);
         if !duplicates.is_empty() {
@@ -86,12 +136,26 @@ impl NewEndpointCommand {
         if service.get_endpoint(&self.name).is_some() {
             return Err(HtrsError::new(format!("Service `{}` already has an endpoint named `{}`", self.service, self.name).as_str()));
         }
+        if let Some(alias) = &self.alias && (alias == &self.name || service.get_endpoint(alias).is_some()) {
+            return Err(HtrsError::new(format!("Service `{}` already has an endpoint named `{}`", self.service, alias).as_str()));
+        }
 
-        service.endpoints.push(Endpoint {
+        let mut endpoint = Endpoint {
             name: self.name.clone(),
+            method,
             path_template: self.path_template.clone(),
-            query_parameters: self.query_parameters.iter().map(|q| QueryParameter::from_shorthand(q)).collect(),
-        });
+            query_parameters: query_params,
+            headers: HashMap::new(),
+            body: None,
+            description: self.description.clone(),
+            alias: self.alias.clone(),
+            tags: self.tags.clone(),
+            parameter_descriptions: HashMap::new(),
+            mock: None,
+            pagination: None,
+        };
+        endpoint.parameter_descriptions = parse_parameter_descriptions(&endpoint, &self.parameter_descriptions)?;
+        service.endpoints.push(endpoint);
         Ok(UpdateConfig)
     }
 }
@@ -0,0 +1,142 @@
+use crate::commands::bindings::MatchBinding;
+use crate::common::parse_parameter_values;
+use crate::config::current_config::{HtrsConfig, WorkflowStep};
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use crate::testing::validate_status;
+use crate::workflow::{get_extracted_names, get_value_references, parse_extraction};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+pub struct NewStepCommand {
+    pub workflow: String,
+    pub name: String,
+    pub service: String,
+    pub endpoint: String,
+    pub preset: Option<String>,
+    pub parameters: Vec<String>,
+    pub extractions: Vec<String>,
+    pub expect_status: Option<String>,
+}
+
+impl NewStepCommand {
+    pub fn get_command() -> Command {
+        Command::new("step")
+            .about("Add a step to the end of a workflow")
+            .arg(
+                Arg::new("name")
+                    .help("Name of the step to add")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("workflow")
+                    .help("The workflow the step will be added to")
+                    .required(true)
+                    .long("workflow")
+                    .short('w')
+            )
+            .arg(
+                Arg::new("service")
+                    .help("The service the step calls")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+            .arg(
+                Arg::new("endpoint")
+                    .help("The endpoint the step calls")
+                    .required(true)
+                    .long("endpoint")
+            )
+            .arg(
+                Arg::new("preset")
+                    .help("A preset used to populate the endpoint's parameters")
+                    .required(false)
+                    .long("preset")
+                    .short('p')
+            )
+            .arg(
+                Arg::new("param")
+                    .value_name("name=value")
+                    .help("A value for a path or query parameter, which can use values from earlier steps as `{{name}}`")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("param")
+            )
+            .arg(
+                Arg::new("extract")
+                    .value_name("name=source")
+                    .help("A value to take from the response for later steps, e.g. `id=$.id` or `location=header:Location`")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("extract")
+            )
+            .arg(
+                Arg::new("expect_status")
+                    .value_name("status")
+                    .help("Status the response must have for the workflow to continue, defaults to `2xx`")
+                    .required(false)
+                    .long("expect-status")
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> NewStepCommand {
+        NewStepCommand {
+            workflow: args.bind_field("workflow"),
+            name: args.bind_field("name"),
+            service: args.bind_field("service"),
+            endpoint: args.bind_field("endpoint"),
+            preset: args.bind_field("preset"),
+            parameters: args.bind_field("param"),
+            extractions: args.bind_field("extract"),
+            expect_status: args.bind_field("expect_status"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        if self.name.is_empty() {
+            return Err(HtrsError::new("Step name cannot be empty"));
+        }
+        if let Some(expected) = &self.expect_status {
+            validate_status(expected)?;
+        }
+        if let Some(preset) = &self.preset && config.get_preset(preset).is_none() {
+            return Err(HtrsError::new(format!("No preset found with name `{}`", preset).as_str()));
+        }
+        let Some(service) = config.get_service(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+        let Some(endpoint) = service.get_endpoint(&self.endpoint) else {
+            return Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", self.endpoint, service.name).as_str()));
+        };
+        let parameters = parse_parameter_values(endpoint, &self.parameters)?;
+        let extractions = self.extractions.iter()
+            .map(|e| parse_extraction(e))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let step = WorkflowStep {
+            name: self.name.clone(),
+            service: service.name.clone(),
+            endpoint: endpoint.name.clone(),
+            preset: self.preset.clone(),
+            parameters,
+            expected_status: self.expect_status.clone(),
+            extractions,
+        };
+
+        let Some(workflow) = config.get_workflow_mut(&self.workflow) else {
+            return Err(HtrsError::new(format!("No workflow could be found with name `{}`", self.workflow).as_str()));
+        };
+        if workflow.steps.iter().any(|s| s.name == self.name) {
+            return Err(HtrsError::new(format!("Workflow `{}` already has a step named `{}`", workflow.name, self.name).as_str()));
+        }
+        let extracted_names = get_extracted_names(&workflow.steps);
+        for value in step.parameters.values() {
+            if let Some(name) = get_value_references(value).into_iter().find(|n| !extracted_names.contains(&n.as_str())) {
+                return Err(HtrsError::new(format!("No value named `{}` is taken by an earlier step of workflow `{}`", name, workflow.name).as_str()));
+            }
+        }
+
+        workflow.steps.push(step);
+        Ok(UpdateConfig)
+    }
+}
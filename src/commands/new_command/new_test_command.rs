@@ -0,0 +1,200 @@
+use crate::commands::bindings::MatchBinding;
+use crate::common::{parse_duration, parse_key_value_string, parse_parameter_values};
+use crate::config::current_config::{Assertion, HtrsConfig, TestCase};
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use crate::testing::validate_status;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use regex::Regex;
+use serde_json::Value;
+
+pub struct NewTestCommand {
+    pub service: String,
+    pub name: String,
+    pub endpoint: String,
+    pub preset: Option<String>,
+    pub parameters: Vec<String>,
+    pub expect_status: Option<String>,
+    pub expect_headers: Vec<String>,
+    pub expect_json: Vec<String>,
+    pub expect_json_exists: Vec<String>,
+    pub expect_json_matches: Vec<String>,
+    pub max_latency: Option<String>,
+}
+
+impl NewTestCommand {
+    pub fn get_command() -> Command {
+        Command::new("test")
+            .about("Create a test case that calls an endpoint & checks its response")
+            .arg(
+                Arg::new("name")
+                    .help("Name of the test to create")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("service")
+                    .help("The service the test will be created for")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+            .arg(
+                Arg::new("endpoint")
+                    .help("The endpoint the test calls")
+                    .required(true)
+                    .long("endpoint")
+            )
+            .arg(
+                Arg::new("preset")
+                    .help("A preset used to populate the endpoint's parameters")
+                    .required(false)
+                    .long("preset")
+                    .short('p')
+            )
+            .arg(
+                Arg::new("param")
+                    .value_name("name=value")
+                    .help("A value for a path or query parameter, in the format `name=value`")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("param")
+            )
+            .arg(
+                Arg::new("expect_status")
+                    .value_name("status")
+                    .help("Expected status, as a code like `200`, a class like `2xx` or a range like `200-299`")
+                    .required(false)
+                    .long("expect-status")
+            )
+            .arg(
+                Arg::new("expect_header")
+                    .value_name("name[=value]")
+                    .help("A header the response must have, optionally with the value it must have")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("expect-header")
+            )
+            .arg(
+                Arg::new("expect_json")
+                    .value_name("path=value")
+                    .help("A json body value the response must have, e.g. `$.items[0].id=1`")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("expect-json")
+            )
+            .arg(
+                Arg::new("expect_json_exists")
+                    .value_name("path")
+                    .help("Path of a json body value the response must have")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("expect-json-exists")
+            )
+            .arg(
+                Arg::new("expect_json_matches")
+                    .value_name("path=pattern")
+                    .help("A json body value that must match a regex, e.g. `$.email=@example.com$`")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("expect-json-matches")
+            )
+            .arg(
+                Arg::new("max_latency")
+                    .value_name("duration")
+                    .help("The longest the response can take, e.g. `500ms`")
+                    .required(false)
+                    .long("max-latency")
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> NewTestCommand {
+        NewTestCommand {
+            service: args.bind_field("service"),
+            name: args.bind_field("name"),
+            endpoint: args.bind_field("endpoint"),
+            preset: args.bind_field("preset"),
+            parameters: args.bind_field("param"),
+            expect_status: args.bind_field("expect_status"),
+            expect_headers: args.bind_field("expect_header"),
+            expect_json: args.bind_field("expect_json"),
+            expect_json_exists: args.bind_field("expect_json_exists"),
+            expect_json_matches: args.bind_field("expect_json_matches"),
+            max_latency: args.bind_field("max_latency"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        if self.name.is_empty() {
+            return Err(HtrsError::new("Test name cannot be empty"));
+        }
+        if let Some(preset) = &self.preset && config.get_preset(preset).is_none() {
+            return Err(HtrsError::new(format!("No preset found with name `{}`", preset).as_str()));
+        }
+        let assertions = self.get_assertions()?;
+
+        let Some(service) = config.get_service_mut(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+        let Some(endpoint) = service.get_endpoint(&self.endpoint) else {
+            return Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", self.endpoint, service.name).as_str()));
+        };
+        if service.get_test(&self.name).is_some() {
+            return Err(HtrsError::new(format!("Service `{}` already has a test named `{}`", service.name, self.name).as_str()));
+        }
+
+        let test = TestCase {
+            name: self.name.clone(),
+            endpoint: endpoint.name.clone(),
+            preset: self.preset.clone(),
+            parameters: parse_parameter_values(endpoint, &self.parameters)?,
+            assertions,
+        };
+        service.tests.push(test);
+        Ok(UpdateConfig)
+    }
+
+    fn get_assertions(&self) -> Result<Vec<Assertion>, HtrsError> {
+        let mut assertions = vec![];
+
+        if let Some(expected) = &self.expect_status {
+            validate_status(expected)?;
+            assertions.push(Assertion::Status { expected: expected.clone() });
+        }
+
+        for header in &self.expect_headers {
+            assertions.push(match header.split_once('=') {
+                Some((name, value)) => Assertion::Header { name: name.to_string(), value: Some(value.to_string()) },
+                None => Assertion::Header { name: header.clone(), value: None },
+            });
+        }
+
+        for expected in &self.expect_json {
+            let Ok((path, value)) = parse_key_value_string(expected) else {
+                return Err(HtrsError::new(format!("Invalid json expectation `{}`, should be in format `path=value`", expected).as_str()));
+            };
+            let value = serde_json::from_str::<Value>(&value).unwrap_or(Value::String(value));
+            assertions.push(Assertion::JsonEquals { path, value });
+        }
+
+        for path in &self.expect_json_exists {
+            assertions.push(Assertion::JsonExists { path: path.clone() });
+        }
+
+        for expected in &self.expect_json_matches {
+            let Ok((path, pattern)) = parse_key_value_string(expected) else {
+                return Err(HtrsError::new(format!("Invalid json expectation `{}`, should be in format `path=pattern`", expected).as_str()));
+            };
+            if let Err(e) = Regex::new(&pattern) {
+                return Err(HtrsError::new(format!("Invalid pattern `{}`: {}", pattern, e).as_str()));
+            }
+            assertions.push(Assertion::JsonMatches { path, pattern });
+        }
+
+        if let Some(max_latency) = &self.max_latency {
+            let duration = parse_duration(max_latency)?;
+            assertions.push(Assertion::MaxLatency { milliseconds: duration.as_millis() as u64 });
+        }
+
+        Ok(assertions)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::{HtrsConfig, Workflow};
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+
+pub struct NewWorkflowCommand {
+    pub name: String,
+}
+
+impl NewWorkflowCommand {
+    pub fn get_command() -> Command {
+        Command::new("workflow")
+            .about("Create a workflow, with steps added by `new step`")
+            .arg(
+                Arg::new("name")
+                    .help("Name of the workflow to create")
+                    .required(true)
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> NewWorkflowCommand {
+        NewWorkflowCommand {
+            name: args.bind_field("name"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        if self.name.is_empty() {
+            return Err(HtrsError::new("Workflow name cannot be empty"));
+        }
+        if config.get_workflow(&self.name).is_some() {
+            return Err(HtrsError::new(format!("A workflow already exists with the name `{}`", self.name).as_str()));
+        }
+
+        config.workflows.push(Workflow {
+            name: self.name.clone(),
+            steps: vec![],
+        });
+        Ok(UpdateConfig)
+    }
+}
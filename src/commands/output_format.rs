@@ -0,0 +1,58 @@
+use crate::outcomes::HtrsAction::PrintDialogue;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::builder::PossibleValuesParser;
+use clap::{Arg, ArgMatches};
+use serde::Serialize;
+
+/// How list, view & history commands print the items they find. The option is only given to those
+/// commands, so that it can't clash with the parameter args of `call`
+#[derive(Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Yaml,
+}
+
+impl OutputFormat {
+    pub fn get_arg() -> Arg {
+        Arg::new("format")
+            .help("Format to print items in")
+            .required(false)
+            .global(true)
+            .value_parser(PossibleValuesParser::new(["table", "json", "yaml"]))
+            .default_value("table")
+            .long("format")
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> OutputFormat {
+        match args.get_one::<String>("format").map(|f| f.as_str()) {
+            Some("json") => OutputFormat::Json,
+            Some("yaml") => OutputFormat::Yaml,
+            _ => OutputFormat::Table,
+        }
+    }
+
+    /// Prints the data in a structured format, or the text given by `table` for the table format.
+    /// Data is converted to a json value first so that map keys are always printed in sorted order.
+    /// Items should be given as their views from `output_view`, so that the printed schema doesn't
+    /// change along with the config file
+    pub fn print<T: Serialize + ?Sized>(&self, data: &T, table: impl FnOnce() -> String) -> Result<HtrsAction, HtrsError> {
+        if *self == OutputFormat::Table {
+            return Ok(PrintDialogue(table()));
+        }
+
+        let value = match serde_json::to_value(data) {
+            Ok(value) => value,
+            Err(e) => return Err(HtrsError::new(format!("Failed to serialize output: {}", e).as_str())),
+        };
+        let output = match self {
+            OutputFormat::Json => serde_json::to_string_pretty(&value).map_err(|e| e.to_string()),
+            OutputFormat::Yaml => serde_yaml::to_string(&value).map_err(|e| e.to_string()),
+            OutputFormat::Table => unreachable!(),
+        };
+        match output {
+            Ok(output) => Ok(PrintDialogue(output.trim_end().to_string())),
+            Err(e) => Err(HtrsError::new(format!("Failed to serialize output: {}", e).as_str())),
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use crate::common::get_params_from_path;
+use crate::config::current_config::{Endpoint, Environment, MockResponse, Pagination, PaginationStrategy, Preset, Service, TestCase, Workflow, WorkflowStep};
+use crate::history::HistoryEntry;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Serialize)]
+pub struct ServiceView {
+    pub name: String,
+    pub alias: Option<String>,
+    pub health_endpoint: Option<String>,
+    pub headers: BTreeMap<String, String>,
+    pub environments: Vec<EnvironmentView>,
+    pub endpoints: Vec<EndpointView>,
+}
+
+impl ServiceView {
+    pub fn new(service: &Service) -> ServiceView {
+        ServiceView {
+            name: service.name.clone(),
+            alias: service.alias.clone(),
+            health_endpoint: service.health_endpoint.clone(),
+            headers: to_sorted(&service.headers),
+            environments: service.environments.iter().map(EnvironmentView::new).collect(),
+            endpoints: service.endpoints.iter().map(EndpointView::new).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct EnvironmentView {
+    pub name: String,
+    pub alias: Option<String>,
+    pub host: String,
+    pub default: bool,
+    pub headers: BTreeMap<String, String>,
+}
+
+impl EnvironmentView {
+    pub fn new(environment: &Environment) -> EnvironmentView {
+        EnvironmentView {
+            name: environment.name.clone(),
+            alias: environment.alias.clone(),
+            host: environment.host.clone(),
+            default: environment.default,
+            headers: to_sorted(&environment.headers),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct EndpointView {
+    pub name: String,
+    pub alias: Option<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub method: String,
+    pub path: String,
+    pub path_parameters: Vec<ParameterView>,
+    pub query_parameters: Vec<ParameterView>,
+    pub headers: BTreeMap<String, String>,
+    pub body: Option<String>,
+    pub mock: Option<MockView>,
+    pub pagination: Option<PaginationView>,
+}
+
+/// A path or query parameter, path parameters are always required
+#[derive(Serialize)]
+pub struct ParameterView {
+    pub name: String,
+    pub required: bool,
+    pub description: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct MockView {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: Option<String>,
+    pub file: Option<String>,
+}
+
+/// How the endpoint is paginated, `parameter` & `cursor` are only given by the strategies that
+/// use them
+#[derive(Serialize)]
+pub struct PaginationView {
+    pub strategy: String,
+    pub parameter: Option<String>,
+    pub cursor: Option<String>,
+    pub items: Option<String>,
+}
+
+impl EndpointView {
+    pub fn new(endpoint: &Endpoint) -> EndpointView {
+        let description = |name: &str| endpoint.parameter_descriptions.get(name).cloned();
+        EndpointView {
+            name: endpoint.name.clone(),
+            alias: endpoint.alias.clone(),
+            description: endpoint.description.clone(),
+            tags: endpoint.tags.clone(),
+            method: endpoint.method.clone(),
+            path: endpoint.path_template.clone(),
+            path_parameters: get_params_from_path(&endpoint.path_template).into_iter()
+                .map(|name| ParameterView { description: description(&name), name, required: true })
+                .collect(),
+            query_parameters: endpoint.query_parameters.iter()
+                .map(|q| ParameterView { name: q.name.clone(), required: q.required, description: description(&q.name) })
+                .collect(),
+            headers: to_sorted(&endpoint.headers),
+            body: endpoint.body.clone(),
+            mock: endpoint.mock.as_ref().map(MockView::new),
+            pagination: endpoint.pagination.as_ref().map(PaginationView::new),
+        }
+    }
+}
+
+impl MockView {
+    fn new(mock: &MockResponse) -> MockView {
+        MockView {
+            status: mock.status,
+            headers: to_sorted(&mock.headers),
+            body: mock.body.clone(),
+            file: mock.file.clone(),
+        }
+    }
+}
+
+impl PaginationView {
+    fn new(pagination: &Pagination) -> PaginationView {
+        let (strategy, parameter, cursor) = match &pagination.strategy {
+            PaginationStrategy::Link => ("link", None, None),
+            PaginationStrategy::Cursor { path, parameter } => ("cursor", Some(parameter), Some(path)),
+            PaginationStrategy::Page { parameter } => ("page", Some(parameter), None),
+            PaginationStrategy::Offset { parameter } => ("offset", Some(parameter), None),
+        };
+        PaginationView {
+            strategy: strategy.to_string(),
+            parameter: parameter.cloned(),
+            cursor: cursor.cloned(),
+            items: pagination.items.clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct PresetView {
+    pub name: String,
+    pub alias: Option<String>,
+    pub values: BTreeMap<String, String>,
+}
+
+impl PresetView {
+    pub fn new(preset: &Preset) -> PresetView {
+        PresetView {
+            name: preset.name.clone(),
+            alias: preset.alias.clone(),
+            values: to_sorted(&preset.values),
+        }
+    }
+}
+
+/// A test case, with its assertions written out as they're shown by `list test`
+#[derive(Serialize)]
+pub struct TestCaseView {
+    pub name: String,
+    pub endpoint: String,
+    pub preset: Option<String>,
+    pub parameters: BTreeMap<String, String>,
+    pub assertions: Vec<String>,
+}
+
+impl TestCaseView {
+    pub fn new(test: &TestCase) -> TestCaseView {
+        TestCaseView {
+            name: test.name.clone(),
+            endpoint: test.endpoint.clone(),
+            preset: test.preset.clone(),
+            parameters: to_sorted(&test.parameters),
+            assertions: test.assertions.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct WorkflowView {
+    pub name: String,
+    pub steps: Vec<WorkflowStepView>,
+}
+
+/// A workflow step, with its extractions written out as they're shown by `list workflow`
+#[derive(Serialize)]
+pub struct WorkflowStepView {
+    pub name: String,
+    pub service: String,
+    pub endpoint: String,
+    pub preset: Option<String>,
+    pub parameters: BTreeMap<String, String>,
+    pub expected_status: Option<String>,
+    pub extractions: Vec<String>,
+}
+
+impl WorkflowView {
+    pub fn new(workflow: &Workflow) -> WorkflowView {
+        WorkflowView {
+            name: workflow.name.clone(),
+            steps: workflow.steps.iter().map(WorkflowStepView::new).collect(),
+        }
+    }
+}
+
+impl WorkflowStepView {
+    fn new(step: &WorkflowStep) -> WorkflowStepView {
+        WorkflowStepView {
+            name: step.name.clone(),
+            service: step.service.clone(),
+            endpoint: step.endpoint.clone(),
+            preset: step.preset.clone(),
+            parameters: to_sorted(&step.parameters),
+            expected_status: step.expected_status.clone(),
+            extractions: step.extractions.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct HistoryEntryView {
+    pub id: u64,
+    pub timestamp: String,
+    pub service: String,
+    pub endpoint: String,
+    pub environment: String,
+    pub parameters: BTreeMap<String, String>,
+    pub method: String,
+    pub url: String,
+    pub request_headers: Vec<HeaderView>,
+    pub request_body: Option<String>,
+    pub status: u16,
+    pub duration_ms: u128,
+    pub connect_ms: Option<u128>,
+    pub first_byte_ms: Option<u128>,
+    pub response_size: Option<usize>,
+    pub decoded_size: Option<usize>,
+    pub response_body: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct HeaderView {
+    pub name: String,
+    pub value: String,
+}
+
+impl HistoryEntryView {
+    pub fn new(entry: &HistoryEntry) -> HistoryEntryView {
+        HistoryEntryView {
+            id: entry.id,
+            timestamp: entry.timestamp.clone(),
+            service: entry.service.clone(),
+            endpoint: entry.endpoint.clone(),
+            environment: entry.environment.clone(),
+            parameters: to_sorted(&entry.parameters),
+            method: entry.method.clone(),
+            url: entry.url.clone(),
+            request_headers: entry.request_headers.iter()
+                .map(|(name, value)| HeaderView { name: name.clone(), value: value.clone() })
+                .collect(),
+            request_body: entry.request_body.clone(),
+            status: entry.status,
+            duration_ms: entry.duration_ms,
+            connect_ms: entry.connect_ms,
+            first_byte_ms: entry.first_byte_ms,
+            response_size: entry.response_size,
+            decoded_size: entry.decoded_size,
+            response_body: entry.response_body.clone(),
+        }
+    }
+}
+
+fn to_sorted(map: &HashMap<String, String>) -> BTreeMap<String, String> {
+    map.iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
@@ -0,0 +1,92 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::call_command::{add_har_args, CallServiceEndpointCommand};
+use crate::common::parse_duration;
+use crate::config::current_config::HtrsConfig;
+use crate::formats::har::HarCapture;
+use crate::outcomes::HtrsAction::PingEnvironments;
+use crate::outcomes::{HtrsAction, HtrsError};
+use crate::ping::{HealthCheck, PingTarget};
+use clap::{Arg, ArgMatches, Command};
+use std::collections::HashMap;
+
+pub struct PingCommand {
+    pub service: String,
+    pub endpoint: Option<String>,
+    pub timeout: String,
+    pub har: Option<String>,
+    pub redact_secrets: bool,
+}
+
+impl PingCommand {
+    pub fn get_command() -> Command {
+        let command = Command::new("ping")
+            .about("Check which environments of a service are healthy")
+            .arg(
+                Arg::new("service")
+                    .help("Name or alias of the service to ping")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("endpoint")
+                    .help("Endpoint to call instead of the service's health endpoint")
+                    .required(false)
+                    .long("endpoint")
+            )
+            .arg(
+                Arg::new("timeout")
+                    .value_name("duration")
+                    .help("How long to wait for each environment to respond")
+                    .required(false)
+                    .default_value("5s")
+                    .long("timeout")
+            );
+
+        add_har_args(command)
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> PingCommand {
+        PingCommand {
+            service: args.bind_field("service"),
+            endpoint: args.bind_field("endpoint"),
+            timeout: args.bind_field("timeout"),
+            har: args.bind_field("har"),
+            redact_secrets: args.bind_field("redact_secrets"),
+        }
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+        let Some(endpoint_name) = self.endpoint.as_ref().or(service.health_endpoint.as_ref()) else {
+            return Err(HtrsError::new(format!("No health endpoint set for service `{}`, set one with `htrs edit service {} --health-endpoint <endpoint>` or use `--endpoint`", service.name, service.name).as_str()));
+        };
+        let Some(endpoint) = service.get_endpoint(endpoint_name) else {
+            return Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", endpoint_name, service.name).as_str()));
+        };
+        if service.environments.is_empty() {
+            return Err(HtrsError::new(format!("Service `{}` has no environments to ping", service.name).as_str()));
+        }
+        let timeout = parse_duration(&self.timeout)?;
+
+        let targets = service.environments.iter()
+            .map(|environment| PingTarget {
+                environment: environment.name.clone(),
+                host: environment.host.clone(),
+                request: CallServiceEndpointCommand::new(
+                    service.name.clone(),
+                    Some(environment.name.clone()),
+                    endpoint.name.clone(),
+                    HashMap::new(),
+                    None,
+                ).prepare_request(config),
+            })
+            .collect();
+
+        Ok(PingEnvironments(HealthCheck {
+            targets,
+            timeout,
+            har: self.har.as_ref().map(|path| HarCapture::new(path, self.redact_secrets, config)),
+        }))
+    }
+}
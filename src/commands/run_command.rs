@@ -0,0 +1,85 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::call_command::add_har_args;
+use crate::config::current_config::HtrsConfig;
+use crate::formats::har::HarCapture;
+use crate::outcomes::HtrsAction::RunWorkflow;
+use crate::outcomes::{HtrsAction, HtrsError};
+use crate::workflow::WorkflowRun;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+pub struct RunCommand {
+    pub workflow: String,
+    pub environment: Option<String>,
+    pub show_body: bool,
+    pub har: Option<String>,
+    pub redact_secrets: bool,
+}
+
+impl RunCommand {
+    pub fn get_command() -> Command {
+        let command = Command::new("run")
+            .about("Run the steps of a workflow in order, stopping at the first failure")
+            .arg(
+                Arg::new("workflow")
+                    .help("Name of the workflow to run")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("environment")
+                    .help("Environment to run the workflow against, otherwise each service's default environment is used")
+                    .required(false)
+                    .long("environment")
+                    .short('e')
+            )
+            .arg(
+                Arg::new("body")
+                    .help("Print the response body of each step")
+                    .required(false)
+                    .action(ArgAction::SetTrue)
+                    .long("body")
+                    .short('b')
+            );
+
+        add_har_args(command)
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> RunCommand {
+        RunCommand {
+            workflow: args.bind_field("workflow"),
+            environment: args.bind_field("environment"),
+            show_body: args.bind_field("body"),
+            har: args.bind_field("har"),
+            redact_secrets: args.bind_field("redact_secrets"),
+        }
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(workflow) = config.get_workflow(&self.workflow) else {
+            return Err(HtrsError::new(format!("No workflow could be found with name `{}`", self.workflow).as_str()));
+        };
+        if workflow.steps.is_empty() {
+            return Err(HtrsError::new(format!("Workflow `{}` has no steps, add them with `htrs new step`", workflow.name).as_str()));
+        }
+
+        // Steps are checked against config before any are run so a workflow doesn't stop part way
+        // through because of a step that could never be called
+        for step in &workflow.steps {
+            let Some(service) = config.get_service(&step.service) else {
+                return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", step.service).as_str()));
+            };
+            if service.get_endpoint(&step.endpoint).is_none() {
+                return Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", step.endpoint, service.name).as_str()));
+            }
+            if let Some(environment) = &self.environment && service.get_environment(environment).is_none() {
+                return Err(HtrsError::new(format!("Unable to find environment with name or alias `{}` for service `{}`", environment, service.name).as_str()));
+            }
+        }
+
+        Ok(RunWorkflow(WorkflowRun {
+            workflow: workflow.clone(),
+            environment: self.environment.clone(),
+            show_body: self.show_body,
+            har: self.har.as_ref().map(|path| HarCapture::new(path, self.redact_secrets, config)),
+        }))
+    }
+}
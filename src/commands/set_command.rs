@@ -1,13 +1,22 @@
 use crate::commands::set_command::set_header_command::SetHeaderCommand;
-use crate::commands::set_command::SetCommand::Header;
+use crate::commands::set_command::set_mock_command::SetMockCommand;
+use crate::commands::set_command::set_pagination_command::SetPaginationCommand;
+use crate::commands::set_command::set_timing_command::SetTimingCommand;
+use crate::commands::set_command::SetCommand::{Header, Mock, Pagination, Timing};
 use crate::config::current_config::HtrsConfig;
 use crate::outcomes::{HtrsAction, HtrsError};
 use clap::{ArgMatches, Command};
 
 mod set_header_command;
+mod set_timing_command;
+mod set_mock_command;
+mod set_pagination_command;
 
 pub enum SetCommand {
     Header(SetHeaderCommand),
+    Timing(SetTimingCommand),
+    Mock(SetMockCommand),
+    Pagination(SetPaginationCommand),
 }
 
 impl SetCommand {
@@ -16,11 +25,17 @@ impl SetCommand {
             .about("Set a value for an item in config")
             .arg_required_else_help(true)
             .subcommand(SetHeaderCommand::get_command())
+            .subcommand(SetTimingCommand::get_command())
+            .subcommand(SetMockCommand::get_command())
+            .subcommand(SetPaginationCommand::get_command())
     }
 
     pub fn bind_from_matches(args: &ArgMatches) -> SetCommand {
         match args.subcommand() {
             Some(("header", header_matches)) => Header(SetHeaderCommand::bind_from_matches(header_matches)),
+            Some(("timing", timing_matches)) => Timing(SetTimingCommand::bind_from_matches(timing_matches)),
+            Some(("mock", mock_matches)) => Mock(SetMockCommand::bind_from_matches(mock_matches)),
+            Some(("pagination", pagination_matches)) => Pagination(SetPaginationCommand::bind_from_matches(pagination_matches)),
             _ => unreachable!(),
         }
     }
@@ -28,6 +43,9 @@ impl SetCommand {
     pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
         match self {
             Header(header) => header.execute(config),
+            Timing(timing) => timing.execute(config),
+            Mock(mock) => mock.execute(config),
+            Pagination(pagination) => pagination.execute(config),
         }
     }
 }
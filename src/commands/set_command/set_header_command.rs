@@ -7,9 +7,12 @@ use clap::{Arg, ArgMatches, Command};
 
 pub struct SetHeaderCommand {
     pub header_name: String,
-    pub header_value: String,
+    /// Only left out when just unmarking the header as a secret
+    pub header_value: Option<String>,
     pub service: Option<String>,
     pub environment: Option<String>,
+    pub secret: bool,
+    pub no_secret: bool,
 }
 
 impl SetHeaderCommand {
@@ -24,7 +27,7 @@ impl SetHeaderCommand {
             .arg(
                 Arg::new("value")
                     .help("The header value to set")
-                    .required(true)
+                    .required_unless_present("no_secret")
             )
             .arg(
                 Arg::new("service")
@@ -40,6 +43,21 @@ impl SetHeaderCommand {
                     .long("environment")
                     .short('e')
             )
+            .arg(
+                Arg::new("secret")
+                    .help("Mark the header as a secret so its value is redacted from recorded requests")
+                    .required(false)
+                    .num_args(0)
+                    .long("secret")
+            )
+            .arg(
+                Arg::new("no_secret")
+                    .help("Stop marking the header as a secret, the value can be left out to only do this")
+                    .required(false)
+                    .num_args(0)
+                    .conflicts_with("secret")
+                    .long("no-secret")
+            )
     }
 
     pub fn bind_from_matches(args: &ArgMatches) -> SetHeaderCommand {
@@ -48,10 +66,22 @@ impl SetHeaderCommand {
             header_value: args.bind_field("value"),
             service: args.bind_field("service"),
             environment: args.bind_field("environment"),
+            secret: args.bind_field("secret"),
+            no_secret: args.bind_field("no_secret"),
         }
     }
 
     pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        if self.secret && !config.is_secret_header(&self.header_name) {
+            config.secret_headers.push(self.header_name.clone());
+        }
+        if self.no_secret {
+            config.remove_secret_header(&self.header_name);
+        }
+        let Some(header_value) = &self.header_value else {
+            return Ok(UpdateConfig);
+        };
+
         let header_item: &mut dyn HeaderItem = match (&self.service, &self.environment) {
             (None, None) => config,
 
@@ -75,7 +105,7 @@ impl SetHeaderCommand {
             _ => return Err(HtrsError::new("Invalid combination of arguments used"))
         };
 
-        header_item.set_header(self.header_name.clone(), self.header_value.clone());
+        header_item.set_header(self.header_name.clone(), header_value.clone());
         Ok(UpdateConfig)
     }
 }
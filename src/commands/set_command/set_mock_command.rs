@@ -0,0 +1,106 @@
+use crate::commands::bindings::MatchBinding;
+use crate::common::parse_key_value_string;
+use crate::config::current_config::{HtrsConfig, MockResponse};
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command, ValueHint};
+use std::collections::HashMap;
+use std::path::Path;
+
+pub struct SetMockCommand {
+    pub endpoint: String,
+    pub service: String,
+    pub status: u64,
+    pub body: Option<String>,
+    pub file: Option<String>,
+    pub headers: Vec<String>,
+}
+
+impl SetMockCommand {
+    pub fn get_command() -> Command {
+        Command::new("mock")
+            .about("Set the response served for an endpoint by `htrs mock`")
+            .arg(
+                Arg::new("endpoint")
+                    .help("The endpoint to set the response for")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("service")
+                    .help("The service the endpoint belongs to")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+            .arg(
+                Arg::new("status")
+                    .help("Status of the response")
+                    .required(false)
+                    .default_value("200")
+                    .value_parser(value_parser!(u64).range(100..600))
+                    .long("status")
+            )
+            .arg(
+                Arg::new("body")
+                    .help("Body of the response")
+                    .required(false)
+                    .long("body")
+            )
+            .arg(
+                Arg::new("file")
+                    .help("File to read the body of the response from each time it's served")
+                    .value_hint(ValueHint::FilePath)
+                    .required(false)
+                    .conflicts_with("body")
+                    .long("file")
+            )
+            .arg(
+                Arg::new("header")
+                    .value_name("name=value")
+                    .help("A header to include in the response, in the format `name=value`")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .long("header")
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> SetMockCommand {
+        SetMockCommand {
+            endpoint: args.bind_field("endpoint"),
+            service: args.bind_field("service"),
+            status: args.bind_field("status"),
+            body: args.bind_field("body"),
+            file: args.bind_field("file"),
+            headers: args.bind_field("header"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service_mut(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+        let service_name = service.name.clone();
+        let Some(endpoint) = service.get_endpoint_mut(&self.endpoint) else {
+            return Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", self.endpoint, service_name).as_str()));
+        };
+        if let Some(file) = &self.file && !Path::new(file).is_file() {
+            return Err(HtrsError::new(format!("No file could be found at `{}`", file).as_str()));
+        }
+
+        let mut headers = HashMap::new();
+        for header in &self.headers {
+            let Ok((name, value)) = parse_key_value_string(header) else {
+                return Err(HtrsError::new(format!("Invalid header `{}`, should be in format `name=value`", header).as_str()));
+            };
+            headers.insert(name, value);
+        }
+
+        endpoint.mock = Some(MockResponse {
+            status: self.status as u16,
+            headers,
+            body: self.body.clone(),
+            file: self.file.clone(),
+        });
+        Ok(UpdateConfig)
+    }
+}
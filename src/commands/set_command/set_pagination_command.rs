@@ -0,0 +1,97 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::{HtrsConfig, Pagination, PaginationStrategy};
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+
+pub struct SetPaginationCommand {
+    pub endpoint: String,
+    pub service: String,
+    pub strategy: String,
+    pub parameter: Option<String>,
+    pub cursor: Option<String>,
+    pub items: Option<String>,
+}
+
+impl SetPaginationCommand {
+    pub fn get_command() -> Command {
+        Command::new("pagination")
+            .about("Set how `--all-pages` requests each page of an endpoint's responses")
+            .arg(
+                Arg::new("endpoint")
+                    .help("The endpoint to set pagination for")
+                    .required(true)
+            )
+            .arg(
+                Arg::new("service")
+                    .help("The service the endpoint belongs to")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+            .arg(
+                Arg::new("strategy")
+                    .help("How the next page is requested, by the `Link` header's next url, a cursor from the body, a page number or an offset")
+                    .required(true)
+                    .value_parser(["link", "cursor", "page", "offset"])
+                    .long("strategy")
+            )
+            .arg(
+                Arg::new("parameter")
+                    .value_name("query param")
+                    .help("Query parameter to send the cursor, page number or offset in")
+                    .required_if_eq_any([("strategy", "cursor"), ("strategy", "page"), ("strategy", "offset")])
+                    .long("parameter")
+            )
+            .arg(
+                Arg::new("cursor")
+                    .value_name("json path")
+                    .help("Path of the next page's cursor in the body, e.g. `$.next_cursor`")
+                    .required_if_eq("strategy", "cursor")
+                    .long("cursor")
+            )
+            .arg(
+                Arg::new("items")
+                    .value_name("json path")
+                    .help("Path of the array of items in the body, e.g. `$.data`, which are concatenated across pages")
+                    .required(false)
+                    .long("items")
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> SetPaginationCommand {
+        SetPaginationCommand {
+            endpoint: args.bind_field("endpoint"),
+            service: args.bind_field("service"),
+            strategy: args.bind_field("strategy"),
+            parameter: args.bind_field("parameter"),
+            cursor: args.bind_field("cursor"),
+            items: args.bind_field("items"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service_mut(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+        let service_name = service.name.clone();
+        let Some(endpoint) = service.get_endpoint_mut(&self.endpoint) else {
+            return Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", self.endpoint, service_name).as_str()));
+        };
+
+        let parameter = self.parameter.clone().unwrap_or_default();
+        let strategy = match self.strategy.as_str() {
+            "link" => PaginationStrategy::Link,
+            "cursor" => PaginationStrategy::Cursor { path: self.cursor.clone().unwrap_or_default(), parameter },
+            "page" => PaginationStrategy::Page { parameter },
+            "offset" => PaginationStrategy::Offset { parameter },
+            _ => unreachable!(),
+        };
+
+        endpoint.pagination = Some(Pagination {
+            strategy,
+            items: self.items.clone(),
+        });
+        Ok(UpdateConfig)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::commands::bindings::MatchBinding;
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::HtrsAction::UpdateConfig;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::builder::PossibleValuesParser;
+use clap::{Arg, ArgMatches, Command};
+
+pub struct SetTimingCommand {
+    pub value: String,
+}
+
+impl SetTimingCommand {
+    pub fn get_command() -> Command {
+        Command::new("timing")
+            .about("Set whether timing & size figures are always printed when calling an endpoint")
+            .arg(
+                Arg::new("value")
+                    .help("Whether to always print timing")
+                    .required(true)
+                    .value_parser(PossibleValuesParser::new(["on", "off"]))
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> SetTimingCommand {
+        SetTimingCommand {
+            value: args.bind_field("value"),
+        }
+    }
+
+    pub fn execute(&self, config: &mut HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        config.show_timing = self.value == "on";
+        Ok(UpdateConfig)
+    }
+}
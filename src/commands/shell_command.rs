@@ -0,0 +1,16 @@
+use crate::outcomes::HtrsAction::StartShell;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::Command;
+
+pub struct ShellCommand;
+
+impl ShellCommand {
+    pub fn get_command() -> Command {
+        Command::new("shell")
+            .about("Start an interactive session for calling endpoints & editing config")
+    }
+
+    pub fn execute(&self) -> Result<HtrsAction, HtrsError> {
+        Ok(StartShell)
+    }
+}
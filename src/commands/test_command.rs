@@ -0,0 +1,115 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::call_command::{add_har_args, CallServiceEndpointCommand};
+use crate::config::current_config::{HtrsConfig, Service};
+use crate::formats::har::HarCapture;
+use crate::outcomes::HtrsAction::{PrintDialogue, RunTests};
+use crate::outcomes::{HtrsAction, HtrsError};
+use crate::testing::{PreparedTest, TestRun};
+use clap::builder::ValueHint;
+use clap::{Arg, ArgMatches, Command};
+use std::path::PathBuf;
+
+pub struct TestCommand {
+    pub service: Option<String>,
+    pub environment: Option<String>,
+    pub junit: Option<String>,
+    pub har: Option<String>,
+    pub redact_secrets: bool,
+}
+
+impl TestCommand {
+    pub fn get_command() -> Command {
+        let command = Command::new("test")
+            .about("Run the test cases defined for services")
+            .arg(
+                Arg::new("service")
+                    .help("Only run tests for this service")
+                    .required(false)
+                    .long("service")
+                    .short('s')
+            )
+            .arg(
+                Arg::new("environment")
+                    .help("Environment to run tests against, otherwise each service's default environment is used")
+                    .required(false)
+                    .long("environment")
+                    .short('e')
+            )
+            .arg(
+                Arg::new("junit")
+                    .value_name("file")
+                    .help("Write a JUnit XML report of the results to a file")
+                    .required(false)
+                    .value_hint(ValueHint::FilePath)
+                    .long("junit")
+            );
+
+        add_har_args(command)
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> TestCommand {
+        TestCommand {
+            service: args.bind_field("service"),
+            environment: args.bind_field("environment"),
+            junit: args.bind_field("junit"),
+            har: args.bind_field("har"),
+            redact_secrets: args.bind_field("redact_secrets"),
+        }
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let services: Vec<&Service> = match &self.service {
+            Some(service_name) => {
+                let Some(service) = config.get_service(service_name) else {
+                    return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", service_name).as_str()));
+                };
+                if let Some(environment) = &self.environment && service.get_environment(environment).is_none() {
+                    return Err(HtrsError::new(format!("Unable to find environment with name or alias `{}` for service `{}`", environment, service.name).as_str()));
+                }
+                vec![service]
+            },
+            // Services without the environment are skipped rather than failed when running everything
+            None => config.services.iter()
+                .filter(|s| self.environment.as_ref().is_none_or(|e| s.get_environment(e).is_some()))
+                .collect(),
+        };
+
+        let mut tests = vec![];
+        for service in services {
+            for test in &service.tests {
+                if service.get_endpoint(&test.endpoint).is_none() {
+                    tests.push(PreparedTest {
+                        service: service.name.clone(),
+                        name: test.name.clone(),
+                        request: Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", test.endpoint, service.name).as_str())),
+                        assertions: test.assertions.clone(),
+                    });
+                    continue;
+                }
+                let call = CallServiceEndpointCommand::new(
+                    service.name.clone(),
+                    self.environment.clone(),
+                    test.endpoint.clone(),
+                    test.parameters.clone(),
+                    test.preset.clone(),
+                );
+                tests.push(PreparedTest {
+                    service: service.name.clone(),
+                    name: test.name.clone(),
+                    request: call.prepare_request(config),
+                    assertions: test.assertions.clone(),
+                });
+            }
+        }
+
+        if tests.is_empty() {
+            return Ok(PrintDialogue("No tests defined".to_string()));
+        }
+
+        Ok(RunTests(TestRun {
+            tests,
+            junit: self.junit.as_ref().map(PathBuf::from),
+            har: self.har.as_ref().map(|path| HarCapture::new(path, self.redact_secrets, config)),
+        }))
+    }
+}
@@ -1,16 +1,23 @@
+use crate::commands::output_format::OutputFormat;
+use crate::commands::view_command::view_endpoint_command::ViewEndpointCommand;
+use crate::commands::view_command::view_environment_command::ViewEnvironmentCommand;
 use crate::commands::view_command::view_preset_command::ViewPresetCommand;
 use crate::commands::view_command::view_service_command::ViewServiceCommand;
-use crate::commands::view_command::ViewCommand::{Preset, Service};
+use crate::commands::view_command::ViewCommand::{Endpoint, Environment, Preset, Service};
 use crate::config::current_config::HtrsConfig;
 use crate::outcomes::{HtrsAction, HtrsError};
 use clap::{ArgMatches, Command};
 
 mod view_preset_command;
 mod view_service_command;
+mod view_environment_command;
+mod view_endpoint_command;
 
 pub enum ViewCommand {
     Preset(ViewPresetCommand),
     Service(ViewServiceCommand),
+    Environment(ViewEnvironmentCommand),
+    Endpoint(ViewEndpointCommand),
 }
 
 impl ViewCommand {
@@ -18,14 +25,19 @@ impl ViewCommand {
         Command::new("view")
             .about("View an item")
             .arg_required_else_help(true)
+            .arg(OutputFormat::get_arg())
             .subcommand(ViewPresetCommand::get_command())
             .subcommand(ViewServiceCommand::get_command())
+            .subcommand(ViewEnvironmentCommand::get_command())
+            .subcommand(ViewEndpointCommand::get_command())
     }
 
     pub fn bind_from_matches(args: &ArgMatches) -> ViewCommand {
         match args.subcommand() {
             Some(("preset", preset_matches)) => Preset(ViewPresetCommand::bind_from_matches(preset_matches)),
             Some(("service", service_matches)) => Service(ViewServiceCommand::bind_from_matches(service_matches)),
+            Some(("environment", environment_matches)) => Environment(ViewEnvironmentCommand::bind_from_matches(environment_matches)),
+            Some(("endpoint", endpoint_matches)) => Endpoint(ViewEndpointCommand::bind_from_matches(endpoint_matches)),
             _ => unreachable!(),
         }
     }
@@ -34,6 +46,8 @@ impl ViewCommand {
         match self {
             Preset(command) => command.execute(config),
             Service(command) => command.execute(config),
+            Environment(command) => command.execute(config),
+            Endpoint(command) => command.execute(config),
         }
     }
 }
@@ -0,0 +1,172 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::EndpointView;
+use crate::config::current_config::{Endpoint, HtrsConfig, PaginationStrategy};
+use crate::config::ScopedHeader;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+use serde::Serialize;
+
+pub struct ViewEndpointCommand {
+    pub name: String,
+    pub service: String,
+    pub format: OutputFormat,
+}
+
+#[derive(Serialize)]
+struct EndpointDetails<'a> {
+    #[serde(skip)]
+    endpoint: &'a Endpoint,
+    #[serde(flatten)]
+    view: EndpointView,
+    effective_headers: Vec<ScopedHeader>,
+    sample_urls: Vec<SampleUrl>,
+}
+
+#[derive(Serialize)]
+struct SampleUrl {
+    environment: String,
+    url: String,
+}
+
+impl ViewEndpointCommand {
+    pub fn get_command() -> Command {
+        Command::new("endpoint")
+            .about("View an endpoint")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("name")
+                    .required(true)
+                    .help("Name of the endpoint to view")
+            )
+            .arg(
+                Arg::new("service")
+                    .help("Service the endpoint belongs to")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> Self {
+        Self {
+            name: args.bind_field("name"),
+            service: args.bind_field("service"),
+            format: OutputFormat::bind_from_matches(args),
+        }
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+        let Some(endpoint) = service.get_endpoint(&self.name) else {
+            return Err(HtrsError::new(format!("No endpoint could be found with name `{}` for service `{}`", self.name, service.name).as_str()));
+        };
+
+        let required_query = endpoint.query_parameters.iter()
+            .filter(|q| q.required)
+            .map(|q| format!("{}={{{}}}", q.name, q.name))
+            .collect::<Vec<String>>();
+        let query = match required_query.is_empty() {
+            true => String::new(),
+            false => format!("?{}", required_query.join("&")),
+        };
+
+        let view = EndpointDetails {
+            endpoint,
+            view: EndpointView::new(endpoint),
+            effective_headers: config.get_scoped_headers(Some(service), None, Some(endpoint)),
+            sample_urls: service.environments.iter()
+                .map(|environment| SampleUrl {
+                    environment: environment.name.clone(),
+                    url: format!("http://{}{}{}", environment.host, endpoint.path_template, query),
+                })
+                .collect(),
+        };
+        self.format.print(&view, || Self::get_endpoint_string(&view))
+    }
+
+    fn get_endpoint_string(view: &EndpointDetails) -> String {
+        let endpoint = view.endpoint;
+        let mut text = String::new();
+        text.push_str(format!("Name: {}\n", endpoint.name).as_str());
+        if let Some(alias) = &endpoint.alias {
+            text.push_str(format!("Alias: {}\n", alias).as_str());
+        }
+        if let Some(description) = &endpoint.description {
+            text.push_str(format!("Description: {}\n", description).as_str());
+        }
+        if !endpoint.tags.is_empty() {
+            text.push_str(format!("Tags: {}\n", endpoint.tags.join(", ")).as_str());
+        }
+        text.push_str(format!("Method: {}\n", endpoint.method).as_str());
+        text.push_str(format!("Path: {}\n", endpoint.path_template).as_str());
+
+        text.push_str("Path Parameters:\n");
+        if view.view.path_parameters.is_empty() {
+            text.push_str("  (no path parameters)\n");
+        }
+        for param in &view.view.path_parameters {
+            text.push_str(format!(" - {}{}\n", param.name, get_parameter_help(endpoint, &param.name)).as_str());
+        }
+
+        text.push_str("Query Parameters:\n");
+        if endpoint.query_parameters.is_empty() {
+            text.push_str("  (no query parameters)\n");
+        }
+        for param in &endpoint.query_parameters {
+            match param.required {
+                true => text.push_str(format!(" - *{}{}\n", param.name, get_parameter_help(endpoint, &param.name)).as_str()),
+                false => text.push_str(format!(" - {}{}\n", param.name, get_parameter_help(endpoint, &param.name)).as_str()),
+            };
+        }
+
+        text.push_str("Headers:\n");
+        if view.effective_headers.is_empty() {
+            text.push_str("  (no headers)\n");
+        }
+        for header in &view.effective_headers {
+            text.push_str(format!(" - {}: {} ({})\n", header.name, header.value, header.scope).as_str());
+        }
+
+        if let Some(body) = &endpoint.body {
+            text.push_str(format!("Body:\n{}\n", body).as_str());
+        }
+        if let Some(mock) = &endpoint.mock {
+            match &mock.file {
+                Some(file) => text.push_str(format!("Mock: {} (body from `{}`)\n", mock.status, file).as_str()),
+                None => text.push_str(format!("Mock: {}\n", mock.status).as_str()),
+            }
+        }
+        if let Some(pagination) = &endpoint.pagination {
+            let strategy = match &pagination.strategy {
+                PaginationStrategy::Link => "next url from `Link` header".to_string(),
+                PaginationStrategy::Cursor { path, parameter } => format!("cursor from `{}` as `{}`", path, parameter),
+                PaginationStrategy::Page { parameter } => format!("page number as `{}`", parameter),
+                PaginationStrategy::Offset { parameter } => format!("offset as `{}`", parameter),
+            };
+            match &pagination.items {
+                Some(items) => text.push_str(format!("Pagination: {} (items at `{}`)\n", strategy, items).as_str()),
+                None => text.push_str(format!("Pagination: {}\n", strategy).as_str()),
+            }
+        }
+
+        text.push_str("Sample URLs:\n");
+        if view.sample_urls.is_empty() {
+            text.push_str("  (no environments)\n");
+        }
+        for sample_url in &view.sample_urls {
+            text.push_str(format!(" - {}: {}\n", sample_url.environment, sample_url.url).as_str());
+        }
+
+        text
+    }
+}
+
+fn get_parameter_help(endpoint: &Endpoint, name: &str) -> String {
+    match endpoint.parameter_descriptions.get(name) {
+        Some(description) => format!(" ~ {}", description),
+        None => String::new(),
+    }
+}
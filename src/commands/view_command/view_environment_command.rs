@@ -0,0 +1,89 @@
+use crate::commands::bindings::MatchBinding;
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::EnvironmentView;
+use crate::config::current_config::{Environment, HtrsConfig};
+use crate::config::ScopedHeader;
+use crate::outcomes::{HtrsAction, HtrsError};
+use clap::{Arg, ArgMatches, Command};
+use serde::Serialize;
+
+pub struct ViewEnvironmentCommand {
+    pub name: String,
+    pub service: String,
+    pub format: OutputFormat,
+}
+
+#[derive(Serialize)]
+struct EnvironmentDetails<'a> {
+    #[serde(skip)]
+    environment: &'a Environment,
+    #[serde(flatten)]
+    view: EnvironmentView,
+    effective_headers: Vec<ScopedHeader>,
+}
+
+impl ViewEnvironmentCommand {
+    pub fn get_command() -> Command {
+        Command::new("environment")
+            .about("View an environment")
+            .visible_alias("env")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("name")
+                    .required(true)
+                    .help("Name or alias of the environment to view")
+            )
+            .arg(
+                Arg::new("service")
+                    .help("Service the environment belongs to")
+                    .required(true)
+                    .long("service")
+                    .short('s')
+            )
+    }
+
+    pub fn bind_from_matches(args: &ArgMatches) -> Self {
+        Self {
+            name: args.bind_field("name"),
+            service: args.bind_field("service"),
+            format: OutputFormat::bind_from_matches(args),
+        }
+    }
+
+    pub fn execute(&self, config: &HtrsConfig) -> Result<HtrsAction, HtrsError> {
+        let Some(service) = config.get_service(&self.service) else {
+            return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.service).as_str()));
+        };
+        let Some(environment) = service.get_environment(&self.name) else {
+            return Err(HtrsError::new(format!("Unable to find environment with name or alias `{}` for service `{}`", self.name, service.name).as_str()));
+        };
+
+        let view = EnvironmentDetails {
+            environment,
+            view: EnvironmentView::new(environment),
+            effective_headers: config.get_scoped_headers(Some(service), Some(environment), None),
+        };
+        self.format.print(&view, || Self::get_environment_string(&view))
+    }
+
+    fn get_environment_string(view: &EnvironmentDetails) -> String {
+        let environment = view.environment;
+        let mut text = String::new();
+        text.push_str(format!("Name: {}\n", environment.name).as_str());
+        if let Some(alias) = &environment.alias {
+            text.push_str(format!(" Alias: {}\n", alias).as_str());
+        }
+        text.push_str(format!("Host: {}\n", environment.host).as_str());
+        text.push_str(format!("Default: {}\n", environment.default).as_str());
+
+        text.push_str("Headers:\n");
+        if view.effective_headers.is_empty() {
+            text.push_str("  (no headers)\n");
+        }
+        for header in &view.effective_headers {
+            text.push_str(format!(" - {}: {} ({})\n", header.name, header.value, header.scope).as_str());
+        }
+
+        text
+    }
+}
@@ -1,10 +1,13 @@
 use crate::commands::bindings::MatchBinding;
-use crate::config::current_config::HtrsConfig;
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::PresetView;
+use crate::config::current_config::{HtrsConfig, Preset};
 use crate::outcomes::{HtrsAction, HtrsError};
 use clap::{Arg, ArgMatches, Command};
 
 pub struct ViewPresetCommand {
     pub name: String,
+    pub format: OutputFormat,
 }
 
 impl ViewPresetCommand {
@@ -21,7 +24,8 @@ impl ViewPresetCommand {
 
     pub fn bind_from_matches(args: &ArgMatches) -> ViewPresetCommand {
         ViewPresetCommand {
-            name: args.bind_field("name")
+            name: args.bind_field("name"),
+            format: OutputFormat::bind_from_matches(args),
         }
     }
 
@@ -30,6 +34,10 @@ impl ViewPresetCommand {
             return Err(HtrsError::new(format!("No preset could be found with name or alias `{}`", self.name).as_str()));
         };
 
+        self.format.print(&PresetView::new(preset), || Self::get_preset_string(preset))
+    }
+
+    fn get_preset_string(preset: &Preset) -> String {
         let name = match preset.alias {
             Some(ref alias) => format!("{} ({}):", preset.name, alias),
             None => format!("{}:", preset.name),
@@ -40,6 +48,6 @@ impl ViewPresetCommand {
             .collect::<Vec<String>>()
             .join("\n");
 
-        Ok(HtrsAction::PrintDialogue(format!("{}\n{}\n", name, values)))
+        format!("{}\n{}\n", name, values)
     }
 }
\ No newline at end of file
@@ -1,11 +1,13 @@
 use crate::commands::bindings::MatchBinding;
-use crate::config::current_config::{Endpoint, Environment, HtrsConfig};
-use crate::outcomes::HtrsAction::PrintDialogue;
+use crate::commands::output_format::OutputFormat;
+use crate::commands::output_view::ServiceView;
+use crate::config::current_config::{Endpoint, Environment, HtrsConfig, Service};
 use crate::outcomes::{HtrsAction, HtrsError};
 use clap::{Arg, ArgMatches, Command};
 
 pub struct ViewServiceCommand {
     pub name: String,
+    pub format: OutputFormat,
 }
 
 impl ViewServiceCommand {
@@ -22,6 +24,7 @@ impl ViewServiceCommand {
     pub fn bind_from_matches(args: &ArgMatches) -> Self {
         Self {
             name: args.bind_field("name"),
+            format: OutputFormat::bind_from_matches(args),
         }
     }
 
@@ -30,11 +33,18 @@ impl ViewServiceCommand {
             return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", self.name).as_str()));
         };
 
+        self.format.print(&ServiceView::new(service), || Self::get_service_string(service))
+    }
+
+    fn get_service_string(service: &Service) -> String {
         let mut text = String::new();
         text.push_str(format!("Name: {}\n", service.name).as_str());
         if let Some(alias) = &service.alias {
             text.push_str(format!(" Alias: {}\n", alias).as_str());
         }
+        if let Some(health_endpoint) = &service.health_endpoint {
+            text.push_str(format!(" Health endpoint: {}\n", health_endpoint).as_str());
+        }
         text.push_str("Environments:\n");
         let environment_text = service.environments.iter()
             .map(Self::get_environment_str)
@@ -55,7 +65,7 @@ impl ViewServiceCommand {
             false => endpoint_text.as_str(),
         });
 
-        Ok(PrintDialogue(text))
+        text
     }
 
     fn get_environment_str(environment: &Environment) -> String {
@@ -67,11 +77,21 @@ impl ViewServiceCommand {
 
     fn get_endpoint_string(endpoint: &Endpoint) -> String {
         let mut text = String::new();
-        text.push_str(format!(" - {} ~ {}\n", endpoint.name, endpoint.path_template).as_str());
+        text.push_str(format!(" - {} ~ {} ({})\n", endpoint.display_name(), endpoint.path_template, endpoint.method).as_str());
+        if let Some(description) = &endpoint.description {
+            text.push_str(format!("   {}\n", description).as_str());
+        }
+        if !endpoint.tags.is_empty() {
+            text.push_str(format!("   Tags: {}\n", endpoint.tags.join(", ")).as_str());
+        }
         for param in &endpoint.query_parameters {
+            let help = match endpoint.parameter_descriptions.get(&param.name) {
+                Some(description) => format!(" ~ {}", description),
+                None => String::new(),
+            };
             match param.required {
-                true => text.push_str(format!("   - *{}\n", param.name).as_str()),
-                false => text.push_str(format!("   - {}\n", param.name).as_str()),
+                true => text.push_str(format!("   - *{}{}\n", param.name, help).as_str()),
+                false => text.push_str(format!("   - {}{}\n", param.name, help).as_str()),
             };
         }
 
@@ -1,7 +1,11 @@
+use crate::config::current_config::Endpoint;
+use crate::outcomes::HtrsError;
 use lazy_static::lazy_static;
 use regex::Regex;
+use reqwest::Method;
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
+use std::time::Duration;
 
 pub fn get_params_from_path(path: &str) -> Vec<String> {
     lazy_static! {
@@ -40,6 +44,73 @@ pub fn parse_key_value_string(s: &str) -> Result<(String, String), ()> {
     Ok((left.to_string(), right.to_string()))
 }
 
+pub fn parse_duration(value: &str) -> Result<Duration, HtrsError> {
+    match humantime::parse_duration(value) {
+        Ok(duration) => Ok(duration),
+        Err(_) => Err(HtrsError::new(format!("Invalid duration `{}`, should be in a format like `500ms` or `2s`", value).as_str())),
+    }
+}
+
+/// Parses help text given for parameters in the format `name=text`, each name must be a path or
+/// query parameter of the endpoint
+pub fn parse_parameter_descriptions(endpoint: &Endpoint, values: &[String]) -> Result<HashMap<String, String>, HtrsError> {
+    let parameter_names = endpoint.get_parameter_names();
+    let mut descriptions = HashMap::new();
+    for value in values {
+        let Ok((name, description)) = parse_key_value_string(value) else {
+            return Err(HtrsError::new(format!("Invalid parameter help `{}`, should be in format `name=text`", value).as_str()));
+        };
+        if !parameter_names.contains(&name) {
+            return Err(HtrsError::new(format!("Endpoint `{}` has no path or query parameter named `{}`", endpoint.name, name).as_str()));
+        }
+        descriptions.insert(name, description);
+    }
+    Ok(descriptions)
+}
+
+/// Parses values given for parameters in the format `name=value`, each name must be a path or
+/// query parameter of the endpoint
+pub fn parse_parameter_values(endpoint: &Endpoint, values: &[String]) -> Result<HashMap<String, String>, HtrsError> {
+    let parameter_names = endpoint.get_parameter_names();
+    let mut parameters = HashMap::new();
+    for value in values {
+        let Ok((name, value)) = parse_key_value_string(value) else {
+            return Err(HtrsError::new(format!("Invalid parameter `{}`, should be in format `name=value`", value).as_str()));
+        };
+        if !parameter_names.contains(&name) {
+            return Err(HtrsError::new(format!("Endpoint `{}` has no path or query parameter named `{}`", endpoint.name, name).as_str()));
+        }
+        parameters.insert(name, value);
+    }
+    Ok(parameters)
+}
+
+/// Validates a http method name, returning it in its canonical upper-case form
+pub fn parse_method(method: &str) -> Result<String, HtrsError> {
+    let method = method.to_uppercase();
+    match Method::from_bytes(method.as_bytes()) {
+        Ok(_) => Ok(method),
+        Err(_) => Err(HtrsError::new(format!("Invalid http method `{}`", method).as_str())),
+    }
+}
+
+/// Encodes bytes using the standard base64 alphabet with padding
+pub fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true => encoded.push(ALPHABET[((n >> (18 - i * 6)) & 0x3f) as usize] as char),
+                false => encoded.push('='),
+            }
+        }
+    }
+    encoded
+}
+
 /// Merges two given HashMaps into a new HashMap, if a value is present in both hashmaps the value
 /// provided in HashMap `b` will be used in the returned HashMap
 pub fn merge_hashmaps<T: Eq + Hash, U>(a: HashMap<T, U>, b: HashMap<T, U>) -> HashMap<T, U> {
@@ -54,9 +125,73 @@ pub fn merge_hashmaps<T: Eq + Hash, U>(a: HashMap<T, U>, b: HashMap<T, U>) -> Ha
     merged
 }
 
+/// Splits a command line into words following posix shell quoting rules
+pub fn split_words(command: &str) -> Result<Vec<String>, HtrsError> {
+    let mut tokens = vec![];
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut chars = command.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_token = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => return Err(HtrsError::new("Unterminated single quote")),
+                    }
+                }
+            },
+            '"' => {
+                in_token = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\' | '$' | '`')) => current.push(c),
+                            Some('\n') => {},
+                            Some(c) => {
+                                current.push('\\');
+                                current.push(c);
+                            },
+                            None => return Err(HtrsError::new("Unterminated double quote")),
+                        },
+                        Some(c) => current.push(c),
+                        None => return Err(HtrsError::new("Unterminated double quote")),
+                    }
+                }
+            },
+            '\\' => match chars.next() {
+                Some('\n') | Some('\r') | None => {},
+                Some(c) => {
+                    in_token = true;
+                    current.push(c);
+                },
+            },
+            c if c.is_whitespace() => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            },
+            c => {
+                in_token = true;
+                current.push(c);
+            },
+        }
+    }
+    if in_token {
+        tokens.push(current);
+    }
+
+    Ok(tokens)
+}
+
 #[cfg(test)]
 mod common_tests {
-    use crate::common::parse_key_value_string;
+    use crate::common::{encode_base64, parse_key_value_string};
     use rstest::rstest;
 
     #[test]
@@ -81,4 +216,17 @@ mod common_tests {
         let result = parse_key_value_string(s);
         assert!(result.is_err());
     }
+
+    #[rstest]
+    #[case("", "")]
+    #[case("f", "Zg==")]
+    #[case("fo", "Zm8=")]
+    #[case("foo", "Zm9v")]
+    #[case("user:pass", "dXNlcjpwYXNz")]
+    fn given_bytes_when_encode_base64_then_should_encode(
+        #[case] s: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(encode_base64(s.as_bytes()), expected);
+    }
 }
@@ -0,0 +1,227 @@
+use crate::formats::har::HarCapture;
+use crate::json_path::{format_path, matches_any, parse_path};
+use crate::outcomes::HtrsError;
+use crate::request::{send_request, Exchange, PreparedRequest};
+use reqwest::blocking::Client;
+use serde_json::Value;
+
+/// The same request resolved for several environments, whose responses are compared against the
+/// response from the first environment
+pub struct Comparison {
+    pub requests: Vec<(String, PreparedRequest)>,
+    pub headers: Vec<String>,
+    pub ignore_paths: Vec<String>,
+    pub har: Option<HarCapture>,
+}
+
+/// A value that differs between two responses, `None` where the value is missing from a response
+#[derive(Debug, PartialEq)]
+pub struct Difference {
+    pub path: String,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+}
+
+impl Comparison {
+    /// Sends each request & prints the differences between the responses, failing if there are any
+    pub fn run(&self) -> Result<(), HtrsError> {
+        let client = Client::new();
+        let mut exchanges: Vec<Exchange> = vec![];
+        let mut sent = Ok(());
+        for (_, request) in &self.requests {
+            match send_request(&client, &request.method, &request.url, &request.headers, request.body.as_deref()) {
+                Ok(exchange) => exchanges.push(exchange),
+                Err(e) => {
+                    sent = Err(e);
+                    break;
+                },
+            }
+        }
+        // Responses received before a request failed are still recorded
+        if let Some(har) = &self.har {
+            har.write(&exchanges)?;
+        }
+        sent?;
+
+        let ignore_paths: Vec<Vec<String>> = self.ignore_paths.iter()
+            .map(|p| parse_path(p))
+            .collect();
+        let environments: Vec<&String> = self.requests.iter().map(|(environment, _)| environment).collect();
+        let (Some((base_environment, other_environments)), Some((base, others))) = (environments.split_first(), exchanges.split_first()) else {
+            return Ok(());
+        };
+
+        let mut difference_count = 0;
+        for (environment, exchange) in other_environments.iter().zip(others) {
+            let differences = self.compare_exchanges(base, exchange, &ignore_paths);
+            difference_count += differences.len();
+
+            println!("{} ({}) vs {} ({})", base_environment, base.status.as_u16(), environment, exchange.status.as_u16());
+            if differences.is_empty() {
+                println!(" No differences");
+            }
+            for difference in differences {
+                println!(" ~ {}: {} vs {}", difference.path, display_value(&difference.expected), display_value(&difference.actual));
+            }
+        }
+
+        match difference_count {
+            0 => Ok(()),
+            1 => Err(HtrsError::new("Found 1 difference between environments")),
+            n => Err(HtrsError::new(format!("Found {} differences between environments", n).as_str())),
+        }
+    }
+
+    fn compare_exchanges(&self, expected: &Exchange, actual: &Exchange, ignore_paths: &[Vec<String>]) -> Vec<Difference> {
+        let mut differences = vec![];
+        if expected.status != actual.status {
+            differences.push(Difference {
+                path: "status".to_string(),
+                expected: Some(expected.status.as_u16().to_string()),
+                actual: Some(actual.status.as_u16().to_string()),
+            });
+        }
+
+        for header in &self.headers {
+            let expected_value = get_header(expected, header);
+            let actual_value = get_header(actual, header);
+            if expected_value != actual_value {
+                differences.push(Difference {
+                    path: format!("header {}", header.to_lowercase()),
+                    expected: expected_value,
+                    actual: actual_value,
+                });
+            }
+        }
+
+        match (serde_json::from_str::<Value>(&expected.response_body), serde_json::from_str::<Value>(&actual.response_body)) {
+            (Ok(expected_body), Ok(actual_body)) => {
+                diff_json(&expected_body, &actual_body, &mut vec!["$".to_string()], ignore_paths, &mut differences);
+            },
+            _ if expected.response_body != actual.response_body => differences.push(Difference {
+                path: "body".to_string(),
+                expected: Some(summarise_text(&expected.response_body)),
+                actual: Some(summarise_text(&actual.response_body)),
+            }),
+            _ => {},
+        }
+
+        differences
+    }
+}
+
+fn get_header(exchange: &Exchange, name: &str) -> Option<String> {
+    exchange.response_headers.iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.clone())
+}
+
+fn display_value(value: &Option<String>) -> &str {
+    match value {
+        Some(value) => value.as_str(),
+        None => "(missing)",
+    }
+}
+
+fn summarise_text(text: &str) -> String {
+    const MAX_LENGTH: usize = 60;
+    match text.chars().count() > MAX_LENGTH {
+        true => format!("{:?}...", text.chars().take(MAX_LENGTH).collect::<String>()),
+        false => format!("{:?}", text),
+    }
+}
+
+/// Finds the differences between two json values, recursing into objects & arrays
+pub fn diff_json(expected: &Value, actual: &Value, path: &mut Vec<String>, ignore_paths: &[Vec<String>], differences: &mut Vec<Difference>) {
+    if matches_any(path, ignore_paths) {
+        return;
+    }
+
+    match (expected, actual) {
+        (Value::Object(expected_map), Value::Object(actual_map)) => {
+            let mut keys: Vec<&String> = expected_map.keys().chain(actual_map.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                path.push(key.clone());
+                match (expected_map.get(key), actual_map.get(key)) {
+                    (Some(expected_value), Some(actual_value)) => diff_json(expected_value, actual_value, path, ignore_paths, differences),
+                    (expected_value, actual_value) if !matches_any(path, ignore_paths) => differences.push(Difference {
+                        path: format_path(path),
+                        expected: expected_value.map(|v| v.to_string()),
+                        actual: actual_value.map(|v| v.to_string()),
+                    }),
+                    _ => {},
+                }
+                path.pop();
+            }
+        },
+        (Value::Array(expected_items), Value::Array(actual_items)) => {
+            for index in 0..expected_items.len().max(actual_items.len()) {
+                path.push(format!("[{}]", index));
+                match (expected_items.get(index), actual_items.get(index)) {
+                    (Some(expected_value), Some(actual_value)) => diff_json(expected_value, actual_value, path, ignore_paths, differences),
+                    (expected_value, actual_value) if !matches_any(path, ignore_paths) => differences.push(Difference {
+                        path: format_path(path),
+                        expected: expected_value.map(|v| v.to_string()),
+                        actual: actual_value.map(|v| v.to_string()),
+                    }),
+                    _ => {},
+                }
+                path.pop();
+            }
+        },
+        _ if expected != actual => differences.push(Difference {
+            path: format_path(path),
+            expected: Some(expected.to_string()),
+            actual: Some(actual.to_string()),
+        }),
+        _ => {},
+    }
+}
+
+#[cfg(test)]
+mod compare_tests {
+    use crate::compare::{diff_json, Difference};
+    use crate::json_path::parse_path;
+    use serde_json::json;
+
+    fn diff(expected: serde_json::Value, actual: serde_json::Value, ignore_paths: &[&str]) -> Vec<Difference> {
+        let ignore_paths: Vec<Vec<String>> = ignore_paths.iter().map(|p| parse_path(p)).collect();
+        let mut differences = vec![];
+        diff_json(&expected, &actual, &mut vec!["$".to_string()], &ignore_paths, &mut differences);
+        differences
+    }
+
+    #[test]
+    fn given_equal_values_when_diff_then_should_have_no_differences() {
+        assert!(diff(json!({"a": [1, {"b": true}]}), json!({"a": [1, {"b": true}]}), &[]).is_empty());
+    }
+
+    #[test]
+    fn given_changed_missing_and_added_values_when_diff_then_should_report_each() {
+        let differences = diff(
+            json!({"name": "foo", "items": [1, 2], "old": 1}),
+            json!({"name": "bar", "items": [1], "new": 1}),
+            &[],
+        );
+
+        assert_eq!(differences, vec![
+            Difference { path: "$.items[1]".to_string(), expected: Some("2".to_string()), actual: None },
+            Difference { path: "$.name".to_string(), expected: Some("\"foo\"".to_string()), actual: Some("\"bar\"".to_string()) },
+            Difference { path: "$.new".to_string(), expected: None, actual: Some("1".to_string()) },
+            Difference { path: "$.old".to_string(), expected: Some("1".to_string()), actual: None },
+        ]);
+    }
+
+    #[test]
+    fn given_ignore_paths_when_diff_then_should_skip_matching_values() {
+        let differences = diff(
+            json!({"updated_at": 1, "items": [{"id": 1, "updated_at": 1}]}),
+            json!({"updated_at": 2, "items": [{"id": 1, "updated_at": 2}]}),
+            &["updated_at", "$.items[*].updated_at"],
+        );
+
+        assert!(differences.is_empty());
+    }
+}
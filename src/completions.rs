@@ -0,0 +1,191 @@
+use crate::config::current_config::{HtrsConfig, Service};
+use clap::builder::ValueHint;
+use clap::{Arg, Command};
+
+/// Name of the hidden command that completion scripts call back into
+pub const COMPLETE_COMMAND: &str = "__complete";
+
+pub const SHELLS: [&str; 4] = ["bash", "zsh", "fish", "powershell"];
+
+/// Gets the script for a shell that completes htrs commands by calling back into htrs, so
+/// completions always reflect the current config
+pub fn get_completion_script(shell: &str) -> String {
+    let script = match shell {
+        "bash" => r#"_htrs() {
+    local IFS=$'\n'
+    COMPREPLY=($(htrs __complete "${COMP_WORDS[@]:1:COMP_CWORD}" 2>/dev/null))
+}
+complete -o default -F _htrs htrs"#,
+        "zsh" => r#"#compdef htrs
+_htrs() {
+    local -a candidates
+    candidates=(${(f)"$(htrs __complete "${(@)words[2,CURRENT]}" 2>/dev/null)"})
+    if (( ${#candidates} )); then
+        compadd -a candidates
+    else
+        _files
+    fi
+}
+compdef _htrs htrs"#,
+        "fish" => r#"function __htrs_complete
+    set -l tokens (commandline -opc)
+    set -l current (commandline -ct)
+    htrs __complete $tokens[2..-1] "$current" 2>/dev/null
+end
+complete -c htrs -f -a '(__htrs_complete)'"#,
+        "powershell" => r#"Register-ArgumentCompleter -Native -CommandName htrs -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+    $words = @($commandAst.CommandElements | Select-Object -Skip 1 | ForEach-Object { $_.ToString() })
+    if ($wordToComplete -eq '') {
+        $words += ''
+    }
+    htrs __complete @words 2>$null | ForEach-Object {
+        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)
+    }
+}"#,
+        _ => unreachable!(),
+    };
+    script.to_string()
+}
+
+/// Gets the candidates for the last of the given words, which is the word being completed. The
+/// preceding words are walked through the command tree to find the command & argument in use.
+pub fn get_completions(mut root: Command, config: &HtrsConfig, words: &[String]) -> Vec<String> {
+    root.build();
+    let (current, previous) = match words.split_last() {
+        Some((current, previous)) => (current.as_str(), previous),
+        None => ("", words),
+    };
+
+    let mut command = &root;
+    let mut path: Vec<&str> = vec![];
+    let mut service: Option<&Service> = None;
+    let mut pending_value: Option<&Arg> = None;
+    let mut positional_index = 0;
+
+    for word in previous {
+        if let Some(arg) = pending_value.take() {
+            bind_service(arg, word, config, &mut service);
+            continue;
+        }
+
+        if let Some(long) = word.strip_prefix("--") {
+            let (name, inline_value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (long, None),
+            };
+            let Some(arg) = command.get_arguments().find(|a| a.get_long() == Some(name)) else {
+                continue;
+            };
+            match inline_value {
+                Some(value) => bind_service(arg, value, config, &mut service),
+                None if arg.get_action().takes_values() => pending_value = Some(arg),
+                None => {},
+            }
+        } else if let Some(short) = word.strip_prefix('-') && !short.is_empty() {
+            let Some(arg) = short.chars().next().and_then(|c| command.get_arguments().find(|a| a.get_short() == Some(c))) else {
+                continue;
+            };
+            if arg.get_action().takes_values() && short.len() == 1 {
+                pending_value = Some(arg);
+            }
+        } else if let Some(subcommand) = command.find_subcommand(word) {
+            if matches!(path.last(), Some(&"call" | &"compare")) {
+                service = config.get_service(word);
+            }
+            path.push(subcommand.get_name());
+            command = subcommand;
+            positional_index = 0;
+        } else {
+            if let Some(arg) = command.get_positionals().nth(positional_index) {
+                bind_service(arg, word, config, &mut service);
+            }
+            positional_index += 1;
+        }
+    }
+
+    let candidates = match pending_value {
+        Some(arg) => get_value_candidates(arg, &path, config, service),
+        None if current.starts_with('-') => command.get_arguments()
+            .filter(|a| !a.is_hide_set())
+            .filter_map(|a| a.get_long())
+            .map(|long| format!("--{}", long))
+            .collect(),
+        None => {
+            let mut candidates: Vec<String> = command.get_subcommands()
+                .filter(|c| !c.is_hide_set())
+                .flat_map(|c| std::iter::once(c.get_name()).chain(c.get_visible_aliases()))
+                .map(|name| name.to_string())
+                .collect();
+            if let Some(arg) = command.get_positionals().nth(positional_index) {
+                candidates.extend(get_value_candidates(arg, &path, config, service));
+            }
+            candidates
+        },
+    };
+
+    let mut completions: Vec<String> = vec![];
+    for candidate in candidates {
+        if candidate.starts_with(current) && !completions.contains(&candidate) {
+            completions.push(candidate);
+        }
+    }
+    completions
+}
+
+/// Keeps track of the service named on the command line so that its environments & endpoints can
+/// be offered for later arguments
+fn bind_service<'a>(arg: &Arg, value: &str, config: &'a HtrsConfig, service: &mut Option<&'a Service>) {
+    if arg.get_id() == "service" {
+        *service = config.get_service(value);
+    }
+}
+
+fn get_value_candidates(arg: &Arg, path: &[&str], config: &HtrsConfig, service: Option<&Service>) -> Vec<String> {
+    if matches!(arg.get_value_hint(), ValueHint::FilePath | ValueHint::DirPath | ValueHint::AnyPath) {
+        return vec![];
+    }
+
+    let id = match (arg.get_id().as_str(), path) {
+        ("name", ["new" | "copy", ..]) => return vec![],
+        ("name", [.., item]) => *item,
+        (id, _) => id,
+    };
+
+    match id {
+        "service" => config.services.iter()
+            .flat_map(|s| std::iter::once(&s.name).chain(s.alias.as_ref()))
+            .cloned()
+            .collect(),
+        "environment" => match service {
+            Some(service) => service.environments.iter()
+                .flat_map(|e| std::iter::once(&e.name).chain(e.alias.as_ref()))
+                .cloned()
+                .collect(),
+            None => config.services.iter()
+                .flat_map(|s| &s.environments)
+                .map(|e| e.name.clone())
+                .collect(),
+        },
+        "endpoint" => match service {
+            Some(service) => service.endpoints.iter()
+                .flat_map(|e| std::iter::once(&e.name).chain(e.alias.as_ref()))
+                .cloned()
+                .collect(),
+            None => vec![],
+        },
+        "test" => match service {
+            Some(service) => service.tests.iter().map(|t| t.name.clone()).collect(),
+            None => vec![],
+        },
+        "workflow" => config.workflows.iter().map(|w| w.name.clone()).collect(),
+        "preset" => config.presets.iter()
+            .flat_map(|p| std::iter::once(&p.name).chain(p.alias.as_ref()))
+            .cloned()
+            .collect(),
+        _ => arg.get_possible_values().iter()
+            .filter(|v| !v.is_hide_set())
+            .map(|v| v.get_name().to_string())
+            .collect(),
+    }
+}
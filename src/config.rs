@@ -2,9 +2,14 @@ pub mod current_config;
 mod versioned_config;
 mod util;
 
-use crate::config::current_config::{Endpoint, Environment, HtrsConfig, Preset, QueryParameter, Service};
+use crate::common::get_params_from_path;
+use crate::config::current_config::{Assertion, Endpoint, Extraction, Environment, HtrsConfig, Preset, QueryParameter, Service, TestCase, Workflow};
+use crate::config::util::get_data_file_path;
 use crate::config::versioned_config::VersionedHtrsConfig;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
 
 impl HtrsConfig {
     pub fn load() -> Result<HtrsConfig, String> {
@@ -14,6 +19,10 @@ impl HtrsConfig {
     pub fn save(self) -> Result<(), String> {
         VersionedHtrsConfig::save_config(self)
     }
+
+    pub fn get_data_file_path(extension: &str) -> Result<PathBuf, String> {
+        get_data_file_path(extension)
+    }
 }
 
 impl QueryParameter {
@@ -37,6 +46,9 @@ impl HtrsConfig {
             services: Vec::new(),
             headers: HashMap::new(),
             presets: Vec::new(),
+            secret_headers: Vec::new(),
+            workflows: Vec::new(),
+            show_timing: false,
         }
     }
 
@@ -62,11 +74,120 @@ impl HtrsConfig {
         self.presets.iter_mut().find(|p| p.name == name)
     }
 
+    /// Gets the headers that would be sent for the given scopes along with the scope each value comes
+    /// from, following the precedence endpoint > environment > service > global
+    pub fn get_scoped_headers(
+        &self,
+        service: Option<&Service>,
+        environment: Option<&Environment>,
+        endpoint: Option<&Endpoint>,
+    ) -> Vec<ScopedHeader> {
+        let scopes = [
+            (Some(&self.headers), HeaderScope::Global),
+            (service.map(|s| &s.headers), HeaderScope::Service),
+            (environment.map(|e| &e.headers), HeaderScope::Environment),
+            (endpoint.map(|e| &e.headers), HeaderScope::Endpoint),
+        ];
+
+        let mut headers: HashMap<String, (String, HeaderScope)> = HashMap::new();
+        for (scope_headers, scope) in scopes {
+            for (name, value) in scope_headers.into_iter().flatten() {
+                headers.insert(name.clone(), (value.clone(), scope));
+            }
+        }
+
+        let mut headers: Vec<ScopedHeader> = headers.into_iter()
+            .map(|(name, (value, scope))| ScopedHeader { name, value, scope })
+            .collect();
+        headers.sort_by(|a, b| a.name.cmp(&b.name));
+        headers
+    }
+
+    pub fn get_headers(&self, service: Option<&Service>, environment: Option<&Environment>, endpoint: Option<&Endpoint>) -> HashMap<String, String> {
+        self.get_scoped_headers(service, environment, endpoint).into_iter()
+            .map(|header| (header.name, header.value))
+            .collect()
+    }
+
+    pub fn is_secret_header(&self, name: &str) -> bool {
+        self.secret_headers.iter().any(|h| h.eq_ignore_ascii_case(name))
+    }
+
+    pub fn remove_secret_header(&mut self, name: &str) {
+        self.secret_headers.retain(|h| !h.eq_ignore_ascii_case(name));
+    }
+
+    /// Whether a header with the name (ignoring case) is set at any scope
+    pub fn has_header(&self, name: &str) -> bool {
+        let is_named = |headers: &HashMap<String, String>| headers.keys().any(|h| h.eq_ignore_ascii_case(name));
+        is_named(&self.headers) || self.services.iter().any(|s| is_named(&s.headers)
+            || s.environments.iter().any(|e| is_named(&e.headers))
+            || s.endpoints.iter().any(|e| is_named(&e.headers)))
+    }
+
     pub fn remove_preset(&mut self, name: &str) -> bool {
         let init_length = self.presets.len();
         self.presets.retain(|preset| preset.name != name);
         init_length != self.presets.len()
     }
+
+    pub fn get_workflow(&self, name: &str) -> Option<&Workflow> {
+        self.workflows.iter().find(|w| w.name == name)
+    }
+
+    pub fn get_workflow_mut(&mut self, name: &str) -> Option<&mut Workflow> {
+        self.workflows.iter_mut().find(|w| w.name == name)
+    }
+
+    pub fn remove_workflow(&mut self, name: &str) -> bool {
+        let init_length = self.workflows.len();
+        self.workflows.retain(|w| w.name != name);
+        init_length != self.workflows.len()
+    }
+
+    /// Describes each test, workflow step & health check that calls the service's endpoint, by
+    /// either its name or alias
+    pub fn get_endpoint_references(&self, service: &Service, endpoint: &Endpoint) -> Vec<String> {
+        let is_endpoint = |name: &String| &endpoint.name == name || endpoint.alias.as_ref() == Some(name);
+        let mut references = vec![];
+        if service.health_endpoint.as_ref().is_some_and(is_endpoint) {
+            references.push(format!("the health endpoint of service `{}`", service.name));
+        }
+        for test in service.tests.iter().filter(|t| is_endpoint(&t.endpoint)) {
+            references.push(format!("test `{}`", test.name));
+        }
+        for workflow in &self.workflows {
+            for step in &workflow.steps {
+                if self.get_service(&step.service).is_some_and(|s| s.name == service.name) && is_endpoint(&step.endpoint) {
+                    references.push(format!("step `{}` of workflow `{}`", step.name, workflow.name));
+                }
+            }
+        }
+        references
+    }
+
+    /// Points each test, workflow step & health check that called one of the service's endpoints by
+    /// a name in `old_names` at `new_name` instead, for when the endpoint has been renamed
+    pub fn rename_endpoint_references(&mut self, service_name: &str, old_names: &[String], new_name: &str) {
+        let rename = |reference: &mut String| if old_names.contains(reference) {
+            *reference = new_name.to_string();
+        };
+        let Some(service) = self.get_service_mut(service_name) else {
+            return;
+        };
+        let service_names = [Some(service.name.clone()), service.alias.clone()];
+        if let Some(health_endpoint) = &mut service.health_endpoint {
+            rename(health_endpoint);
+        }
+        for test in &mut service.tests {
+            rename(&mut test.endpoint);
+        }
+        for step in self.workflows.iter_mut().flat_map(|w| &mut w.steps) {
+            if service_names.contains(&Some(step.service.clone())) {
+                rename(&mut step.endpoint);
+            }
+        }
+    }
 }
 
 impl Service {
@@ -76,7 +197,9 @@ impl Service {
             alias,
             environments: vec![],
             headers: HashMap::new(),
-            endpoints: vec![]
+            endpoints: vec![],
+            tests: vec![],
+            health_endpoint: None,
         }
     }
 
@@ -113,19 +236,29 @@ impl Service {
     }
 
     pub fn get_endpoint(&self, name: &str) -> Option<&Endpoint> {
-        self.endpoints.iter().find(|&endpoint| endpoint.name == name)
+        self.endpoints.iter().find(|&endpoint| endpoint.name == name || endpoint.alias.as_deref() == Some(name))
     }
     
     pub fn get_endpoint_mut(&mut self, name: &str) -> Option<&mut Endpoint> {
-        self.endpoints.iter_mut().find(|e| e.name == name)
+        self.endpoints.iter_mut().find(|e| e.name == name || e.alias.as_deref() == Some(name))
     }
 
     pub fn remove_endpoint(&mut self, name: &str) -> bool {
         let init_len = self.endpoints.len();
-        self.endpoints.retain(|x| x.name != name);
+        self.endpoints.retain(|x| x.name != name && x.alias.as_deref() != Some(name));
         init_len != self.endpoints.len()
     }
 
+    pub fn get_test(&self, name: &str) -> Option<&TestCase> {
+        self.tests.iter().find(|t| t.name == name)
+    }
+
+    pub fn remove_test(&mut self, name: &str) -> bool {
+        let init_len = self.tests.len();
+        self.tests.retain(|t| t.name != name);
+        init_len != self.tests.len()
+    }
+
     pub fn display_name(&self) -> String {
         match &self.alias {
             None => self.name.to_string(),
@@ -160,6 +293,82 @@ impl Environment {
     }
 }
 
+impl Endpoint {
+    /// Gets the names of all path & query parameters of the endpoint
+    pub fn get_parameter_names(&self) -> Vec<String> {
+        let mut names = get_params_from_path(&self.path_template);
+        names.extend(self.query_parameters.iter().map(|q| q.name.clone()));
+        names
+    }
+
+    pub fn display_name(&self) -> String {
+        match &self.alias {
+            None => self.name.to_string(),
+            Some(alias) => format!("{} ({})", self.name, alias),
+        }
+    }
+}
+
+impl Preset {
+    pub fn display_name(&self) -> String {
+        match &self.alias {
+            None => self.name.to_string(),
+            Some(alias) => format!("{} ({})", self.name, alias),
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum HeaderScope {
+    Global,
+    Service,
+    Environment,
+    Endpoint,
+}
+
+/// A header value along with the scope it was set at
+#[derive(Serialize)]
+pub struct ScopedHeader {
+    pub name: String,
+    pub value: String,
+    pub scope: HeaderScope,
+}
+
+impl fmt::Display for HeaderScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HeaderScope::Global => write!(f, "global"),
+            HeaderScope::Service => write!(f, "service"),
+            HeaderScope::Environment => write!(f, "environment"),
+            HeaderScope::Endpoint => write!(f, "endpoint"),
+        }
+    }
+}
+
+impl fmt::Display for Assertion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Assertion::Status { expected } => write!(f, "status is `{}`", expected),
+            Assertion::Header { name, value: None } => write!(f, "header `{}` is present", name),
+            Assertion::Header { name, value: Some(value) } => write!(f, "header `{}` is `{}`", name, value),
+            Assertion::JsonEquals { path, value } => write!(f, "json `{}` is `{}`", path, value),
+            Assertion::JsonExists { path } => write!(f, "json `{}` exists", path),
+            Assertion::JsonMatches { path, pattern } => write!(f, "json `{}` matches `{}`", path, pattern),
+            Assertion::MaxLatency { milliseconds } => write!(f, "responds within {}ms", milliseconds),
+        }
+    }
+}
+
+impl fmt::Display for Extraction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Extraction::Json { name, path } => write!(f, "{} = json `{}`", name, path),
+            Extraction::Header { name, header } => write!(f, "{} = header `{}`", name, header),
+        }
+    }
+}
+
 pub trait HeaderItem {
     fn set_header(&mut self, header_name: String, header_value: String);
     fn clear_header(&mut self, header_name: String);
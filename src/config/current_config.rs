@@ -5,7 +5,13 @@ use std::collections::HashMap;
 pub struct HtrsConfig {
     pub services: Vec<Service>,
     pub headers: HashMap<String, String>,
-    pub presets: Vec<Preset>
+    pub presets: Vec<Preset>,
+    #[serde(default)]
+    pub secret_headers: Vec<String>,
+    #[serde(default)]
+    pub workflows: Vec<Workflow>,
+    #[serde(default)]
+    pub show_timing: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -22,6 +28,10 @@ pub struct Service {
     pub environments: Vec<Environment>,
     pub headers: HashMap<String, String>,
     pub endpoints: Vec<Endpoint>,
+    #[serde(default)]
+    pub tests: Vec<TestCase>,
+    #[serde(default)]
+    pub health_endpoint: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -36,8 +46,55 @@ pub struct Environment {
 #[derive(Serialize, Deserialize, Clone)]
 pub struct Endpoint {
     pub name: String,
+    pub method: String,
     pub path_template: String,
     pub query_parameters: Vec<QueryParameter>,
+    pub headers: HashMap<String, String>,
+    pub body: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub alias: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub parameter_descriptions: HashMap<String, String>,
+    #[serde(default)]
+    pub mock: Option<MockResponse>,
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+}
+
+/// A canned response that `htrs mock` serves for an endpoint
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    pub body: Option<String>,
+    /// Path of a file to read the body from each time the response is served
+    pub file: Option<String>,
+}
+
+/// How `--all-pages` requests each page of a paginated endpoint's responses
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Pagination {
+    pub strategy: PaginationStrategy,
+    /// Path of the array of items in each page's body, which are concatenated across pages
+    pub items: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaginationStrategy {
+    /// Follows the `rel="next"` url of the `Link` header
+    Link,
+    /// Sends the value at `path` in each page's body as the `parameter` query parameter
+    Cursor { path: String, parameter: String },
+    /// Increases the `parameter` query parameter by one for each page, starting from 1
+    Page { parameter: String },
+    /// Increases the `parameter` query parameter by the number of items in each page, starting from 0
+    Offset { parameter: String },
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -45,3 +102,50 @@ pub struct QueryParameter {
     pub name: String,
     pub required: bool,
 }
+
+/// A named call to an endpoint along with what its response is expected to look like
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TestCase {
+    pub name: String,
+    pub endpoint: String,
+    pub preset: Option<String>,
+    pub parameters: HashMap<String, String>,
+    pub assertions: Vec<Assertion>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Assertion {
+    Status { expected: String },
+    Header { name: String, value: Option<String> },
+    JsonEquals { path: String, value: serde_json::Value },
+    JsonExists { path: String },
+    JsonMatches { path: String, pattern: String },
+    MaxLatency { milliseconds: u64 },
+}
+
+/// Calls to endpoints made in order, where later calls can use values taken from earlier responses
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Workflow {
+    pub name: String,
+    pub steps: Vec<WorkflowStep>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WorkflowStep {
+    pub name: String,
+    pub service: String,
+    pub endpoint: String,
+    pub preset: Option<String>,
+    pub parameters: HashMap<String, String>,
+    pub expected_status: Option<String>,
+    pub extractions: Vec<Extraction>,
+}
+
+/// A value taken from a step's response, which later steps can use in parameters as `{{name}}`
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "from", rename_all = "snake_case")]
+pub enum Extraction {
+    Json { name: String, path: String },
+    Header { name: String, header: String },
+}
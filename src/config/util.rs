@@ -24,3 +24,11 @@ pub fn get_config_path() -> Result<PathBuf, String> {
 
     Ok(directory.join("config.json"))
 }
+
+/// Get the path for a data file that's stored alongside the config, such as shell history
+///
+/// The file is named after the config file with the given extension, so that separate configs
+/// don't share data.
+pub fn get_data_file_path(extension: &str) -> Result<PathBuf, String> {
+    Ok(get_config_path()?.with_extension(extension))
+}
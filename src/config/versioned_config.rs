@@ -1,8 +1,10 @@
 use crate::config::current_config::HtrsConfig;
 use crate::config::util::get_config_path;
 use crate::config::versioned_config::migrations::migrate_v1_to_v2::migrate_v1_to_v2;
+use crate::config::versioned_config::migrations::migrate_v2_to_v3::migrate_v2_to_v3;
 use crate::config::versioned_config::versions::v1::v1config::HtrsConfigV1;
-use crate::config::versioned_config::VersionedHtrsConfig::{V1, V2};
+use crate::config::versioned_config::versions::v2::v2config::HtrsConfigV2;
+use crate::config::versioned_config::VersionedHtrsConfig::{V1, V2, V3};
 use serde::{Deserialize, Serialize};
 use std::fs::OpenOptions;
 use std::path::PathBuf;
@@ -14,7 +16,8 @@ mod migrations;
 #[serde(tag = "version")]
 pub enum VersionedHtrsConfig {
     V1(HtrsConfigV1),
-    V2(HtrsConfig),
+    V2(HtrsConfigV2),
+    V3(HtrsConfig),
 }
 
 impl VersionedHtrsConfig {
@@ -26,7 +29,7 @@ impl VersionedHtrsConfig {
 
     fn load_config(path: &PathBuf) -> Result<VersionedHtrsConfig, String> {
         if !path.exists() {
-            return Ok(V2(HtrsConfig::new()));
+            return Ok(V3(HtrsConfig::new()));
         }
 
         let file = match OpenOptions::new().read(true).open(path) {
@@ -42,7 +45,7 @@ impl VersionedHtrsConfig {
 
     pub fn save_config(config: HtrsConfig) -> Result<(), String> {
         let path = get_config_path()?;
-        let versioned_config = V2(config);
+        let versioned_config = V3(config);
 
         let mut file = match OpenOptions::new()
             .create(true)
@@ -62,10 +65,13 @@ impl VersionedHtrsConfig {
     fn migrate_config(self) -> HtrsConfig {
         match self {
             V1(v1_config) => {
-                migrate_v1_to_v2(v1_config)
+                migrate_v2_to_v3(migrate_v1_to_v2(v1_config))
             },
             V2(v2_config) => {
-                v2_config
+                migrate_v2_to_v3(v2_config)
+            },
+            V3(v3_config) => {
+                v3_config
             }
         }
     }
@@ -1,8 +1,8 @@
-use crate::config::current_config::{Endpoint, Environment, HtrsConfig, Preset, QueryParameter, Service};
 use crate::config::versioned_config::versions::v1::v1config::{EndpointV1, EnvironmentV1, HtrsConfigV1, PresetV1, QueryParameterV1, ServiceV1};
+use crate::config::versioned_config::versions::v2::v2config::{EndpointV2, EnvironmentV2, HtrsConfigV2, PresetV2, QueryParameterV2, ServiceV2};
 
-pub fn migrate_v1_to_v2(v1_config: HtrsConfigV1) -> HtrsConfig {
-    HtrsConfig {
+pub fn migrate_v1_to_v2(v1_config: HtrsConfigV1) -> HtrsConfigV2 {
+    HtrsConfigV2 {
         services: v1_config.services.into_iter()
             .map(migrate_v1_service)
             .collect(),
@@ -13,8 +13,8 @@ pub fn migrate_v1_to_v2(v1_config: HtrsConfigV1) -> HtrsConfig {
     }
 }
 
-fn migrate_v1_service(service: ServiceV1) -> Service {
-    Service {
+fn migrate_v1_service(service: ServiceV1) -> ServiceV2 {
+    ServiceV2 {
         name: service.name,
         alias: service.alias,
         environments: service.environments.into_iter()
@@ -27,8 +27,8 @@ fn migrate_v1_service(service: ServiceV1) -> Service {
     }
 }
 
-fn migrate_v1_environment(environment: EnvironmentV1) -> Environment {
-    Environment {
+fn migrate_v1_environment(environment: EnvironmentV1) -> EnvironmentV2 {
+    EnvironmentV2 {
         name: environment.name,
         alias: environment.alias,
         host: environment.host,
@@ -37,8 +37,8 @@ fn migrate_v1_environment(environment: EnvironmentV1) -> Environment {
     }
 }
 
-fn migrate_v1_endpoint(endpoint: EndpointV1) -> Endpoint {
-    Endpoint {
+fn migrate_v1_endpoint(endpoint: EndpointV1) -> EndpointV2 {
+    EndpointV2 {
         name: endpoint.name,
         path_template: endpoint.path_template,
         query_parameters: endpoint.query_parameters.into_iter()
@@ -47,15 +47,15 @@ fn migrate_v1_endpoint(endpoint: EndpointV1) -> Endpoint {
     }
 }
 
-fn migrate_v1_query_parameter(parameter: QueryParameterV1) -> QueryParameter {
-    QueryParameter {
+fn migrate_v1_query_parameter(parameter: QueryParameterV1) -> QueryParameterV2 {
+    QueryParameterV2 {
         name: parameter.name,
         required: parameter.required,
     }
 }
 
-fn migrate_v1_preset(preset: PresetV1) -> Preset {
-    Preset {
+fn migrate_v1_preset(preset: PresetV1) -> PresetV2 {
+    PresetV2 {
         name: preset.name,
         alias: None,
         values: preset.values,
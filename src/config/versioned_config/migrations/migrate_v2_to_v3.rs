@@ -0,0 +1,78 @@
+use crate::config::current_config::{Endpoint, Environment, HtrsConfig, Preset, QueryParameter, Service};
+use crate::config::versioned_config::versions::v2::v2config::{EndpointV2, EnvironmentV2, HtrsConfigV2, PresetV2, QueryParameterV2, ServiceV2};
+use std::collections::HashMap;
+
+pub fn migrate_v2_to_v3(v2_config: HtrsConfigV2) -> HtrsConfig {
+    HtrsConfig {
+        services: v2_config.services.into_iter()
+            .map(migrate_v2_service)
+            .collect(),
+        presets: v2_config.presets.into_iter()
+            .map(migrate_v2_preset)
+            .collect(),
+        headers: v2_config.headers,
+        secret_headers: vec![],
+        workflows: vec![],
+        show_timing: false,
+    }
+}
+
+fn migrate_v2_service(service: ServiceV2) -> Service {
+    Service {
+        name: service.name,
+        alias: service.alias,
+        environments: service.environments.into_iter()
+            .map(migrate_v2_environment)
+            .collect(),
+        endpoints: service.endpoints.into_iter()
+            .map(migrate_v2_endpoint)
+            .collect(),
+        headers: service.headers,
+        tests: vec![],
+        health_endpoint: None,
+    }
+}
+
+fn migrate_v2_environment(environment: EnvironmentV2) -> Environment {
+    Environment {
+        name: environment.name,
+        alias: environment.alias,
+        host: environment.host,
+        default: environment.default,
+        headers: environment.headers,
+    }
+}
+
+fn migrate_v2_endpoint(endpoint: EndpointV2) -> Endpoint {
+    Endpoint {
+        name: endpoint.name,
+        method: "GET".to_string(),
+        path_template: endpoint.path_template,
+        query_parameters: endpoint.query_parameters.into_iter()
+            .map(migrate_v2_query_parameter)
+            .collect(),
+        headers: HashMap::new(),
+        body: None,
+        description: None,
+        alias: None,
+        tags: vec![],
+        parameter_descriptions: HashMap::new(),
+        mock: None,
+        pagination: None,
+    }
+}
+
+fn migrate_v2_query_parameter(parameter: QueryParameterV2) -> QueryParameter {
+    QueryParameter {
+        name: parameter.name,
+        required: parameter.required,
+    }
+}
+
+fn migrate_v2_preset(preset: PresetV2) -> Preset {
+    Preset {
+        name: preset.name,
+        alias: preset.alias,
+        values: preset.values,
+    }
+}
@@ -1 +1,2 @@
-pub mod migrate_v1_to_v2;
\ No newline at end of file
+pub mod migrate_v1_to_v2;
+pub mod migrate_v2_to_v3;
\ No newline at end of file
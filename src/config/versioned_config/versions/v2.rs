@@ -0,0 +1,50 @@
+pub mod v2config {
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct HtrsConfigV2 {
+        pub services: Vec<ServiceV2>,
+        pub headers: HashMap<String, String>,
+        pub presets: Vec<PresetV2>
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct PresetV2 {
+        pub name: String,
+        pub alias: Option<String>,
+        pub values: HashMap<String, String>,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct ServiceV2 {
+        pub name: String,
+        pub alias: Option<String>,
+        pub environments: Vec<EnvironmentV2>,
+        pub headers: HashMap<String, String>,
+        pub endpoints: Vec<EndpointV2>,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct EnvironmentV2 {
+        pub name: String,
+        pub alias: Option<String>,
+        pub host: String,
+        pub default: bool,
+        pub headers: HashMap<String, String>,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct EndpointV2 {
+        pub name: String,
+        pub path_template: String,
+        pub query_parameters: Vec<QueryParameterV2>,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct QueryParameterV2 {
+        pub name: String,
+        pub required: bool,
+    }
+
+}
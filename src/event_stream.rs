@@ -0,0 +1,113 @@
+use std::mem;
+
+/// An event received from a `text/event-stream` response
+#[derive(PartialEq, Debug)]
+pub struct ServerSentEvent {
+    /// The most recent id given by the stream, which carries over to later events without one
+    pub id: Option<String>,
+    pub event: String,
+    pub data: String,
+}
+
+impl ServerSentEvent {
+    /// The event's id, type & data, where data spanning several lines is kept on separate lines
+    pub fn format(&self) -> String {
+        format!("{} | {} | {}", self.id.as_deref().unwrap_or("-"), self.event, self.data)
+    }
+}
+
+/// Parses server-sent events from a body received in chunks, which can split lines & events at
+/// any point
+#[derive(Default)]
+pub struct EventStreamParser {
+    line: String,
+    data: Vec<String>,
+    event: Option<String>,
+    last_id: Option<String>,
+    /// Set after a `\r` so that a following `\n` isn't read as a second line ending
+    after_carriage_return: bool,
+}
+
+impl EventStreamParser {
+    /// Adds the next chunk of the body, giving any events that it completes
+    pub fn push(&mut self, text: &str) -> Vec<ServerSentEvent> {
+        let mut events = vec![];
+        for c in text.chars() {
+            if mem::take(&mut self.after_carriage_return) && c == '\n' {
+                continue;
+            }
+            match c {
+                '\r' | '\n' => {
+                    self.after_carriage_return = c == '\r';
+                    let line = mem::take(&mut self.line);
+                    events.extend(self.read_line(&line));
+                },
+                '\u{feff}' if self.line.is_empty() => {},
+                _ => self.line.push(c),
+            }
+        }
+        events
+    }
+
+    fn read_line(&mut self, line: &str) -> Option<ServerSentEvent> {
+        // A blank line ends the event, which is only dispatched if it had any data
+        if line.is_empty() {
+            let event = self.event.take().filter(|event| !event.is_empty());
+            if self.data.is_empty() {
+                return None;
+            }
+            return Some(ServerSentEvent {
+                id: self.last_id.clone(),
+                event: event.unwrap_or("message".to_string()),
+                data: mem::take(&mut self.data).join("\n"),
+            });
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "data" => self.data.push(value.to_string()),
+            "event" => self.event = Some(value.to_string()),
+            "id" if !value.contains('\0') => self.last_id = Some(value.to_string()).filter(|id| !id.is_empty()),
+            _ => {},
+        }
+        None
+    }
+}
+
+/// Whether the response headers give a content type of `text/event-stream`
+pub fn is_event_stream(headers: &[(String, String)]) -> bool {
+    headers.iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+        .any(|(_, value)| value.split(';').next().unwrap_or_default().trim().eq_ignore_ascii_case("text/event-stream"))
+}
+
+#[cfg(test)]
+mod event_stream_tests {
+    use crate::event_stream::{EventStreamParser, ServerSentEvent};
+
+    fn event(id: Option<&str>, event: &str, data: &str) -> ServerSentEvent {
+        ServerSentEvent { id: id.map(|i| i.to_string()), event: event.to_string(), data: data.to_string() }
+    }
+
+    #[test]
+    fn given_events_split_across_chunks_when_push_then_should_give_completed_events() {
+        let mut parser = EventStreamParser::default();
+
+        assert_eq!(parser.push(": connected\n\nid: 1\nevent: upd"), vec![]);
+        assert_eq!(parser.push("ate\ndata: {\"a\":1}\n\ndata: first\r\n"), vec![event(Some("1"), "update", "{\"a\":1}")]);
+        assert_eq!(parser.push("data:second\r\n\r\n"), vec![event(Some("1"), "message", "first\nsecond")]);
+    }
+
+    #[test]
+    fn given_event_without_data_when_push_then_should_not_dispatch() {
+        let mut parser = EventStreamParser::default();
+
+        assert_eq!(parser.push("event: ping\n\nid\ndata\n\n"), vec![event(None, "message", "")]);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::outcomes::HtrsError;
+use crate::request::{redact_headers, Exchange};
+use reqwest::{Method, Url};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(PartialEq)]
+pub enum FixtureMode {
+    Record,
+    Replay,
+    /// Replays recorded responses, failing instead of sending requests that weren't recorded
+    StrictReplay,
+}
+
+/// A directory of recorded responses, keyed by the request that they were received for
+pub struct Fixtures {
+    pub directory: PathBuf,
+    pub mode: FixtureMode,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Fixture {
+    pub method: String,
+    pub url: String,
+    pub request_headers: Vec<(String, String)>,
+    pub request_body: Option<String>,
+    pub status: u16,
+    pub response_headers: Vec<(String, String)>,
+    pub response_body: String,
+}
+
+impl Fixtures {
+    /// Finds the recorded response for a request, if one was recorded
+    pub fn find(&self, method: &Method, url: &Url, headers: &HashMap<String, String>, body: Option<&str>, secret_headers: &[String]) -> Result<Option<Fixture>, HtrsError> {
+        let path = self.get_path(method, url, headers, body, secret_headers);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) => return Err(HtrsError::new(format!("Failed to read recording `{}`: {}", path.display(), e).as_str())),
+        };
+        match serde_json::from_str(&contents) {
+            Ok(fixture) => Ok(Some(fixture)),
+            Err(e) => Err(HtrsError::new(format!("Failed to parse recording `{}`: {}", path.display(), e).as_str())),
+        }
+    }
+
+    /// Records the response to a request, replacing any previous recording of the same request.
+    /// `headers` are the headers resolved from config, which secret headers are excluded from
+    pub fn record(&self, exchange: &Exchange, headers: &HashMap<String, String>, secret_headers: &[String]) -> Result<(), HtrsError> {
+        let path = self.get_path(&exchange.method, &exchange.url, headers, exchange.request_body.as_deref(), secret_headers);
+        let mut request_headers: Vec<(String, String)> = headers.iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        request_headers.sort();
+        let fixture = Fixture {
+            method: exchange.method.to_string(),
+            url: exchange.url.to_string(),
+            request_headers: redact_headers(&request_headers, secret_headers),
+            request_body: exchange.request_body.clone(),
+            status: exchange.status.as_u16(),
+            response_headers: redact_headers(&exchange.response_headers, secret_headers),
+            response_body: exchange.response_body.clone(),
+        };
+
+        if let Err(e) = fs::create_dir_all(&self.directory) {
+            return Err(HtrsError::new(format!("Failed to create directory `{}`: {}", self.directory.display(), e).as_str()));
+        }
+        let contents = match serde_json::to_string_pretty(&fixture) {
+            Ok(contents) => contents,
+            Err(e) => return Err(HtrsError::new(format!("Failed to serialize recording: {}", e).as_str())),
+        };
+        match fs::write(&path, contents) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(HtrsError::new(format!("Failed to write recording `{}`: {}", path.display(), e).as_str())),
+        }
+    }
+
+    fn get_path(&self, method: &Method, url: &Url, headers: &HashMap<String, String>, body: Option<&str>, secret_headers: &[String]) -> PathBuf {
+        let key = get_key(method, url, headers, body, secret_headers);
+        self.directory.join(format!("{}-{:016x}.json", method.as_str().to_lowercase(), key))
+    }
+}
+
+/// Hashes the parts of a request that decide its response. Query parameters are sorted as their
+/// order isn't stable, and secret headers are left out so that recordings survive rotated tokens
+fn get_key(method: &Method, url: &Url, headers: &HashMap<String, String>, body: Option<&str>, secret_headers: &[String]) -> u64 {
+    let mut query: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    query.sort();
+    let mut headers: Vec<(String, &String)> = headers.iter()
+        .filter(|(name, _)| !secret_headers.iter().any(|s| s.eq_ignore_ascii_case(name)))
+        .map(|(name, value)| (name.to_lowercase(), value))
+        .collect();
+    headers.sort();
+
+    let mut path = url.clone();
+    path.set_query(None);
+    path.set_fragment(None);
+
+    let mut key = format!("{}\n{}\n", method, path);
+    for (name, value) in query {
+        key.push_str(format!("?{}={}\n", name, value).as_str());
+    }
+    for (name, value) in headers {
+        key.push_str(format!("{}: {}\n", name, value).as_str());
+    }
+    key.push_str(body.unwrap_or_default());
+    fnv1a(key.as_bytes())
+}
+
+/// A hash that's stable between builds, unlike the standard library's hasher
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+}
+
+#[cfg(test)]
+mod fixtures_tests {
+    use crate::fixtures::get_key;
+    use reqwest::{Method, Url};
+    use std::collections::HashMap;
+
+    #[test]
+    fn given_reordered_query_parameters_when_get_key_then_should_match() {
+        let headers = HashMap::new();
+        let first = Url::parse("http://foo.com/users?b=2&a=1").unwrap();
+        let second = Url::parse("http://foo.com/users?a=1&b=2").unwrap();
+
+        assert_eq!(get_key(&Method::GET, &first, &headers, None, &[]), get_key(&Method::GET, &second, &headers, None, &[]));
+    }
+
+    #[test]
+    fn given_different_secret_header_when_get_key_then_should_match() {
+        let url = Url::parse("http://foo.com/users").unwrap();
+        let first = HashMap::from([("Authorization".to_string(), "Bearer one".to_string()), ("X-Version".to_string(), "1".to_string())]);
+        let second = HashMap::from([("Authorization".to_string(), "Bearer two".to_string()), ("X-Version".to_string(), "1".to_string())]);
+        let secret_headers = vec!["authorization".to_string()];
+
+        assert_eq!(get_key(&Method::GET, &url, &first, None, &secret_headers), get_key(&Method::GET, &url, &second, None, &secret_headers));
+    }
+
+    #[test]
+    fn given_different_requests_when_get_key_then_should_differ() {
+        let url = Url::parse("http://foo.com/users").unwrap();
+        let headers = HashMap::new();
+        let versioned = HashMap::from([("X-Version".to_string(), "2".to_string())]);
+
+        let key = get_key(&Method::GET, &url, &headers, None, &[]);
+        assert_ne!(key, get_key(&Method::POST, &url, &headers, None, &[]));
+        assert_ne!(key, get_key(&Method::GET, &Url::parse("http://foo.com/users?a=1").unwrap(), &headers, None, &[]));
+        assert_ne!(key, get_key(&Method::GET, &url, &versioned, None, &[]));
+        assert_ne!(key, get_key(&Method::GET, &url, &headers, Some("{}"), &[]));
+    }
+}
@@ -0,0 +1,126 @@
+pub mod curl;
+pub mod har;
+pub mod http_file;
+pub mod postman;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref DOUBLE_BRACE_PLACEHOLDER: Regex = Regex::new(r"\{\{\s*([A-Za-z0-9_-]+)\s*}}").unwrap();
+    static ref SINGLE_BRACE_PLACEHOLDER: Regex = Regex::new(r"\{([A-Za-z0-9_-]+)}").unwrap();
+}
+
+/// Converts `{{name}}` style placeholders used by other tools into htrs `{name}` placeholders
+pub fn from_double_brace_placeholders(s: &str) -> String {
+    DOUBLE_BRACE_PLACEHOLDER.replace_all(s, "{${1}}").to_string()
+}
+
+/// Converts htrs `{name}` placeholders into the `{{name}}` style used by other tools
+pub fn to_double_brace_placeholders(s: &str) -> String {
+    SINGLE_BRACE_PLACEHOLDER.replace_all(s, "{{${1}}}").to_string()
+}
+
+/// If the entire string is a single `{{name}}` placeholder then returns the placeholder's name
+pub fn get_double_brace_placeholder(s: &str) -> Option<String> {
+    let captures = DOUBLE_BRACE_PLACEHOLDER.captures(s.trim())?;
+    match captures.get(0)?.as_str() == s.trim() {
+        true => Some(captures[1].to_string()),
+        false => None,
+    }
+}
+
+/// Converts a free-text name into one that's usable as a subcommand name, e.g. `Get Order` becomes
+/// `get-order`
+pub fn to_command_name(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-")
+}
+
+/// A url split into its parts without any decoding, so that any placeholders are left intact
+pub struct RawUrl {
+    pub host: String,
+    pub path: String,
+    pub query: Vec<(String, Option<String>)>,
+}
+
+/// Splits a url that may contain placeholders, e.g. `{{baseUrl}}/orders/{{id}}?limit=10`, into its
+/// host, path & query parameters
+pub fn split_raw_url(raw: &str) -> RawUrl {
+    let (without_query, query) = raw.split_once('?').unwrap_or((raw, ""));
+    let without_scheme = match without_query.split_once("://") {
+        Some((_, rest)) => rest,
+        None => without_query,
+    };
+    let (host, path) = match without_scheme.find('/') {
+        Some(index) => without_scheme.split_at(index),
+        None => (without_scheme, ""),
+    };
+
+    RawUrl {
+        host: host.to_string(),
+        path: path.to_string(),
+        query: query.split('&')
+            .filter(|q| !q.is_empty())
+            .map(|q| match q.split_once('=') {
+                Some((key, value)) => (key.to_string(), Some(value.to_string())),
+                None => (q.to_string(), None),
+            })
+            .collect(),
+    }
+}
+
+/// Removes any scheme and trailing slashes from a url so that it can be used as an environment host
+pub fn strip_scheme(url: &str) -> String {
+    let without_scheme = match url.split_once("://") {
+        Some((_, rest)) => rest,
+        None => url,
+    };
+    without_scheme.trim_end_matches('/').to_string()
+}
+
+#[cfg(test)]
+mod formats_tests {
+    use crate::formats::{from_double_brace_placeholders, get_double_brace_placeholder, to_command_name, to_double_brace_placeholders};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("/orders/{{id}}", "/orders/{id}")]
+    #[case("/orders/{{ id }}/items", "/orders/{id}/items")]
+    #[case("/orders", "/orders")]
+    fn given_double_brace_placeholders_then_should_convert(
+        #[case] s: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(from_double_brace_placeholders(s), expected);
+    }
+
+    #[test]
+    fn given_single_brace_placeholders_then_should_convert() {
+        assert_eq!(to_double_brace_placeholders("/orders/{id}/items/{item_id}"), "/orders/{{id}}/items/{{item_id}}");
+    }
+
+    #[rstest]
+    #[case("{{baseUrl}}", Some("baseUrl"))]
+    #[case("{{baseUrl}}/path", None)]
+    #[case("api.com", None)]
+    fn given_string_when_get_placeholder_then_should_only_match_whole_string(
+        #[case] s: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(get_double_brace_placeholder(s).as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case("Get Order", "get-order")]
+    #[case("orders / list_all", "orders-list_all")]
+    fn given_name_when_to_command_name_then_should_normalise(
+        #[case] name: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(to_command_name(name), expected);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::common::{encode_base64, split_words};
+use crate::outcomes::HtrsError;
+use reqwest::Url;
+use std::str::FromStr;
+
+/// Short options that take a value, used to tell `-XPOST` apart from combined flags like `-sSL`
+const SHORT_OPTIONS_WITH_VALUES: &str = "XHdubAemorwx";
+
+/// Options that take a value but have no bearing on the request itself
+const IGNORED_OPTIONS_WITH_VALUES: [&str; 14] = [
+    "-o", "--output", "-m", "--max-time", "--connect-timeout", "--retry", "-w", "--write-out",
+    "-x", "--proxy", "--cacert", "--cert", "--key", "-r",
+];
+
+/// A request described by a curl command
+pub struct CurlRequest {
+    pub method: String,
+    pub url: Url,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+/// Parses a curl command line into the request it describes, options that don't affect the request
+/// (such as `--silent` or `--output`) are ignored
+pub fn parse_curl(command: &str) -> Result<CurlRequest, HtrsError> {
+    let mut tokens = split_words(command)?.into_iter();
+    let mut method = None;
+    let mut url = None;
+    let mut headers = vec![];
+    let mut data: Vec<String> = vec![];
+    let mut get = false;
+
+    while let Some(token) = tokens.next() {
+        if token == "curl" && url.is_none() && method.is_none() {
+            continue;
+        }
+
+        let (option, inline_value) = split_option(&token);
+        let mut value = || match inline_value.clone() {
+            Some(value) => Ok(value),
+            None => tokens.next().ok_or_else(|| HtrsError::new(format!("Missing value for curl option `{}`", option).as_str())),
+        };
+
+        match option.as_str() {
+            "-X" | "--request" => method = Some(value()?.to_uppercase()),
+            "-I" | "--head" => method = Some("HEAD".to_string()),
+            "-G" | "--get" => get = true,
+            "-H" | "--header" => {
+                let header = value()?;
+                let Some((name, header_value)) = header.split_once(':') else {
+                    return Err(HtrsError::new(format!("Invalid curl header `{}`", header).as_str()));
+                };
+                headers.push((name.trim().to_string(), header_value.trim().to_string()));
+            },
+            "-d" | "--data" | "--data-raw" | "--data-binary" | "--data-ascii" | "--data-urlencode" => data.push(value()?),
+            "--json" => {
+                data.push(value()?);
+                headers.push(("Content-Type".to_string(), "application/json".to_string()));
+                headers.push(("Accept".to_string(), "application/json".to_string()));
+            },
+            "-u" | "--user" => headers.push(("Authorization".to_string(), format!("Basic {}", encode_base64(value()?.as_bytes())))),
+            "-A" | "--user-agent" => headers.push(("User-Agent".to_string(), value()?)),
+            "-b" | "--cookie" => headers.push(("Cookie".to_string(), value()?)),
+            "-e" | "--referer" => headers.push(("Referer".to_string(), value()?)),
+            "--url" => url = Some(value()?),
+            option if IGNORED_OPTIONS_WITH_VALUES.contains(&option) => {
+                value()?;
+            },
+            option if option.starts_with('-') => {},
+            _ => url = Some(token),
+        }
+    }
+
+    let Some(url) = url else {
+        return Err(HtrsError::new("No url found in curl command"));
+    };
+    let url = match url.contains("://") {
+        true => url,
+        false => format!("http://{}", url),
+    };
+    let mut url = match Url::from_str(&url) {
+        Ok(url) => url,
+        Err(e) => return Err(HtrsError::new(format!("Invalid url `{}` in curl command: {}", url, e).as_str())),
+    };
+
+    let mut body = match data.is_empty() {
+        true => None,
+        false => Some(data.join("&")),
+    };
+    if get && let Some(query) = body.take() {
+        let query = match url.query() {
+            Some(existing) => format!("{}&{}", existing, query),
+            None => query,
+        };
+        url.set_query(Some(&query));
+    }
+
+    let method = match method {
+        Some(method) => method,
+        None if body.is_some() => "POST".to_string(),
+        None => "GET".to_string(),
+    };
+
+    Ok(CurlRequest {
+        method,
+        url,
+        headers,
+        body,
+    })
+}
+
+/// Builds a curl command that will send the given request, headers are sorted by name so the output
+/// is stable between runs
+pub fn to_curl(method: &str, url: &Url, headers: &[(String, String)], body: Option<&str>) -> String {
+    let mut headers = headers.to_vec();
+    headers.sort();
+
+    let mut parts = vec!["curl".to_string()];
+    if method != "GET" || body.is_some() {
+        parts.push(format!("-X {}", method));
+    }
+    parts.push(quote(url.as_str()));
+    for (name, value) in headers {
+        parts.push(format!("-H {}", quote(&format!("{}: {}", name, value))));
+    }
+    if let Some(body) = body {
+        parts.push(format!("--data-raw {}", quote(body)));
+    }
+
+    parts.join(" \\\n  ")
+}
+
+/// Wraps a value in single quotes so that it's passed to curl verbatim by a posix shell
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Splits an option into its name & any value given within the same token, e.g. `-XPOST` or
+/// `--request=POST`
+fn split_option(token: &str) -> (String, Option<String>) {
+    if let Some(long_option) = token.strip_prefix("--") {
+        return match long_option.split_once('=') {
+            Some((name, value)) => (format!("--{}", name), Some(value.to_string())),
+            None => (token.to_string(), None),
+        };
+    }
+
+    let mut chars = token.chars();
+    if chars.next() == Some('-') && let Some(short_option) = chars.next()
+        && SHORT_OPTIONS_WITH_VALUES.contains(short_option) && token.len() > 2 {
+        return (format!("-{}", short_option), Some(chars.collect()));
+    }
+
+    (token.to_string(), None)
+}
+
+#[cfg(test)]
+mod curl_tests {
+    use crate::formats::curl::{parse_curl, to_curl};
+    use reqwest::Url;
+    use std::str::FromStr;
+
+    #[test]
+    fn given_curl_with_options_then_should_parse_request() {
+        let request = parse_curl("curl -sS -XPOST 'https://api.com/orders?limit=10' \\\n  -H 'Content-Type: application/json' \\\n  --data-raw '{\"id\": 1}'").unwrap();
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url.as_str(), "https://api.com/orders?limit=10");
+        assert_eq!(request.headers, vec![("Content-Type".to_string(), "application/json".to_string())]);
+        assert_eq!(request.body, Some("{\"id\": 1}".to_string()));
+    }
+
+    #[test]
+    fn given_curl_with_data_and_no_method_then_should_default_to_post() {
+        let request = parse_curl("curl api.com/orders -d \"a=1\" --data b=2").unwrap();
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url.as_str(), "http://api.com/orders");
+        assert_eq!(request.body, Some("a=1&b=2".to_string()));
+    }
+
+    #[test]
+    fn given_curl_with_get_flag_then_should_move_data_to_query() {
+        let request = parse_curl("curl -G https://api.com/orders?a=1 -d b=2").unwrap();
+
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.url.as_str(), "https://api.com/orders?a=1&b=2");
+        assert_eq!(request.body, None);
+    }
+
+    #[test]
+    fn given_curl_with_no_url_then_should_fail() {
+        assert!(parse_curl("curl -X GET").is_err());
+    }
+
+    #[test]
+    fn given_request_when_to_curl_then_should_round_trip() {
+        let url = Url::from_str("http://api.com/it's").unwrap();
+        let headers = vec![("X-Name".to_string(), "it's me".to_string())];
+
+        let command = to_curl("PUT", &url, &headers, Some("{}"));
+        let request = parse_curl(&command).unwrap();
+
+        assert_eq!(request.method, "PUT");
+        assert_eq!(request.url, url);
+        assert_eq!(request.headers, headers);
+        assert_eq!(request.body, Some("{}".to_string()));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::HtrsError;
+use crate::request::{redact_headers, Exchange};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+
+/// Where to record a HAR file for the requests made by a command
+pub struct HarCapture {
+    pub path: PathBuf,
+    /// Headers whose values should be redacted before being written
+    pub redacted_headers: Vec<String>,
+}
+
+impl HarCapture {
+    /// A capture to the file at `path`, which redacts the config's secret headers when
+    /// `redact_secrets` is set
+    pub fn new(path: &str, redact_secrets: bool, config: &HtrsConfig) -> HarCapture {
+        HarCapture {
+            path: PathBuf::from(path),
+            redacted_headers: match redact_secrets {
+                true => config.secret_headers.clone(),
+                false => vec![],
+            },
+        }
+    }
+
+    pub fn write(&self, exchanges: &[Exchange]) -> Result<(), HtrsError> {
+        let har = to_har(exchanges, &self.redacted_headers);
+        let contents = match serde_json::to_string_pretty(&har) {
+            Ok(contents) => contents,
+            Err(e) => return Err(HtrsError::new(format!("Failed to serialize HAR file: {}", e).as_str())),
+        };
+
+        match fs::write(&self.path, contents) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(HtrsError::new(format!("Failed to write HAR file `{}`: {}", self.path.display(), e).as_str())),
+        }
+    }
+}
+
+/// Builds a HAR 1.2 log containing an entry for each of the given exchanges
+pub fn to_har(exchanges: &[Exchange], redacted_headers: &[String]) -> Value {
+    json!({
+        "log": {
+            "version": "1.2",
+            "creator": {
+                "name": "htrs",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "entries": exchanges.iter()
+                .map(|e| to_har_entry(e, redacted_headers))
+                .collect::<Vec<Value>>(),
+        }
+    })
+}
+
+fn to_har_entry(exchange: &Exchange, redacted_headers: &[String]) -> Value {
+    let request_headers = redact_headers(&exchange.request_headers, redacted_headers);
+    let response_headers = redact_headers(&exchange.response_headers, redacted_headers);
+
+    let mut request = json!({
+        "method": exchange.method.as_str(),
+        "url": exchange.url.as_str(),
+        "httpVersion": exchange.http_version,
+        "cookies": [],
+        "headers": to_name_value_pairs(&request_headers),
+        "queryString": exchange.url.query_pairs()
+            .map(|(name, value)| json!({ "name": name, "value": value }))
+            .collect::<Vec<Value>>(),
+        "headersSize": -1,
+        "bodySize": exchange.request_body.as_ref().map(|b| b.len() as i64).unwrap_or(0),
+    });
+    if let Some(body) = &exchange.request_body {
+        request["postData"] = json!({
+            "mimeType": get_header(&request_headers, "content-type").unwrap_or_default(),
+            "text": body,
+        });
+    }
+
+    json!({
+        "startedDateTime": humantime::format_rfc3339_millis(exchange.started).to_string(),
+        "time": exchange.total_time().as_secs_f64() * 1000.0,
+        "request": request,
+        "response": {
+            "status": exchange.status.as_u16(),
+            "statusText": exchange.status.canonical_reason().unwrap_or_default(),
+            "httpVersion": exchange.http_version,
+            "cookies": [],
+            "headers": to_name_value_pairs(&response_headers),
+            "content": {
+                "size": exchange.response_body.len(),
+                "mimeType": get_header(&response_headers, "content-type").unwrap_or_default(),
+                "text": exchange.response_body,
+            },
+            "redirectURL": get_header(&response_headers, "location").unwrap_or_default(),
+            "headersSize": -1,
+            "bodySize": exchange.response_size,
+        },
+        "cache": {},
+        "timings": {
+            "blocked": -1,
+            "dns": -1,
+            "connect": exchange.connect.map(|c| c.as_secs_f64() * 1000.0).unwrap_or(-1.0),
+            "ssl": -1,
+            "send": 0,
+            // Time to first byte includes connecting, which har records separately
+            "wait": exchange.wait.saturating_sub(exchange.connect.unwrap_or_default()).as_secs_f64() * 1000.0,
+            "receive": exchange.receive.as_secs_f64() * 1000.0,
+        },
+    })
+}
+
+fn to_name_value_pairs(headers: &[(String, String)]) -> Vec<Value> {
+    headers.iter()
+        .map(|(name, value)| json!({ "name": name, "value": value }))
+        .collect()
+}
+
+fn get_header(headers: &[(String, String)], name: &str) -> Option<String> {
+    headers.iter()
+        .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.clone())
+}
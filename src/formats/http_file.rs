@@ -0,0 +1,288 @@
+use crate::common::merge_hashmaps;
+use crate::config::current_config::{Endpoint, Environment, QueryParameter, Service};
+use crate::formats::{from_double_brace_placeholders, get_double_brace_placeholder, split_raw_url, to_command_name, to_double_brace_placeholders};
+use crate::outcomes::HtrsError;
+use std::collections::HashMap;
+
+const HOST_VARIABLE: &str = "host";
+
+/// A request file in the format used by editor tooling such as REST Client
+pub struct HttpFile {
+    pub variables: Vec<(String, String)>,
+    pub requests: Vec<HttpFileRequest>,
+}
+
+pub struct HttpFileRequest {
+    pub name: Option<String>,
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+    /// Names of query parameters on commented out `&name=value` lines, which aren't sent until
+    /// they're uncommented so are imported as optional
+    pub optional_query: Vec<String>,
+}
+
+enum ParseState {
+    RequestLine,
+    Headers,
+    Body,
+}
+
+/// Parses the contents of a `.http` file, requests are separated by lines starting with `###`
+pub fn parse_http_file(contents: &str) -> Result<HttpFile, HtrsError> {
+    let mut variables = vec![];
+    let mut requests = vec![];
+
+    let mut blocks: Vec<(Option<String>, Vec<&str>)> = vec![(None, vec![])];
+    for line in contents.lines() {
+        match line.strip_prefix("###") {
+            Some(title) => blocks.push((Some(title.trim().to_string()).filter(|t| !t.is_empty()), vec![])),
+            None => blocks.last_mut().unwrap().1.push(line),
+        }
+    }
+
+    for (title, lines) in blocks {
+        let mut state = ParseState::RequestLine;
+        let mut name = title.map(|t| to_command_name(&t));
+        let mut request: Option<HttpFileRequest> = None;
+        let mut body_lines = vec![];
+
+        for line in lines {
+            let trimmed = line.trim();
+            match state {
+                ParseState::RequestLine => {
+                    if trimmed.is_empty() {
+                        continue;
+                    }
+                    if let Some(variable) = trimmed.strip_prefix('@') && let Some((key, value)) = variable.split_once('=') {
+                        let value = substitute_variables(value.trim(), &variables);
+                        variables.push((key.trim().to_string(), value));
+                        continue;
+                    }
+                    if let Some(comment) = trimmed.strip_prefix('#').or_else(|| trimmed.strip_prefix("//")) {
+                        if let Some(request_name) = comment.trim().strip_prefix("@name") {
+                            name = Some(request_name.trim().to_string());
+                        }
+                        continue;
+                    }
+
+                    let parts: Vec<&str> = trimmed.split_whitespace().collect();
+                    let (method, url) = match parts.as_slice() {
+                        [method, url, ..] if method.chars().all(|c| c.is_ascii_uppercase()) => (method.to_string(), url.to_string()),
+                        [url, ..] => ("GET".to_string(), url.to_string()),
+                        [] => unreachable!(),
+                    };
+                    request = Some(HttpFileRequest { name: None, method, url, headers: vec![], body: None, optional_query: vec![] });
+                    state = ParseState::Headers;
+                },
+                ParseState::Headers => {
+                    let request = request.as_mut().unwrap();
+                    if trimmed.is_empty() {
+                        state = ParseState::Body;
+                    } else if trimmed.starts_with('?') || trimmed.starts_with('&') {
+                        request.url.push_str(trimmed);
+                    } else if let Some(comment) = trimmed.strip_prefix('#').or_else(|| trimmed.strip_prefix("//")) {
+                        let comment = comment.trim();
+                        if comment.starts_with('?') || comment.starts_with('&') {
+                            request.optional_query.extend(comment.split(['?', '&'])
+                                .filter_map(|pair| pair.split('=').next())
+                                .filter(|name| !name.is_empty())
+                                .map(|name| name.to_string()));
+                        }
+                    } else if let Some((header_name, header_value)) = trimmed.split_once(':') {
+                        request.headers.push((header_name.trim().to_string(), header_value.trim().to_string()));
+                    } else {
+                        return Err(HtrsError::new(format!("Invalid header `{}` in http file", trimmed).as_str()));
+                    }
+                },
+                ParseState::Body => body_lines.push(line),
+            }
+        }
+
+        if let Some(mut request) = request {
+            let body = body_lines.join("\n");
+            request.name = name;
+            request.body = Some(body.trim().to_string()).filter(|b| !b.is_empty());
+            requests.push(request);
+        }
+    }
+
+    Ok(HttpFile {
+        variables,
+        requests,
+    })
+}
+
+/// Replaces any `{{name}}` placeholders that have a matching file variable with its value
+fn substitute_variables(s: &str, variables: &[(String, String)]) -> String {
+    let mut s = s.to_string();
+    for (name, value) in variables {
+        s = s.replace(&format!("{{{{{}}}}}", name), value);
+    }
+    s
+}
+
+/// Converts the requests of a http file into endpoints. File variables are substituted into the
+/// requests, with any remaining `{{name}}` placeholders becoming parameters.
+///
+/// Returns the endpoints along with the host used by the requests, if it could be resolved.
+pub fn http_file_to_endpoints(file: &HttpFile) -> (Vec<Endpoint>, Option<String>) {
+    let mut endpoints: Vec<Endpoint> = vec![];
+    let mut host = None;
+
+    for (index, request) in file.requests.iter().enumerate() {
+        let url = split_raw_url(&substitute_variables(&request.url, &file.variables));
+        if host.is_none() && !url.host.is_empty() && get_double_brace_placeholder(&url.host).is_none() {
+            host = Some(url.host.clone());
+        }
+
+        let name = request.name.clone().unwrap_or_else(|| format!("request-{}", index + 1));
+        let mut endpoint_name = name.clone();
+        let mut suffix = 2;
+        while endpoints.iter().any(|e| e.name == endpoint_name) {
+            endpoint_name = format!("{}-{}", name, suffix);
+            suffix += 1;
+        }
+
+        endpoints.push(Endpoint {
+            name: endpoint_name,
+            method: request.method.clone(),
+            path_template: match url.path.is_empty() {
+                true => "/".to_string(),
+                false => from_double_brace_placeholders(&url.path),
+            },
+            query_parameters: url.query.iter()
+                .map(|(key, value)| QueryParameter {
+                    name: key.clone(),
+                    required: value.as_ref().and_then(|v| get_double_brace_placeholder(v)).is_some(),
+                })
+                .chain(request.optional_query.iter()
+                    .filter(|name| !url.query.iter().any(|(key, _)| key == *name))
+                    .map(|name| QueryParameter { name: name.clone(), required: false }))
+                .collect(),
+            headers: request.headers.iter()
+                .map(|(name, value)| (name.clone(), substitute_variables(value, &file.variables)))
+                .collect(),
+            body: request.body.as_ref().map(|b| substitute_variables(b, &file.variables)),
+            description: None,
+            alias: None,
+            tags: vec![],
+            parameter_descriptions: HashMap::new(),
+            mock: None,
+            pagination: None,
+        });
+    }
+
+    (endpoints, host)
+}
+
+/// Builds a http file for calling a service's endpoints in the given environment, parameters are
+/// left as `{{name}}` placeholders to be filled in by the editor. Optional query parameters are
+/// given on commented out lines, to be uncommented when they're needed
+pub fn service_to_http_file(service: &Service, environment: &Environment, global_headers: &HashMap<String, String>) -> String {
+    let headers = merge_hashmaps(
+        merge_hashmaps(global_headers.clone(), service.headers.clone()),
+        environment.headers.clone(),
+    );
+
+    let mut text = format!("@{} = http://{}\n", HOST_VARIABLE, environment.host);
+    for endpoint in &service.endpoints {
+        let mut url = format!("{{{{{}}}}}{}", HOST_VARIABLE, to_double_brace_placeholders(&endpoint.path_template));
+        let query = endpoint.query_parameters.iter()
+            .filter(|q| q.required)
+            .map(|q| format!("{}={{{{{}}}}}", q.name, q.name))
+            .collect::<Vec<String>>();
+        if !query.is_empty() {
+            url = format!("{}?{}", url, query.join("&"));
+        }
+
+        text.push_str(format!("\n### {}\n# @name {}\n{} {}\n", endpoint.name, endpoint.name, endpoint.method, url).as_str());
+        for (index, parameter) in endpoint.query_parameters.iter().filter(|q| !q.required).enumerate() {
+            let separator = match query.is_empty() && index == 0 {
+                true => '?',
+                false => '&',
+            };
+            text.push_str(format!("# {}{}={{{{{}}}}}\n", separator, parameter.name, parameter.name).as_str());
+        }
+
+        let mut endpoint_headers = merge_hashmaps(headers.clone(), endpoint.headers.clone())
+            .into_iter()
+            .collect::<Vec<(String, String)>>();
+        endpoint_headers.sort();
+        for (name, value) in endpoint_headers {
+            text.push_str(format!("{}: {}\n", name, value).as_str());
+        }
+
+        if let Some(body) = &endpoint.body {
+            text.push_str(format!("\n{}\n", body).as_str());
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod http_file_tests {
+    use crate::formats::http_file::{http_file_to_endpoints, parse_http_file};
+
+    const HTTP_FILE: &str = "@baseUrl = https://api.foo.com/v1
+@token = abc
+
+### Get Order
+GET {{baseUrl}}/orders/{{id}}
+    ?expand={{expand}}
+    &debug=true
+    # &page={{page}}&debug={{debug}}
+Authorization: Bearer {{token}}
+
+###
+# @name createOrder
+POST {{baseUrl}}/orders HTTP/1.1
+Content-Type: application/json
+
+{
+    \"item\": 1
+}
+";
+
+    #[test]
+    fn given_http_file_then_should_parse_requests() {
+        let file = parse_http_file(HTTP_FILE).unwrap();
+
+        assert_eq!(file.variables.len(), 2);
+        assert_eq!(file.requests.len(), 2);
+
+        let get_order = &file.requests[0];
+        assert_eq!(get_order.name, Some("get-order".to_string()));
+        assert_eq!(get_order.method, "GET");
+        assert_eq!(get_order.url, "{{baseUrl}}/orders/{{id}}?expand={{expand}}&debug=true");
+        assert_eq!(get_order.headers, vec![("Authorization".to_string(), "Bearer {{token}}".to_string())]);
+        assert_eq!(get_order.optional_query, vec!["page".to_string(), "debug".to_string()]);
+        assert_eq!(get_order.body, None);
+
+        let create_order = &file.requests[1];
+        assert_eq!(create_order.name, Some("createOrder".to_string()));
+        assert_eq!(create_order.method, "POST");
+        assert_eq!(create_order.body, Some("{\n    \"item\": 1\n}".to_string()));
+    }
+
+    #[test]
+    fn given_http_file_then_should_convert_to_endpoints() {
+        let file = parse_http_file(HTTP_FILE).unwrap();
+
+        let (endpoints, host) = http_file_to_endpoints(&file);
+
+        assert_eq!(host, Some("api.foo.com".to_string()));
+        assert_eq!(endpoints[0].path_template, "/v1/orders/{id}");
+        assert_eq!(endpoints[0].query_parameters[0].name, "expand");
+        assert!(endpoints[0].query_parameters[0].required);
+        assert_eq!(endpoints[0].query_parameters[1].name, "debug");
+        assert!(!endpoints[0].query_parameters[1].required);
+        assert_eq!(endpoints[0].query_parameters.len(), 3);
+        assert_eq!(endpoints[0].query_parameters[2].name, "page");
+        assert!(!endpoints[0].query_parameters[2].required);
+        assert_eq!(endpoints[0].headers.get("Authorization"), Some(&"Bearer abc".to_string()));
+        assert_eq!(endpoints[1].path_template, "/v1/orders");
+    }
+}
@@ -0,0 +1,408 @@
+use crate::common::merge_hashmaps;
+use crate::config::current_config::{Endpoint, Environment, QueryParameter, Service};
+use crate::formats::{from_double_brace_placeholders, get_double_brace_placeholder, split_raw_url, strip_scheme, to_command_name, to_double_brace_placeholders};
+use crate::outcomes::HtrsError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const COLLECTION_SCHEMA: &str = "https://schema.getpostman.com/json/collection/v2.1.0/collection.json";
+const HOST_VARIABLE: &str = "baseUrl";
+
+#[derive(Serialize, Deserialize)]
+pub struct PostmanCollection {
+    pub info: PostmanInfo,
+    #[serde(default)]
+    pub item: Vec<PostmanItem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variable: Vec<PostmanVariable>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostmanInfo {
+    pub name: String,
+    pub schema: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostmanItem {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<Vec<PostmanItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<PostmanRequest>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostmanRequest {
+    pub method: Option<String>,
+    #[serde(default)]
+    pub header: Vec<PostmanKeyValue>,
+    pub url: PostmanUrl,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<PostmanBody>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostmanUrl {
+    Raw(String),
+    Detailed(PostmanUrlDetails),
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostmanUrlDetails {
+    pub raw: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<PostmanSegments>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PostmanSegments>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub query: Vec<PostmanKeyValue>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostmanSegments {
+    Joined(String),
+    Split(Vec<String>),
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PostmanKeyValue {
+    pub key: String,
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostmanBody {
+    pub mode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostmanVariable {
+    pub key: String,
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostmanEnvironment {
+    pub name: String,
+    #[serde(default)]
+    pub values: Vec<PostmanEnvironmentValue>,
+    #[serde(rename = "_postman_variable_scope", skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostmanEnvironmentValue {
+    pub key: String,
+    pub value: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+impl PostmanKeyValue {
+    fn is_enabled(&self) -> bool {
+        !self.disabled.unwrap_or(false)
+    }
+}
+
+impl PostmanEnvironment {
+    fn get_value(&self, key: &str) -> Option<&String> {
+        self.values.iter()
+            .filter(|v| v.enabled.unwrap_or(true))
+            .find(|v| v.key == key)
+            .and_then(|v| v.value.as_ref())
+    }
+}
+
+/// The constituent parts of a postman request url, with placeholders still in postman format
+struct UrlParts {
+    host: String,
+    path: String,
+    query: Vec<PostmanKeyValue>,
+}
+
+/// Converts a postman collection into a service. Requests within folders are flattened into a
+/// single list of endpoints with the folder names prefixed onto the endpoint name.
+///
+/// Postman environments are converted into htrs environments using the value of the variable that
+/// the collection's requests use as their host.
+pub fn collection_to_service(
+    collection: &PostmanCollection,
+    name: String,
+    alias: Option<String>,
+    environments: &[PostmanEnvironment],
+) -> Result<Service, HtrsError> {
+    let mut service = Service::new(name, alias);
+    let mut host = None;
+    add_items_to_service(&mut service, &mut host, &collection.item, "");
+
+    let Some(host) = host else {
+        return Ok(service);
+    };
+
+    let host_variable = get_double_brace_placeholder(&host);
+    for environment in environments {
+        let environment_host = match &host_variable {
+            Some(variable) => match environment.get_value(variable) {
+                Some(value) => value.clone(),
+                None => return Err(HtrsError::new(format!("Postman environment `{}` has no value for host variable `{}`", environment.name, variable).as_str())),
+            },
+            None => host.clone(),
+        };
+        service.environments.push(Environment::new(
+            environment.name.clone(),
+            None,
+            strip_scheme(&environment_host),
+            service.environments.is_empty(),
+        ));
+    }
+
+    if service.environments.is_empty() {
+        let default_host = match &host_variable {
+            Some(variable) => collection.variable.iter()
+                .find(|v| &v.key == variable)
+                .and_then(|v| v.value.clone()),
+            None => Some(host),
+        };
+        if let Some(default_host) = default_host {
+            service.environments.push(Environment::new("default".to_string(), None, strip_scheme(&default_host), true));
+        }
+    }
+
+    Ok(service)
+}
+
+/// Gets the literal values given to enabled query parameters across the collection's requests, so
+/// that they aren't lost when the parameters are imported. Where requests give the same parameter
+/// different values, the first request's value is used
+pub fn get_query_values(items: &[PostmanItem]) -> HashMap<String, String> {
+    let mut values = HashMap::new();
+    for item in items {
+        if let Some(children) = &item.item {
+            for (key, value) in get_query_values(children) {
+                values.entry(key).or_insert(value);
+            }
+        }
+        let Some(request) = &item.request else {
+            continue;
+        };
+        for query in get_url_parts(&request.url).query {
+            if let Some(value) = &query.value && query.is_enabled() && get_double_brace_placeholder(value).is_none() {
+                values.entry(query.key.clone()).or_insert(value.clone());
+            }
+        }
+    }
+    values
+}
+
+fn add_items_to_service(service: &mut Service, host: &mut Option<String>, items: &[PostmanItem], prefix: &str) {
+    for (index, item) in items.iter().enumerate() {
+        // Names made up only of punctuation or non-ascii characters have nothing left once made
+        // into a command name, so requests fall back to their position & folders are skipped over
+        let item_name = match to_command_name(&item.name) {
+            item_name if item_name.is_empty() && item.request.is_some() => format!("request-{}", index + 1),
+            item_name => item_name,
+        };
+        let name = match (prefix.is_empty(), item_name.is_empty()) {
+            (_, true) => prefix.to_string(),
+            (true, false) => item_name,
+            (false, false) => format!("{}-{}", prefix, item_name),
+        };
+
+        if let Some(children) = &item.item {
+            add_items_to_service(service, host, children, &name);
+        }
+
+        let Some(request) = &item.request else {
+            continue;
+        };
+
+        let url = get_url_parts(&request.url);
+        if host.is_none() && !url.host.is_empty() {
+            *host = Some(url.host.clone());
+        }
+
+        let mut endpoint_name = name.clone();
+        let mut suffix = 2;
+        while service.get_endpoint(&endpoint_name).is_some() {
+            endpoint_name = format!("{}-{}", name, suffix);
+            suffix += 1;
+        }
+
+        service.endpoints.push(Endpoint {
+            name: endpoint_name,
+            method: request.method.clone().unwrap_or("GET".to_string()).to_uppercase(),
+            path_template: url.path,
+            query_parameters: url.query.iter()
+                .map(|q| QueryParameter {
+                    name: q.key.clone(),
+                    required: q.is_enabled() && q.value.as_ref().and_then(|v| get_double_brace_placeholder(v)).is_some(),
+                })
+                .collect(),
+            headers: request.header.iter()
+                .filter(|h| h.is_enabled())
+                .map(|h| (h.key.clone(), h.value.clone().unwrap_or_default()))
+                .collect(),
+            body: request.body.as_ref()
+                .filter(|b| b.mode == "raw")
+                .and_then(|b| b.raw.clone()),
+            description: None,
+            alias: None,
+            tags: vec![],
+            parameter_descriptions: HashMap::new(),
+            mock: None,
+            pagination: None,
+        });
+    }
+}
+
+fn get_url_parts(url: &PostmanUrl) -> UrlParts {
+    let details = match url {
+        PostmanUrl::Raw(raw) => return parse_raw_url(raw),
+        PostmanUrl::Detailed(details) => details,
+    };
+
+    let (Some(host), Some(path)) = (&details.host, &details.path) else {
+        return parse_raw_url(details.raw.as_deref().unwrap_or_default());
+    };
+
+    let mut host = match host {
+        PostmanSegments::Joined(host) => host.clone(),
+        PostmanSegments::Split(segments) => segments.join("."),
+    };
+    if let Some(port) = &details.port {
+        host = format!("{}:{}", host, port);
+    }
+
+    let path = match path {
+        PostmanSegments::Joined(path) => path.clone(),
+        PostmanSegments::Split(segments) => segments.join("/"),
+    };
+
+    UrlParts {
+        host,
+        path: convert_path(&path),
+        query: details.query.clone(),
+    }
+}
+
+fn parse_raw_url(raw: &str) -> UrlParts {
+    let url = split_raw_url(raw);
+    UrlParts {
+        host: url.host,
+        path: convert_path(&url.path),
+        query: url.query.into_iter()
+            .map(|(key, value)| PostmanKeyValue { key, value, disabled: None })
+            .collect(),
+    }
+}
+
+/// Converts a postman path into a htrs path template, both `{{name}}` and `:name` path variables
+/// are converted into `{name}` parameters
+fn convert_path(path: &str) -> String {
+    let segments = path.split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| match s.strip_prefix(':') {
+            Some(variable) => format!("{{{}}}", variable),
+            None => from_double_brace_placeholders(s),
+        })
+        .collect::<Vec<String>>();
+    format!("/{}", segments.join("/"))
+}
+
+/// Converts a service into a postman collection, the host of each request is provided by a
+/// `baseUrl` variable so that environments can be exported separately
+pub fn service_to_collection(service: &Service, global_headers: &HashMap<String, String>) -> PostmanCollection {
+    let service_headers = merge_hashmaps(global_headers.clone(), service.headers.clone());
+    PostmanCollection {
+        info: PostmanInfo {
+            name: service.name.clone(),
+            schema: Some(COLLECTION_SCHEMA.to_string()),
+        },
+        item: service.endpoints.iter()
+            .map(|e| endpoint_to_item(e, &service_headers))
+            .collect(),
+        variable: match service.get_default_environment() {
+            Some(environment) => vec![PostmanVariable {
+                key: HOST_VARIABLE.to_string(),
+                value: Some(format!("http://{}", environment.host)),
+            }],
+            None => vec![],
+        },
+    }
+}
+
+fn endpoint_to_item(endpoint: &Endpoint, service_headers: &HashMap<String, String>) -> PostmanItem {
+    let path = to_double_brace_placeholders(&endpoint.path_template);
+    let query = endpoint.query_parameters.iter()
+        .map(|q| PostmanKeyValue {
+            key: q.name.clone(),
+            value: Some(format!("{{{{{}}}}}", q.name)),
+            disabled: match q.required {
+                true => None,
+                false => Some(true),
+            },
+        })
+        .collect::<Vec<PostmanKeyValue>>();
+
+    let mut raw = format!("{{{{{}}}}}{}", HOST_VARIABLE, path);
+    let enabled_query = query.iter()
+        .filter(|q| q.is_enabled())
+        .map(|q| format!("{}={}", q.key, q.value.as_deref().unwrap_or_default()))
+        .collect::<Vec<String>>();
+    if !enabled_query.is_empty() {
+        raw = format!("{}?{}", raw, enabled_query.join("&"));
+    }
+
+    let mut headers = merge_hashmaps(service_headers.clone(), endpoint.headers.clone())
+        .into_iter()
+        .map(|(key, value)| PostmanKeyValue { key, value: Some(value), disabled: None })
+        .collect::<Vec<PostmanKeyValue>>();
+    headers.sort_by(|a, b| a.key.cmp(&b.key));
+
+    PostmanItem {
+        name: endpoint.name.clone(),
+        item: None,
+        request: Some(PostmanRequest {
+            method: Some(endpoint.method.clone()),
+            header: headers,
+            url: PostmanUrl::Detailed(PostmanUrlDetails {
+                raw: Some(raw),
+                host: Some(PostmanSegments::Split(vec![format!("{{{{{}}}}}", HOST_VARIABLE)])),
+                port: None,
+                path: Some(PostmanSegments::Split(
+                    path.split('/')
+                        .filter(|s| !s.is_empty())
+                        .map(|s| s.to_string())
+                        .collect()
+                )),
+                query,
+            }),
+            body: endpoint.body.as_ref().map(|body| PostmanBody {
+                mode: "raw".to_string(),
+                raw: Some(body.clone()),
+            }),
+        }),
+    }
+}
+
+/// Converts an environment into a postman environment providing the `baseUrl` variable used by
+/// exported collections
+pub fn environment_to_postman(environment: &Environment) -> PostmanEnvironment {
+    PostmanEnvironment {
+        name: environment.name.clone(),
+        values: vec![PostmanEnvironmentValue {
+            key: HOST_VARIABLE.to_string(),
+            value: Some(format!("http://{}", environment.host)),
+            enabled: Some(true),
+        }],
+        scope: Some("environment".to_string()),
+    }
+}
@@ -0,0 +1,113 @@
+use crate::config::current_config::HtrsConfig;
+use crate::outcomes::HtrsError;
+use crate::request::{redact_headers, Exchange};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+const HISTORY_EXTENSION: &str = "history";
+
+/// Number of requests kept in history, the oldest are dropped once the limit is reached
+pub const HISTORY_LIMIT: usize = 100;
+
+/// Where a request was made from, so that it can be recorded in history & replayed later
+pub struct RequestOrigin {
+    pub service: String,
+    pub endpoint: String,
+    pub environment: String,
+    pub parameters: HashMap<String, String>,
+    pub save_response: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct HistoryEntry {
+    pub id: u64,
+    pub timestamp: String,
+    pub service: String,
+    pub endpoint: String,
+    pub environment: String,
+    pub parameters: HashMap<String, String>,
+    pub method: String,
+    pub url: String,
+    pub request_headers: Vec<(String, String)>,
+    pub request_body: Option<String>,
+    pub status: u16,
+    pub duration_ms: u128,
+    #[serde(default)]
+    pub connect_ms: Option<u128>,
+    #[serde(default)]
+    pub first_byte_ms: Option<u128>,
+    #[serde(default)]
+    pub response_size: Option<usize>,
+    #[serde(default)]
+    pub decoded_size: Option<usize>,
+    pub response_body: Option<String>,
+}
+
+fn get_history_path() -> Result<PathBuf, HtrsError> {
+    HtrsConfig::get_data_file_path(HISTORY_EXTENSION).map_err(|e| HtrsError::new(e.as_str()))
+}
+
+/// Reads all recorded requests, oldest first
+pub fn load_history() -> Result<Vec<HistoryEntry>, HtrsError> {
+    let path = get_history_path()?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) => return Err(HtrsError::new(format!("Failed to read history file `{}`: {}", path.display(), e).as_str())),
+    };
+    match serde_json::from_str(&contents) {
+        Ok(history) => Ok(history),
+        Err(e) => Err(HtrsError::new(format!("Failed to parse history file `{}`: {}", path.display(), e).as_str())),
+    }
+}
+
+pub fn save_history(history: &[HistoryEntry]) -> Result<(), HtrsError> {
+    let path = get_history_path()?;
+    let contents = match serde_json::to_string_pretty(history) {
+        Ok(contents) => contents,
+        Err(e) => return Err(HtrsError::new(format!("Failed to serialize history: {}", e).as_str())),
+    };
+    match fs::write(&path, contents) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(HtrsError::new(format!("Failed to write history file `{}`: {}", path.display(), e).as_str())),
+    }
+}
+
+/// Records a sent request in history, the values of any secret headers are redacted before
+/// they're written to disk
+pub fn record_request(origin: &RequestOrigin, exchange: &Exchange, config: &HtrsConfig) -> Result<(), HtrsError> {
+    let mut history = load_history()?;
+    let id = history.last().map(|e| e.id + 1).unwrap_or(1);
+    history.push(HistoryEntry {
+        id,
+        timestamp: humantime::format_rfc3339_seconds(exchange.started).to_string(),
+        service: origin.service.clone(),
+        endpoint: origin.endpoint.clone(),
+        environment: origin.environment.clone(),
+        parameters: origin.parameters.clone(),
+        method: exchange.method.to_string(),
+        url: exchange.url.to_string(),
+        request_headers: redact_headers(&exchange.request_headers, &config.secret_headers),
+        request_body: exchange.request_body.clone(),
+        status: exchange.status.as_u16(),
+        duration_ms: exchange.total_time().as_millis(),
+        connect_ms: exchange.connect.map(|c| c.as_millis()),
+        first_byte_ms: Some(exchange.wait.as_millis()),
+        response_size: Some(exchange.response_size),
+        decoded_size: Some(exchange.decoded_size),
+        response_body: match origin.save_response {
+            true => Some(exchange.response_body.clone()),
+            false => None,
+        },
+    });
+
+    if history.len() > HISTORY_LIMIT {
+        history.drain(..history.len() - HISTORY_LIMIT);
+    }
+    save_history(&history)
+}
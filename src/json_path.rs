@@ -0,0 +1,76 @@
+use serde_json::Value;
+
+/// Parses a path such as `$.items[*].updated_at` into its segments, the leading `$` is optional
+pub fn parse_path(path: &str) -> Vec<String> {
+    let mut segments = vec!["$".to_string()];
+    let path = path.strip_prefix('$').unwrap_or(path);
+    for part in path.split('.').filter(|p| !p.is_empty()) {
+        let mut rest = part;
+        if let Some(index) = rest.find('[') {
+            if index > 0 {
+                segments.push(rest[..index].to_string());
+            }
+            rest = &rest[index..];
+            while let Some(end) = rest.find(']') {
+                segments.push(rest[..=end].to_string());
+                rest = &rest[end + 1..];
+            }
+        } else {
+            segments.push(rest.to_string());
+        }
+    }
+    segments
+}
+
+pub fn format_path(segments: &[String]) -> String {
+    let mut path = String::new();
+    for segment in segments {
+        if !path.is_empty() && !segment.starts_with('[') {
+            path.push('.');
+        }
+        path.push_str(segment);
+    }
+    path
+}
+
+pub fn matches_any(path: &[String], ignore_paths: &[Vec<String>]) -> bool {
+    ignore_paths.iter().any(|ignore_path| {
+        ignore_path.len() == path.len() && ignore_path.iter().zip(path).all(|(pattern, segment)| {
+            pattern == segment || pattern == "*" || (pattern == "[*]" && segment.starts_with('['))
+        })
+    })
+}
+
+/// Gets the value at a path, where `*` can't be used as a segment
+pub fn select<'a>(value: &'a Value, path: &[String]) -> Option<&'a Value> {
+    let mut current = value;
+    for segment in path.iter().skip(1) {
+        current = match segment.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            Some(index) => current.as_array()?.get(index.parse::<usize>().ok()?)?,
+            None => current.as_object()?.get(segment)?,
+        };
+    }
+    Some(current)
+}
+
+#[cfg(test)]
+mod json_path_tests {
+    use crate::json_path::{parse_path, select};
+    use serde_json::json;
+
+    #[test]
+    fn given_path_when_parse_then_should_split_keys_and_indexes() {
+        assert_eq!(parse_path("$.items[0].name"), vec!["$", "items", "[0]", "name"]);
+        assert_eq!(parse_path("items[*][1]"), vec!["$", "items", "[*]", "[1]"]);
+    }
+
+    #[test]
+    fn given_path_when_select_then_should_get_nested_value() {
+        let value = json!({"items": [{"id": 1}, {"id": 2}]});
+
+        assert_eq!(select(&value, &parse_path("$.items[1].id")), Some(&json!(2)));
+        assert_eq!(select(&value, &parse_path("$")), Some(&value));
+        assert_eq!(select(&value, &parse_path("$.items[2].id")), None);
+        assert_eq!(select(&value, &parse_path("$.missing")), None);
+    }
+}
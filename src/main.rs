@@ -4,14 +4,38 @@ mod outcomes;
 
 mod htrs_binding_error;
 mod common;
+mod formats;
+mod completions;
+mod request;
+mod shell;
+mod history;
+mod compare;
+mod json_path;
+mod testing;
+mod workflow;
+mod ping;
+mod benchmark;
+mod mock;
+mod fixtures;
+mod watch;
+mod pagination;
+mod event_stream;
 
 use crate::commands::RootCommand;
+use crate::event_stream::{is_event_stream, EventStreamParser};
 use crate::outcomes::{HtrsAction, HtrsError};
+use crate::fixtures::{FixtureMode, Fixtures};
+use crate::formats::har::HarCapture;
+use crate::history::{record_request, RequestOrigin};
+use crate::request::{apply_query_params_to_url, begin_request, build_timed_client, StreamEnd};
 use config::current_config::HtrsConfig;
 use reqwest::blocking::Client;
-use reqwest::{Method, Url};
+use reqwest::{Method, StatusCode, Url};
 use std::collections::HashMap;
+use std::fs;
+use std::io::{stdout, Write};
 use std::process;
+use std::time::Duration;
 
 fn main() {
     let mut config = match HtrsConfig::load() {
@@ -53,77 +77,134 @@ fn handle_action(action: HtrsAction, config: HtrsConfig) -> Result<(), HtrsError
             println!("{}", dialogue);
             Ok(())
         },
+        HtrsAction::WriteFiles(files) => {
+            for (path, contents) in files {
+                if let Err(e) = fs::write(&path, contents) {
+                    return Err(HtrsError::new(&format!("Failed to write file `{}`: {e}", path.display())));
+                }
+                println!("Wrote {}", path.display());
+            }
+            Ok(())
+        },
+        HtrsAction::StartShell => shell::run_shell(config),
+        HtrsAction::CompareResponses(comparison) => comparison.run(),
+        HtrsAction::RunTests(test_run) => test_run.run(),
+        HtrsAction::RunWorkflow(workflow_run) => workflow_run.run(&config),
+        HtrsAction::PingEnvironments(health_check) => health_check.run(),
+        HtrsAction::RunBenchmark(benchmark) => benchmark.run(),
+        HtrsAction::ServeMock(mock_server) => mock_server.run(),
+        HtrsAction::WatchRequest(watch) => watch.run(),
+        HtrsAction::FetchAllPages(all_pages) => all_pages.run(),
         HtrsAction::UpdateConfig => {
             match config.save() {
                 Ok(_) => Ok(()),
                 Err(e) => Err(HtrsError::new(e.as_str()))
             }
         },
+        HtrsAction::UpdateConfigWithDialogue(dialogue) => {
+            match config.save() {
+                Ok(_) => {
+                    println!("{}", dialogue);
+                    Ok(())
+                },
+                Err(e) => Err(HtrsError::new(e.as_str()))
+            }
+        },
         HtrsAction::MakeRequest {
-            url: base_url, query_parameters, method, headers, show_body
+            url: base_url, query_parameters, method, headers, body, show_body, timing, max_events, idle_timeout, fixtures, har, origin
         } => {
             let url = apply_query_params_to_url(base_url, query_parameters)?;
-            execute_request(method, url, headers, show_body)
+            let stream = StreamOptions { max_events, idle_timeout };
+            execute_request(method, url, headers, body, show_body, timing, stream, fixtures, har, origin, &config)
         },
     }
 }
 
-fn apply_query_params_to_url(base_url: Url, query_params: HashMap<String, String>) -> Result<Url, HtrsError> {
-    if query_params.is_empty() {
-        return Ok(base_url);
-    }
-
-    let query_params_str = query_params.iter()
-        .map(|(k, v)| format!("{}={}", k, v))
-        .collect::<Vec<String>>()
-        .join("&");
-
-    match base_url.join(&format!("?{query_params_str}")) {
-        Ok(url) => Ok(url),
-        Err(e) => Err(HtrsError::new(&format!("Failed to build url with query parameters: {e}"))),
-    }
+/// Controls for reading a response body as it's streamed
+struct StreamOptions {
+    /// Stop after printing this many server-sent events
+    max_events: Option<u64>,
+    /// Stop once nothing has been received for this long
+    idle_timeout: Option<Duration>,
 }
 
-fn execute_request(method: Method, url: Url, headers: HashMap<String, String>, show_body: bool) -> Result<(), HtrsError> {
-    let mut req_headers = get_default_headers();
-    merge_hashmaps(&mut req_headers, &headers);
+#[allow(clippy::too_many_arguments)]
+fn execute_request(method: Method, url: Url, headers: HashMap<String, String>, body: Option<String>, show_body: bool, timing: bool, stream: StreamOptions, fixtures: Option<Fixtures>, har: Option<HarCapture>, origin: Option<RequestOrigin>, config: &HtrsConfig) -> Result<(), HtrsError> {
+    if let Some(fixtures) = &fixtures && fixtures.mode != FixtureMode::Record {
+        match fixtures.find(&method, &url, &headers, body.as_deref(), &config.secret_headers)? {
+            Some(fixture) => {
+                let status = StatusCode::from_u16(fixture.status).map(|s| s.to_string()).unwrap_or(fixture.status.to_string());
+                if !show_body {
+                    println!("{} | {} | {}", status, method, url);
+                }
+                match is_event_stream(&fixture.response_headers) {
+                    true => {
+                        let mut printed = 0;
+                        print_events(&mut EventStreamParser::default(), &fixture.response_body, &mut printed, stream.max_events);
+                    },
+                    false => println!("{}", fixture.response_body),
+                }
+                return Ok(());
+            },
+            None if fixtures.mode == FixtureMode::StrictReplay => {
+                return Err(HtrsError::new(format!("No recording of `{} {}` found in `{}`", method, url, fixtures.directory.display()).as_str()));
+            },
+            None => {},
+        }
+    }
 
-    let client = Client::new();
-    let mut request_builder = client.request(method.clone(), url.clone());
-    for (k, v) in req_headers {
-        request_builder = request_builder.header(k, v);
+    let (client, connect_timer) = build_timed_client(Client::builder())?;
+    let pending = begin_request(&client, &method, &url, &headers, body.as_deref())?;
+    if !show_body {
+        println!("{} | {} | {}", pending.exchange().status, method, url);
     }
 
-    let request = match request_builder.build() {
-        Ok(request) => request,
-        Err(e) => return Err(HtrsError::new(&e.to_string())),
+    // The body is printed as it arrives, and is only held on to when something needs it afterwards
+    let keep_body = har.is_some()
+        || origin.as_ref().is_some_and(|o| o.save_response)
+        || fixtures.as_ref().is_some_and(|f| f.mode == FixtureMode::Record);
+    let (mut exchange, end) = match is_event_stream(&pending.exchange().response_headers) {
+        true => {
+            let mut parser = EventStreamParser::default();
+            let mut printed = 0;
+            pending.stream_body(stream.idle_timeout, keep_body, |text| print_events(&mut parser, text, &mut printed, stream.max_events))?
+        },
+        false => {
+            let mut stdout = stdout();
+            let result = pending.stream_body(stream.idle_timeout, keep_body, |text| stdout.write_all(text.as_bytes()).and_then(|_| stdout.flush()).is_ok())?;
+            println!();
+            result
+        },
     };
+    exchange.connect = connect_timer.take();
+    if let Some(idle_timeout) = stream.idle_timeout && end == StreamEnd::Idle {
+        eprintln!("Stopped after receiving nothing for {}", humantime::format_duration(idle_timeout));
+    }
+    if timing || config.show_timing {
+        eprintln!("{}", exchange.format_timing());
+    }
 
-    match client.execute(request) {
-        Ok(response) => {
-            if !show_body {
-                println!("{} | {} | {}", response.status(), &method, url);
-            }
-
-            let response_text = response.text()
-                .unwrap_or_else(|e| format!("<Failed to read response body: {}>", e));
-            println!("{}", response_text);
-            Ok(())
-        },
-        Err(e) => {
-            Err(HtrsError::new(&e.to_string()))
-        }
+    if let Some(origin) = origin && let Err(e) = record_request(&origin, &exchange, config) {
+        eprintln!("{}", e.details);
+    }
+    if let Some(fixtures) = fixtures && fixtures.mode == FixtureMode::Record {
+        fixtures.record(&exchange, &headers, &config.secret_headers)?;
     }
+    if let Some(har) = har {
+        har.write(&[exchange])?;
+    }
+    Ok(())
 }
 
-fn merge_hashmaps(into: &mut HashMap<String, String>, from: &HashMap<String, String>) {
-    for (k, v) in from {
-        into.insert(k.clone(), v.clone());
+/// Prints each event completed by the next chunk of an event stream, giving false once
+/// `max_events` have been printed
+fn print_events(parser: &mut EventStreamParser, text: &str, printed: &mut u64, max_events: Option<u64>) -> bool {
+    for event in parser.push(text) {
+        println!("{}", event.format());
+        *printed += 1;
+        if max_events.is_some_and(|max_events| *printed >= max_events) {
+            return false;
+        }
     }
+    true
 }
-
-fn get_default_headers() -> HashMap<String, String> {
-    let mut headers: HashMap<String, String> = HashMap::new();
-    headers.insert("User-Agent".to_string(), format!("htrs/{}", env!("CARGO_PKG_VERSION")));
-    headers
-}
\ No newline at end of file
@@ -0,0 +1,227 @@
+use crate::config::current_config::{Endpoint, Service};
+use crate::outcomes::HtrsError;
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::StatusCode;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+/// Serves canned responses for a service's endpoints until the process is stopped
+pub struct MockServer {
+    pub service: Service,
+    pub host: String,
+    pub port: u16,
+}
+
+struct MockRequest {
+    method: String,
+    target: String,
+}
+
+struct MockReply {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl MockServer {
+    pub fn run(&self) -> Result<(), HtrsError> {
+        let listener = match TcpListener::bind((self.host.as_str(), self.port)) {
+            Ok(listener) => listener,
+            Err(e) => return Err(HtrsError::new(format!("Failed to listen on `{}:{}`: {}", self.host, self.port, e).as_str())),
+        };
+        let address = match listener.local_addr() {
+            Ok(address) => address,
+            Err(e) => return Err(HtrsError::new(format!("Failed to listen on `{}:{}`: {}", self.host, self.port, e).as_str())),
+        };
+        println!("Mocking `{}` on http://{}", self.service.name, address);
+
+        thread::scope(|scope| {
+            for stream in listener.incoming().flatten() {
+                scope.spawn(move || self.handle_connection(stream));
+            }
+        });
+        Ok(())
+    }
+
+    /// Answers a single request on the connection, which is closed once the response is written
+    fn handle_connection(&self, mut stream: TcpStream) {
+        let reply = match read_request(&stream) {
+            None => MockReply { status: 400, headers: vec![], body: "Malformed request".to_string() },
+            Some(request) => {
+                let (reply, outcome) = self.get_reply(&request);
+                println!("{} {} -> {} {}", request.method, request.target, reply.status, outcome);
+                reply
+            },
+        };
+
+        let mut response = format!(
+            "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\nAccess-Control-Allow-Origin: *\r\n",
+            reply.status,
+            StatusCode::from_u16(reply.status).ok().and_then(|s| s.canonical_reason()).unwrap_or_default(),
+            reply.body.len(),
+        );
+        for (name, value) in reply.headers.iter().filter(|(name, _)| !name.eq_ignore_ascii_case("content-length")) {
+            response.push_str(format!("{}: {}\r\n", name, value).as_str());
+        }
+        response.push_str("\r\n");
+        response.push_str(&reply.body);
+        let _ = stream.write_all(response.as_bytes());
+    }
+
+    /// Gets the response for a request along with a description of how it was matched for logging
+    fn get_reply(&self, request: &MockRequest) -> (MockReply, String) {
+        let Some(endpoint) = find_endpoint(&self.service.endpoints, &request.method, &request.target) else {
+            // Lets browsers make cross-origin requests to the mock from a local frontend
+            if request.method == "OPTIONS" {
+                let headers = vec![
+                    ("Access-Control-Allow-Methods".to_string(), "*".to_string()),
+                    ("Access-Control-Allow-Headers".to_string(), "*".to_string()),
+                ];
+                return (MockReply { status: 204, headers, body: String::new() }, "(preflight)".to_string());
+            }
+            let body = format!("No endpoint matches `{} {}`", request.method, request.target);
+            return (MockReply { status: 404, headers: vec![], body }, "(unmatched)".to_string());
+        };
+        let Some(mock) = &endpoint.mock else {
+            return (MockReply { status: 200, headers: vec![], body: String::new() }, format!("{} (no mock response set)", endpoint.name));
+        };
+
+        let body = match &mock.file {
+            Some(file) => match fs::read_to_string(file) {
+                Ok(body) => body,
+                Err(e) => {
+                    let body = format!("Failed to read mock response file `{}`: {}", file, e);
+                    return (MockReply { status: 500, headers: vec![], body: body.clone() }, format!("{} ({})", endpoint.name, body));
+                },
+            },
+            None => mock.body.clone().unwrap_or_default(),
+        };
+        let mut headers: Vec<(String, String)> = mock.headers.iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        headers.sort();
+        let has_content_type = headers.iter().any(|(name, _)| name.eq_ignore_ascii_case("content-type"));
+        if !has_content_type && serde_json::from_str::<serde_json::Value>(&body).is_ok() {
+            headers.push(("Content-Type".to_string(), "application/json".to_string()));
+        }
+
+        (MockReply { status: mock.status, headers, body }, endpoint.name.clone())
+    }
+}
+
+/// Reads the request line & headers, discarding any body so that the client isn't cut off
+fn read_request(stream: &TcpStream) -> Option<MockRequest> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next()?.to_uppercase();
+    let target = parts.next()?.to_string();
+
+    let mut content_length: u64 = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).ok()? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') && name.trim().eq_ignore_ascii_case("content-length") {
+            content_length = value.trim().parse().ok()?;
+        }
+    }
+    let discarded = io::copy(&mut reader.take(content_length), &mut io::sink()).ok()?;
+    if discarded < content_length {
+        return None;
+    }
+
+    Some(MockRequest { method, target })
+}
+
+/// Finds the endpoint whose method, path template & required query parameters match the request,
+/// preferring the most specific template where several match, e.g. `/users/me` over `/users/{id}`
+fn find_endpoint<'a>(endpoints: &'a [Endpoint], method: &str, target: &str) -> Option<&'a Endpoint> {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query_names: Vec<&str> = query.split('&')
+        .map(|pair| pair.split_once('=').map(|(name, _)| name).unwrap_or(pair))
+        .collect();
+
+    endpoints.iter()
+        .filter(|e| e.method.eq_ignore_ascii_case(method))
+        .filter(|e| e.query_parameters.iter().all(|q| !q.required || query_names.contains(&q.name.as_str())))
+        .filter_map(|e| match_path(&e.path_template, path).map(|specificity| (e, specificity)))
+        .rev()
+        .max_by_key(|(_, specificity)| *specificity)
+        .map(|(endpoint, _)| endpoint)
+}
+
+/// Matches a path against a template where each `{param}` matches a single path segment, giving
+/// the number of literal characters in the template when it matches
+fn match_path(template: &str, path: &str) -> Option<usize> {
+    lazy_static! {
+        static ref PARAM: Regex = Regex::new(r"\{[^/{}]+}").unwrap();
+    }
+    let literals: Vec<&str> = PARAM.split(template).collect();
+    let pattern = literals.iter()
+        .map(|literal| regex::escape(literal))
+        .collect::<Vec<String>>()
+        .join("[^/]+");
+    let matches = Regex::new(format!("^{}$", pattern).as_str()).ok()?.is_match(path);
+    matches.then(|| literals.iter().map(|literal| literal.len()).sum())
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use crate::config::current_config::{Endpoint, QueryParameter};
+    use crate::mock::{find_endpoint, match_path};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn endpoint(name: &str, method: &str, path_template: &str, required_query: Option<&str>) -> Endpoint {
+        Endpoint {
+            name: name.to_string(),
+            method: method.to_string(),
+            path_template: path_template.to_string(),
+            query_parameters: required_query.into_iter()
+                .map(|q| QueryParameter { name: q.to_string(), required: true })
+                .collect(),
+            headers: HashMap::new(),
+            body: None,
+            description: None,
+            alias: None,
+            tags: vec![],
+            parameter_descriptions: HashMap::new(),
+            mock: None,
+            pagination: None,
+        }
+    }
+
+    #[rstest]
+    #[case("/users/{id}", "/users/1", Some(7))]
+    #[case("/users/{id}", "/users/1/orders", None)]
+    #[case("/users/{id}", "/users/", None)]
+    #[case("/files/{name}.json", "/files/report.json", Some(12))]
+    #[case("/health", "/health", Some(7))]
+    #[case("/health", "/healthz", None)]
+    fn given_template_when_match_path_then_should_match_segments(#[case] template: &str, #[case] path: &str, #[case] expected: Option<usize>) {
+        assert_eq!(match_path(template, path), expected);
+    }
+
+    #[test]
+    fn given_matching_endpoints_when_find_endpoint_then_should_prefer_most_specific() {
+        let endpoints = vec![
+            endpoint("get_user", "GET", "/users/{id}", None),
+            endpoint("get_me", "GET", "/users/me", None),
+            endpoint("delete_user", "DELETE", "/users/{id}", None),
+            endpoint("search", "GET", "/search", Some("q")),
+        ];
+
+        assert_eq!(find_endpoint(&endpoints, "GET", "/users/me").map(|e| e.name.as_str()), Some("get_me"));
+        assert_eq!(find_endpoint(&endpoints, "GET", "/users/1").map(|e| e.name.as_str()), Some("get_user"));
+        assert_eq!(find_endpoint(&endpoints, "DELETE", "/users/1").map(|e| e.name.as_str()), Some("delete_user"));
+        assert_eq!(find_endpoint(&endpoints, "GET", "/search?q=foo").map(|e| e.name.as_str()), Some("search"));
+        assert_eq!(find_endpoint(&endpoints, "GET", "/search?page=2").map(|e| e.name.as_str()), None);
+        assert_eq!(find_endpoint(&endpoints, "POST", "/users/1").map(|e| e.name.as_str()), None);
+    }
+}
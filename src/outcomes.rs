@@ -1,7 +1,20 @@
+use crate::benchmark::Benchmark;
+use crate::compare::Comparison;
+use crate::fixtures::Fixtures;
+use crate::formats::har::HarCapture;
+use crate::history::RequestOrigin;
+use crate::mock::MockServer;
+use crate::pagination::AllPages;
+use crate::ping::HealthCheck;
+use crate::testing::TestRun;
+use crate::watch::Watch;
+use crate::workflow::WorkflowRun;
 use reqwest::{Method, Url};
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct HtrsError {
@@ -26,14 +39,33 @@ impl Error for HtrsError {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 pub enum HtrsAction {
     UpdateConfig,
+    UpdateConfigWithDialogue(String),
     PrintDialogue(String),
+    WriteFiles(Vec<(PathBuf, String)>),
+    StartShell,
+    CompareResponses(Comparison),
+    RunTests(TestRun),
+    RunWorkflow(WorkflowRun),
+    PingEnvironments(HealthCheck),
+    RunBenchmark(Benchmark),
+    ServeMock(MockServer),
+    WatchRequest(Watch),
+    FetchAllPages(AllPages),
     MakeRequest {
         url: Url,
         query_parameters: HashMap<String, String>,
         method: Method,
         headers: HashMap<String, String>,
+        body: Option<String>,
         show_body: bool,
+        timing: bool,
+        max_events: Option<u64>,
+        idle_timeout: Option<Duration>,
+        fixtures: Option<Fixtures>,
+        har: Option<HarCapture>,
+        origin: Option<RequestOrigin>,
     },
 }
\ No newline at end of file
@@ -0,0 +1,177 @@
+use crate::config::current_config::{Pagination, PaginationStrategy};
+use crate::formats::har::HarCapture;
+use crate::json_path::{parse_path, select};
+use crate::outcomes::HtrsError;
+use crate::request::{apply_query_params_to_url, send_request, Exchange};
+use reqwest::blocking::Client;
+use reqwest::{Method, Url};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Requests each page of a paginated endpoint's responses in turn, until there are no more pages
+/// or `max_pages` have been requested
+pub struct AllPages {
+    pub url: Url,
+    pub query_parameters: HashMap<String, String>,
+    pub method: Method,
+    pub headers: HashMap<String, String>,
+    pub body: Option<String>,
+    pub pagination: Pagination,
+    pub max_pages: Option<u64>,
+    pub show_body: bool,
+    pub har: Option<HarCapture>,
+}
+
+impl AllPages {
+    /// Prints each page's response, or the items from every page as a single array when the
+    /// endpoint's pagination has an items path
+    pub fn run(&self) -> Result<(), HtrsError> {
+        let mut exchanges = vec![];
+        let result = self.fetch_pages(&mut exchanges);
+        // Pages up to & including one that failed are recorded
+        if let Some(har) = &self.har {
+            har.write(&exchanges)?;
+        }
+        result
+    }
+
+    fn fetch_pages(&self, exchanges: &mut Vec<Exchange>) -> Result<(), HtrsError> {
+        let client = Client::new();
+        let items_path = self.pagination.items.as_deref().map(parse_path);
+        let mut query_parameters = self.query_parameters.clone();
+        match &self.pagination.strategy {
+            PaginationStrategy::Page { parameter } => {
+                query_parameters.entry(parameter.clone()).or_insert("1".to_string());
+            },
+            PaginationStrategy::Offset { parameter } => {
+                query_parameters.entry(parameter.clone()).or_insert("0".to_string());
+            },
+            PaginationStrategy::Link | PaginationStrategy::Cursor { .. } => {},
+        }
+
+        let mut url = apply_query_params_to_url(self.url.clone(), query_parameters.clone())?;
+        let mut items: Vec<Value> = vec![];
+        let mut pages = 0;
+        loop {
+            exchanges.push(send_request(&client, &self.method, &url, &self.headers, self.body.as_deref())?);
+            let exchange = exchanges.last().unwrap();
+            pages += 1;
+
+            if items_path.is_none() || !exchange.status.is_success() {
+                print_page(exchange, self.show_body);
+            } else if !self.show_body {
+                println!("{} | {} | {}", exchange.status, exchange.method, exchange.url);
+            }
+            if !exchange.status.is_success() {
+                return Err(HtrsError::new(format!("Stopped after page {} responded with {}", pages, exchange.status).as_str()));
+            }
+
+            let body = serde_json::from_str::<Value>(&exchange.response_body).ok();
+            let page_items = body.as_ref()
+                .and_then(|body| select(body, items_path.as_deref().unwrap_or(&["$".to_string()])))
+                .and_then(|items| items.as_array());
+            if items_path.is_some() {
+                items.extend(page_items.into_iter().flatten().cloned());
+            }
+
+            let Some(next_url) = self.get_next_url(exchange, body.as_ref(), page_items, &mut query_parameters)? else {
+                break;
+            };
+            if self.max_pages.is_some_and(|max_pages| pages >= max_pages) {
+                eprintln!("Stopped after {} pages, more pages are available", pages);
+                break;
+            }
+            url = next_url;
+        }
+
+        if items_path.is_some() {
+            match serde_json::to_string(&items) {
+                Ok(items) => println!("{}", items),
+                Err(e) => return Err(HtrsError::new(format!("Failed to serialize items: {}", e).as_str())),
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the url of the page after the one in `exchange`, if there is one. Query parameters
+    /// given for the first page are kept for later pages, other than the link strategy where the
+    /// next url is used as given
+    fn get_next_url(&self, exchange: &Exchange, body: Option<&Value>, page_items: Option<&Vec<Value>>, query_parameters: &mut HashMap<String, String>) -> Result<Option<Url>, HtrsError> {
+        let (parameter, value) = match &self.pagination.strategy {
+            PaginationStrategy::Link => {
+                return Ok(get_next_link(&exchange.response_headers).and_then(|link| exchange.url.join(&link).ok()));
+            },
+            PaginationStrategy::Cursor { path, parameter } => {
+                let cursor = match body.and_then(|body| select(body, &parse_path(path))) {
+                    Some(Value::String(cursor)) if !cursor.is_empty() => cursor.clone(),
+                    Some(Value::Number(cursor)) => cursor.to_string(),
+                    _ => return Ok(None),
+                };
+                (parameter, cursor)
+            },
+            PaginationStrategy::Page { parameter } => {
+                if page_items.is_none_or(|items| items.is_empty()) {
+                    return Ok(None);
+                }
+                (parameter, (get_number(query_parameters, parameter)? + 1).to_string())
+            },
+            PaginationStrategy::Offset { parameter } => {
+                let Some(page_items) = page_items.filter(|items| !items.is_empty()) else {
+                    return Ok(None);
+                };
+                (parameter, (get_number(query_parameters, parameter)? + page_items.len() as u64).to_string())
+            },
+        };
+
+        query_parameters.insert(parameter.clone(), value);
+        Ok(Some(apply_query_params_to_url(self.url.clone(), query_parameters.clone())?))
+    }
+}
+
+fn print_page(exchange: &Exchange, show_body: bool) {
+    if !show_body {
+        println!("{} | {} | {}", exchange.status, exchange.method, exchange.url);
+    }
+    println!("{}", exchange.response_body);
+}
+
+fn get_number(query_parameters: &HashMap<String, String>, parameter: &str) -> Result<u64, HtrsError> {
+    let value = query_parameters.get(parameter).map(|v| v.as_str()).unwrap_or_default();
+    match value.parse::<u64>() {
+        Ok(number) => Ok(number),
+        Err(_) => Err(HtrsError::new(format!("Query parameter `{}` should be a number to paginate with, but was `{}`", parameter, value).as_str())),
+    }
+}
+
+/// Gets the url with `rel="next"` from `Link` headers, e.g. `<https://api.com/items?page=2>; rel="next"`
+fn get_next_link(headers: &[(String, String)]) -> Option<String> {
+    headers.iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("link"))
+        .flat_map(|(_, value)| value.split(','))
+        .find_map(|link| {
+            let (target, params) = link.split_once(';')?;
+            let is_next = params.split(';')
+                .filter_map(|param| param.trim().split_once('='))
+                .any(|(name, rel)| name.trim().eq_ignore_ascii_case("rel")
+                    && rel.trim().trim_matches('"').split_whitespace().any(|rel| rel.eq_ignore_ascii_case("next")));
+            let target = target.trim().strip_prefix('<')?.strip_suffix('>')?;
+            is_next.then(|| target.to_string())
+        })
+}
+
+#[cfg(test)]
+mod pagination_tests {
+    use crate::pagination::get_next_link;
+
+    #[test]
+    fn given_link_headers_when_get_next_link_then_should_find_next_url() {
+        let headers = vec![
+            ("content-type".to_string(), "application/json".to_string()),
+            ("link".to_string(), "<https://api.com/items?page=1>; rel=\"prev\", <https://api.com/items?page=3>; rel=\"next last\"".to_string()),
+        ];
+
+        assert_eq!(get_next_link(&headers), Some("https://api.com/items?page=3".to_string()));
+        assert_eq!(get_next_link(&[("Link".to_string(), "</items?page=2>; rel=next".to_string())]), Some("/items?page=2".to_string()));
+        assert_eq!(get_next_link(&[("link".to_string(), "<https://api.com/items?page=1>; rel=\"prev\"".to_string())]), None);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::config::current_config::Assertion;
+use crate::formats::har::HarCapture;
+use crate::outcomes::HtrsError;
+use crate::request::{send_request, Exchange, PreparedRequest};
+use crate::testing::check_assertion;
+use reqwest::blocking::Client;
+use std::thread;
+use std::time::Duration;
+
+/// An environment's health request, which is an error where it couldn't be resolved
+pub struct PingTarget {
+    pub environment: String,
+    pub host: String,
+    pub request: Result<PreparedRequest, HtrsError>,
+}
+
+/// Health requests for every environment of a service, which are sent at the same time
+pub struct HealthCheck {
+    pub targets: Vec<PingTarget>,
+    pub timeout: Duration,
+    pub har: Option<HarCapture>,
+}
+
+struct PingResult<'a> {
+    target: &'a PingTarget,
+    status: Option<u16>,
+    latency: Option<Duration>,
+    error: Option<String>,
+    exchange: Option<Exchange>,
+}
+
+impl HealthCheck {
+    /// Pings each environment & prints a table of the results, failing if any are unhealthy
+    pub fn run(&self) -> Result<(), HtrsError> {
+        let client = match Client::builder().timeout(self.timeout).build() {
+            Ok(client) => client,
+            Err(e) => return Err(HtrsError::new(format!("Failed to create http client: {}", e).as_str())),
+        };
+
+        let results: Vec<PingResult> = thread::scope(|scope| {
+            let handles: Vec<_> = self.targets.iter()
+                .map(|target| scope.spawn(|| ping(&client, target)))
+                .collect();
+            handles.into_iter()
+                .map(|handle| handle.join().expect("Ping thread panicked"))
+                .collect()
+        });
+
+        let rows: Vec<Vec<String>> = results.iter()
+            .map(|r| vec![
+                r.target.environment.clone(),
+                r.target.host.clone(),
+                r.status.map(|s| s.to_string()).unwrap_or("-".to_string()),
+                r.latency.map(|l| format!("{}ms", l.as_millis())).unwrap_or("-".to_string()),
+                r.error.clone().unwrap_or_default(),
+            ])
+            .collect();
+        println!("{}", format_table(&["ENVIRONMENT", "HOST", "STATUS", "LATENCY", "ERROR"], &rows));
+
+        let unhealthy = results.iter().filter(|r| r.error.is_some()).count();
+        let total = results.len();
+        if let Some(har) = &self.har {
+            har.write(&results.into_iter().filter_map(|r| r.exchange).collect::<Vec<Exchange>>())?;
+        }
+        match unhealthy {
+            0 => {
+                println!("{} of {} environments healthy", total, total);
+                Ok(())
+            },
+            n => Err(HtrsError::new(format!("{} of {} environments unhealthy", n, total).as_str())),
+        }
+    }
+}
+
+fn ping<'a>(client: &Client, target: &'a PingTarget) -> PingResult<'a> {
+    let failed = |error: &str| PingResult {
+        target,
+        status: None,
+        latency: None,
+        error: Some(error.to_string()),
+        exchange: None,
+    };
+
+    let request = match &target.request {
+        Ok(request) => request,
+        Err(e) => return failed(&e.details),
+    };
+    let exchange = match send_request(client, &request.method, &request.url, &request.headers, request.body.as_deref()) {
+        Ok(exchange) => exchange,
+        Err(e) => return failed(&e.details),
+    };
+
+    let healthy = Assertion::Status { expected: "2xx".to_string() };
+    PingResult {
+        target,
+        status: Some(exchange.status.as_u16()),
+        latency: Some(exchange.total_time()),
+        error: check_assertion(&healthy, exchange.status.as_u16(), &[], None, exchange.total_time()).err(),
+        exchange: Some(exchange),
+    }
+}
+
+/// Formats rows as columns that are padded to the width of their longest value
+fn format_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.chars().count());
+        }
+    }
+
+    let headers: Vec<String> = headers.iter().map(|h| h.to_string()).collect();
+    std::iter::once(&headers)
+        .chain(rows)
+        .map(|row| row.iter()
+            .zip(&widths)
+            .map(|(value, width)| format!("{:width$}", value, width = width))
+            .collect::<Vec<String>>()
+            .join("  ")
+            .trim_end()
+            .to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod ping_tests {
+    use crate::ping::format_table;
+
+    #[test]
+    fn given_rows_when_format_table_then_should_align_columns() {
+        let rows = vec![
+            vec!["local".to_string(), "localhost:8080".to_string(), "200".to_string(), "".to_string()],
+            vec!["production".to_string(), "foo.com".to_string(), "-".to_string(), "timed out".to_string()],
+        ];
+
+        assert_eq!(
+            format_table(&["ENVIRONMENT", "HOST", "STATUS", "ERROR"], &rows),
+            "ENVIRONMENT  HOST            STATUS  ERROR
+local        localhost:8080  200
+production   foo.com         -       timed out",
+        );
+    }
+}
@@ -0,0 +1,418 @@
+use crate::outcomes::HtrsError;
+use encoding_rs::{Encoding, UTF_8};
+use flate2::write::{GzDecoder, ZlibDecoder};
+use reqwest::blocking::{Client, ClientBuilder, Request, Response};
+use reqwest::header::{HeaderMap, CONTENT_ENCODING, CONTENT_TYPE};
+use reqwest::{Method, StatusCode, Url};
+use std::collections::HashMap;
+use std::future::Future;
+use std::io::{self, Read, Write};
+use std::mem;
+use std::pin::Pin;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+use tower_layer::Layer;
+use tower_service::Service;
+
+pub const REDACTED: &str = "<redacted>";
+
+/// A request that has been sent along with the response that was received for it
+pub struct Exchange {
+    pub started: SystemTime,
+    pub method: Method,
+    pub url: Url,
+    pub request_headers: Vec<(String, String)>,
+    pub request_body: Option<String>,
+    pub status: StatusCode,
+    pub http_version: String,
+    pub response_headers: Vec<(String, String)>,
+    /// Body of the response, which is left empty when the body was streamed without keeping it
+    pub response_body: String,
+    /// Size of the response body as it was received, before any `gzip` or `deflate` content
+    /// encoding is decompressed, in bytes
+    pub response_size: usize,
+    /// Size of the response body once decompressed & decoded to text, in bytes
+    pub decoded_size: usize,
+    /// Time taken to establish a connection, which is only known when the client was created with
+    /// `build_timed_client` & a new connection was made for the request
+    pub connect: Option<Duration>,
+    /// Time between sending the request and receiving the response headers
+    pub wait: Duration,
+    /// Time taken to read the response body
+    pub receive: Duration,
+}
+
+/// A request that has been resolved from config and is ready to be sent
+#[derive(Clone)]
+pub struct PreparedRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HashMap<String, String>,
+    pub body: Option<String>,
+}
+
+impl Exchange {
+    pub fn total_time(&self) -> Duration {
+        self.wait + self.receive
+    }
+
+    /// Timing & size figures for the exchange on a single line
+    pub fn format_timing(&self) -> String {
+        format!(
+            "connect {} | first byte {}ms | total {}ms | size {} bytes | decoded {} bytes",
+            self.connect.map(|c| format!("{}ms", c.as_millis())).unwrap_or("-".to_string()),
+            self.wait.as_millis(),
+            self.total_time().as_millis(),
+            self.response_size,
+            self.decoded_size,
+        )
+    }
+}
+
+/// Records how long each new connection made by a client took to establish
+#[derive(Clone, Default)]
+pub struct ConnectTimer {
+    connects: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl ConnectTimer {
+    /// Total time spent connecting since the last call, if any connections were made
+    pub fn take(&self) -> Option<Duration> {
+        let connects: Vec<Duration> = self.connects.lock().unwrap().drain(..).collect();
+        match connects.is_empty() {
+            true => None,
+            false => Some(connects.iter().sum()),
+        }
+    }
+}
+
+impl<S> Layer<S> for ConnectTimer {
+    type Service = TimedConnector<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TimedConnector { inner, timer: self.clone() }
+    }
+}
+
+/// Wraps a client's connector to time each connection, including any TLS handshake
+#[derive(Clone)]
+pub struct TimedConnector<S> {
+    inner: S,
+    timer: ConnectTimer,
+}
+
+impl<S, R> Service<R> for TimedConnector<S>
+where
+    S: Service<R>,
+    S::Future: Send + 'static,
+    S::Response: 'static,
+    S::Error: 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        let started = Instant::now();
+        let connecting = self.inner.call(request);
+        let connects = self.timer.connects.clone();
+        Box::pin(async move {
+            let result = connecting.await;
+            connects.lock().unwrap().push(started.elapsed());
+            result
+        })
+    }
+}
+
+/// Builds a client that records the time taken to connect for each request it sends
+pub fn build_timed_client(builder: ClientBuilder) -> Result<(Client, ConnectTimer), HtrsError> {
+    let timer = ConnectTimer::default();
+    match builder.connector_layer(timer.clone()).build() {
+        Ok(client) => Ok((client, timer)),
+        Err(e) => Err(HtrsError::new(format!("Failed to create http client: {}", e).as_str())),
+    }
+}
+
+pub fn apply_query_params_to_url(base_url: Url, query_params: HashMap<String, String>) -> Result<Url, HtrsError> {
+    if query_params.is_empty() {
+        return Ok(base_url);
+    }
+
+    let query_params_str = query_params.iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<String>>()
+        .join("&");
+
+    match base_url.join(&format!("?{query_params_str}")) {
+        Ok(url) => Ok(url),
+        Err(e) => Err(HtrsError::new(&format!("Failed to build url with query parameters: {e}"))),
+    }
+}
+
+/// Headers that are sent with every request unless overridden
+pub fn get_default_headers() -> HashMap<String, String> {
+    let mut headers: HashMap<String, String> = HashMap::new();
+    headers.insert("User-Agent".to_string(), format!("htrs/{}", env!("CARGO_PKG_VERSION")));
+    headers
+}
+
+/// Builds a request with the default headers applied
+pub fn build_request(client: &Client, method: &Method, url: &Url, headers: &HashMap<String, String>, body: Option<&str>) -> Result<Request, HtrsError> {
+    let mut req_headers = get_default_headers();
+    for (k, v) in headers {
+        req_headers.insert(k.clone(), v.clone());
+    }
+
+    let mut request_builder = client.request(method.clone(), url.clone());
+    for (k, v) in req_headers {
+        request_builder = request_builder.header(k, v);
+    }
+    if let Some(body) = body {
+        request_builder = request_builder.body(body.to_string());
+    }
+
+    match request_builder.build() {
+        Ok(request) => Ok(request),
+        Err(e) => Err(HtrsError::new(&e.to_string())),
+    }
+}
+
+/// Sends a request with the default headers applied, reading the full response
+pub fn send_request(client: &Client, method: &Method, url: &Url, headers: &HashMap<String, String>, body: Option<&str>) -> Result<Exchange, HtrsError> {
+    begin_request(client, method, url, headers, body).map(|pending| pending.read_body())
+}
+
+/// A response whose headers have been received but whose body hasn't been read yet
+pub struct PendingResponse {
+    exchange: Exchange,
+    response: Response,
+    timer: Instant,
+}
+
+/// How reading a streamed body came to an end
+#[derive(PartialEq)]
+pub enum StreamEnd {
+    Finished,
+    /// Reading was stopped early by the caller
+    Stopped,
+    /// Nothing was received for the idle timeout
+    Idle,
+}
+
+/// Sends a request with the default headers applied, returning once the response headers arrive
+pub fn begin_request(client: &Client, method: &Method, url: &Url, headers: &HashMap<String, String>, body: Option<&str>) -> Result<PendingResponse, HtrsError> {
+    let request = build_request(client, method, url, headers, body)?;
+    execute_request(client, request).map_err(|e| HtrsError::new(&e.to_string()))
+}
+
+/// Sends a request that has already been built, returning once the response headers arrive
+pub fn execute_request(client: &Client, request: Request) -> Result<PendingResponse, reqwest::Error> {
+    let method = request.method().clone();
+    let url = request.url().clone();
+    let request_headers = header_map_to_vec(request.headers());
+    let request_body = request.body()
+        .and_then(|body| body.as_bytes())
+        .map(|body| String::from_utf8_lossy(body).into_owned());
+
+    let started = SystemTime::now();
+    let timer = Instant::now();
+    let response = client.execute(request)?;
+    let wait = timer.elapsed();
+
+    let exchange = Exchange {
+        started,
+        method,
+        url,
+        request_headers,
+        request_body,
+        status: response.status(),
+        http_version: format!("{:?}", response.version()),
+        response_headers: header_map_to_vec(response.headers()),
+        response_body: String::new(),
+        response_size: 0,
+        decoded_size: 0,
+        connect: None,
+        wait,
+        receive: Duration::ZERO,
+    };
+    Ok(PendingResponse { exchange, response, timer })
+}
+
+impl PendingResponse {
+    /// The exchange so far, which has the response's status & headers but no body
+    pub fn exchange(&self) -> &Exchange {
+        &self.exchange
+    }
+
+    /// Reads the full body, a body that can't be read is replaced with the reason why
+    pub fn read_body(self) -> Exchange {
+        let (mut exchange, read) = self.read_to_end();
+        if let Err(e) = read {
+            exchange.response_body = format!("<{}>", e.details);
+            exchange.decoded_size = exchange.response_body.len();
+        }
+        exchange
+    }
+
+    /// Reads the full body, failing if it can't be read
+    pub fn try_read_body(self) -> Result<Exchange, HtrsError> {
+        let (exchange, read) = self.read_to_end();
+        read.map(|_| exchange)
+    }
+
+    fn read_to_end(self) -> (Exchange, Result<(), HtrsError>) {
+        let PendingResponse { mut exchange, response, timer } = self;
+        let encoding = get_response_encoding(response.headers());
+        let mut decompressor = Decompressor::new(response.headers());
+        let read = match response.bytes() {
+            Ok(bytes) => {
+                exchange.response_size = bytes.len();
+                decompressor.decompress(&bytes, true).map(|bytes| {
+                    exchange.response_body = encoding.decode(&bytes).0.into_owned();
+                    exchange.decoded_size = exchange.response_body.len();
+                })
+            },
+            Err(e) => Err(HtrsError::new(format!("Failed to read response body: {}", e).as_str())),
+        };
+        exchange.receive = timer.elapsed() - exchange.wait;
+        (exchange, read)
+    }
+
+    /// Reads the body as it arrives, passing the text of each chunk to `on_text` until it returns
+    /// false. The body is only kept on the exchange when `keep_body` is set, so that long streams &
+    /// large downloads aren't held in memory. With an `idle_timeout`, reading stops once nothing
+    /// has been received for that long
+    pub fn stream_body(self, idle_timeout: Option<Duration>, keep_body: bool, mut on_text: impl FnMut(&str) -> bool) -> Result<(Exchange, StreamEnd), HtrsError> {
+        let PendingResponse { mut exchange, mut response, timer } = self;
+        let mut decoder = get_response_encoding(response.headers()).new_decoder();
+        let mut decompressor = Decompressor::new(response.headers());
+
+        // Reads on another thread so that waiting for the next chunk can time out
+        let (sender, receiver) = mpsc::sync_channel::<io::Result<Vec<u8>>>(16);
+        thread::spawn(move || {
+            let mut buffer = [0; 8192];
+            loop {
+                let chunk = match response.read(&mut buffer) {
+                    Ok(0) => return,
+                    Ok(read) => Ok(buffer[..read].to_vec()),
+                    Err(e) => Err(e),
+                };
+                let failed = chunk.is_err();
+                if sender.send(chunk).is_err() || failed {
+                    return;
+                }
+            }
+        });
+
+        let mut end = StreamEnd::Finished;
+        let mut text = String::new();
+        loop {
+            let received = match idle_timeout {
+                Some(idle_timeout) => receiver.recv_timeout(idle_timeout),
+                None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+            let (bytes, last) = match received {
+                Ok(Ok(bytes)) => (bytes, false),
+                Ok(Err(e)) => return Err(HtrsError::new(format!("Failed to read response body: {}", e).as_str())),
+                Err(RecvTimeoutError::Disconnected) => (vec![], true),
+                Err(RecvTimeoutError::Timeout) => {
+                    end = StreamEnd::Idle;
+                    (vec![], true)
+                },
+            };
+
+            exchange.response_size += bytes.len();
+            let bytes = decompressor.decompress(&bytes, last)?;
+            text.clear();
+            text.reserve(decoder.max_utf8_buffer_length(bytes.len()).unwrap_or(bytes.len() * 3 + 16));
+            let _ = decoder.decode_to_string(&bytes, &mut text, last);
+            exchange.decoded_size += text.len();
+            if keep_body {
+                exchange.response_body.push_str(&text);
+            }
+
+            if !text.is_empty() && !on_text(&text) {
+                end = StreamEnd::Stopped;
+                break;
+            }
+            if last {
+                break;
+            }
+        }
+
+        exchange.receive = timer.elapsed() - exchange.wait;
+        Ok((exchange, end))
+    }
+}
+
+/// Decompresses a response body sent with a `gzip` or `deflate` content encoding, any other
+/// encoding is left as it was received
+enum Decompressor {
+    Identity,
+    Gzip(GzDecoder<Vec<u8>>),
+    Deflate(ZlibDecoder<Vec<u8>>),
+}
+
+impl Decompressor {
+    fn new(headers: &HeaderMap) -> Decompressor {
+        let content_encoding = headers.get(CONTENT_ENCODING)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim().to_ascii_lowercase());
+        match content_encoding.as_deref() {
+            Some("gzip") | Some("x-gzip") => Decompressor::Gzip(GzDecoder::new(vec![])),
+            Some("deflate") => Decompressor::Deflate(ZlibDecoder::new(vec![])),
+            _ => Decompressor::Identity,
+        }
+    }
+
+    /// Decompresses the next chunk of the body, `last` should be set for the final chunk so
+    /// that the rest of the output is flushed
+    fn decompress(&mut self, bytes: &[u8], last: bool) -> Result<Vec<u8>, HtrsError> {
+        let decompressed = match self {
+            Decompressor::Identity => return Ok(bytes.to_vec()),
+            Decompressor::Gzip(decoder) => decoder.write_all(bytes)
+                .and_then(|_| if last { decoder.try_finish() } else { Ok(()) })
+                .map(|_| mem::take(decoder.get_mut())),
+            Decompressor::Deflate(decoder) => decoder.write_all(bytes)
+                .and_then(|_| if last { decoder.try_finish() } else { Ok(()) })
+                .map(|_| mem::take(decoder.get_mut())),
+        };
+        decompressed.map_err(|e| HtrsError::new(format!("Failed to decompress response body: {}", e).as_str()))
+    }
+}
+
+/// Gets the charset from the response's content type, defaulting to utf-8 as reqwest does
+fn get_response_encoding(headers: &HeaderMap) -> &'static Encoding {
+    headers.get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';')
+            .filter_map(|param| param.trim().split_once('='))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("charset"))
+            .map(|(_, charset)| charset.trim().trim_matches('"').to_string()))
+        .and_then(|charset| Encoding::for_label(charset.as_bytes()))
+        .unwrap_or(UTF_8)
+}
+
+fn header_map_to_vec(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers.iter()
+        .map(|(name, value)| (name.to_string(), String::from_utf8_lossy(value.as_bytes()).to_string()))
+        .collect()
+}
+
+/// Replaces the values of any headers named in `secret_headers` (ignoring case) so that they can be
+/// safely written to disk
+pub fn redact_headers(headers: &[(String, String)], secret_headers: &[String]) -> Vec<(String, String)> {
+    headers.iter()
+        .map(|(name, value)| match secret_headers.iter().any(|s| s.eq_ignore_ascii_case(name)) {
+            true => (name.clone(), REDACTED.to_string()),
+            false => (name.clone(), value.clone()),
+        })
+        .collect()
+}
@@ -0,0 +1,212 @@
+use crate::commands::RootCommand;
+use crate::common::split_words;
+use crate::completions::get_completions;
+use crate::config::current_config::HtrsConfig;
+use crate::handle_action;
+use crate::outcomes::HtrsError;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+
+const HISTORY_EXTENSION: &str = "shell_history";
+const SHELL_COMMANDS: [&str; 5] = ["use", "env", "help", "exit", "quit"];
+
+const SHELL_HELP: &str = "Commands:
+ - use <service>      Call endpoints of a service by name, `use` alone stops using the service
+ - env <environment>  Call endpoints in an environment of the service, `env` alone uses the default
+ - <endpoint> [args]  Call an endpoint of the service in use
+ - <command> [args]   Run any other htrs command, e.g. `list service`
+ - exit               Leave the shell";
+
+/// State of an interactive shell session, commands are run against the config held in memory and
+/// any changes are saved as they're made
+struct ShellSession {
+    config: HtrsConfig,
+    service: Option<String>,
+    environment: Option<String>,
+}
+
+/// Runs an interactive session reading htrs commands from the terminal until exited
+pub fn run_shell(config: HtrsConfig) -> Result<(), HtrsError> {
+    let mut editor: Editor<ShellSession, DefaultHistory> = match Editor::new() {
+        Ok(editor) => editor,
+        Err(e) => return Err(HtrsError::new(format!("Failed to start shell: {}", e).as_str())),
+    };
+    editor.set_helper(Some(ShellSession {
+        config,
+        service: None,
+        environment: None,
+    }));
+
+    let history_path = HtrsConfig::get_data_file_path(HISTORY_EXTENSION).ok();
+    if let Some(path) = &history_path {
+        let _ = editor.load_history(path);
+    }
+
+    loop {
+        let prompt = editor.helper().unwrap().get_prompt();
+        match editor.readline(&prompt) {
+            Ok(line) => {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let _ = editor.add_history_entry(line.as_str());
+                match editor.helper_mut().unwrap().execute_line(&line) {
+                    Ok(true) => break,
+                    Ok(false) => {},
+                    Err(e) => println!("{}", e.details),
+                }
+            },
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(HtrsError::new(format!("Failed to read input: {}", e).as_str())),
+        }
+    }
+
+    if let Some(path) = &history_path && let Err(e) = editor.save_history(path) {
+        return Err(HtrsError::new(format!("Failed to save shell history to `{}`: {}", path.display(), e).as_str()));
+    }
+    Ok(())
+}
+
+impl ShellSession {
+    fn get_prompt(&self) -> String {
+        match (&self.service, &self.environment) {
+            (Some(service), Some(environment)) => format!("htrs ({}@{})> ", service, environment),
+            (Some(service), None) => format!("htrs ({})> ", service),
+            _ => "htrs> ".to_string(),
+        }
+    }
+
+    /// Executes a line of input, returning whether the session should end
+    fn execute_line(&mut self, line: &str) -> Result<bool, HtrsError> {
+        let words = split_words(line)?;
+        match words.iter().map(|w| w.as_str()).collect::<Vec<&str>>().as_slice() {
+            ["exit" | "quit"] => return Ok(true),
+            ["help"] => {
+                println!("{}", SHELL_HELP);
+                return Ok(false);
+            },
+            ["use"] => {
+                self.service = None;
+                self.environment = None;
+                return Ok(false);
+            },
+            ["use", service_name] => {
+                let Some(service) = self.config.get_service(service_name) else {
+                    return Err(HtrsError::new(format!("No service could be found with name or alias `{}`", service_name).as_str()));
+                };
+                self.service = Some(service.name.clone());
+                self.environment = None;
+                return Ok(false);
+            },
+            ["env", rest @ ..] => {
+                let Some(service) = self.service.as_ref().and_then(|s| self.config.get_service(s)) else {
+                    return Err(HtrsError::new("No service in use, select one with `use <service>`"));
+                };
+                self.environment = match rest {
+                    [] => None,
+                    [environment_name] => match service.get_environment(environment_name) {
+                        Some(environment) => Some(environment.name.clone()),
+                        None => return Err(HtrsError::new(format!("Unable to find environment with name or alias `{}` for service `{}`", environment_name, service.name).as_str())),
+                    },
+                    _ => return Err(HtrsError::new("Usage: env <environment>")),
+                };
+                return Ok(false);
+            },
+            ["shell", ..] => return Err(HtrsError::new("Already in a shell session")),
+            _ => {},
+        }
+
+        let matches = match RootCommand::get_command(&self.config).try_get_matches_from(self.get_args(words)) {
+            Ok(matches) => matches,
+            Err(e) => {
+                let _ = e.print();
+                return Ok(false);
+            },
+        };
+        let command = match RootCommand::bind_from_matches(&matches, &self.config) {
+            Ok(command) => command,
+            Err(e) => return Err(HtrsError::new(format!("Command Binding Failed: {e}").as_str())),
+        };
+
+        let mut config = self.config.clone();
+        let action = command.execute(&mut config)?;
+        handle_action(action, config.clone())?;
+        self.config = config;
+        Ok(false)
+    }
+
+    /// Converts the words of a line into the arguments of a htrs command, lines starting with an
+    /// endpoint of the service in use call that endpoint in the selected environment
+    fn get_args(&self, words: Vec<String>) -> Vec<String> {
+        let mut args = vec!["htrs".to_string()];
+        let service = self.service.as_ref().and_then(|s| self.config.get_service(s));
+        let (service, endpoint, endpoint_args) = match (service, words.split_first()) {
+            (Some(service), Some((endpoint, endpoint_args))) if service.get_endpoint(endpoint).is_some() => (service, endpoint, endpoint_args),
+            _ => {
+                args.extend(words);
+                return args;
+            },
+        };
+
+        args.extend(["call".to_string(), service.name.clone(), endpoint.clone()]);
+        let has_environment = endpoint_args.iter()
+            .any(|a| a == "-e" || a == "--environment" || a.starts_with("--environment="));
+        if let Some(environment) = &self.environment && !has_environment {
+            args.extend(["--environment".to_string(), environment.clone()]);
+        }
+        args.extend(endpoint_args.iter().cloned());
+        args
+    }
+}
+
+impl Completer for ShellSession {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> rustyline::Result<(usize, Vec<String>)> {
+        let line = &line[..pos];
+        let start = line.rfind(char::is_whitespace).map(|i| i + 1).unwrap_or(0);
+        let Ok(mut words) = split_words(line) else {
+            return Ok((start, vec![]));
+        };
+        if line.is_empty() || line.ends_with(char::is_whitespace) {
+            words.push(String::new());
+        }
+
+        let service = self.service.as_ref().and_then(|s| self.config.get_service(s));
+        let candidates: Vec<String> = match words.iter().map(|w| w.as_str()).collect::<Vec<&str>>().as_slice() {
+            [current] => SHELL_COMMANDS.iter()
+                .map(|c| c.to_string())
+                .chain(service.iter().flat_map(|s| s.endpoints.iter().flat_map(|e| std::iter::once(e.name.clone()).chain(e.alias.clone()))))
+                .chain(get_completions(RootCommand::get_command(&self.config), &self.config, &words))
+                .filter(|c| c.starts_with(current))
+                .collect(),
+            ["use", current] => self.config.services.iter()
+                .map(|s| s.name.clone())
+                .filter(|s| s.starts_with(current))
+                .collect(),
+            ["env", current] => service.iter()
+                .flat_map(|s| s.environments.iter().map(|e| e.name.clone()))
+                .filter(|e| e.starts_with(current))
+                .collect(),
+            _ => get_completions(RootCommand::get_command(&self.config), &self.config, &self.get_args(words)[1..]),
+        };
+
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for ShellSession {
+    type Hint = String;
+}
+
+impl Highlighter for ShellSession {}
+
+impl Validator for ShellSession {}
+
+impl Helper for ShellSession {}
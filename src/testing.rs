@@ -0,0 +1,336 @@
+use crate::config::current_config::Assertion;
+use crate::formats::har::HarCapture;
+use crate::json_path::{parse_path, select};
+use crate::outcomes::HtrsError;
+use crate::request::{send_request, Exchange, PreparedRequest};
+use regex::Regex;
+use reqwest::blocking::Client;
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// A test case that has been resolved against an environment, the request is an error where it
+/// couldn't be resolved so that the failure is reported alongside the other results
+pub struct PreparedTest {
+    pub service: String,
+    pub name: String,
+    pub request: Result<PreparedRequest, HtrsError>,
+    pub assertions: Vec<Assertion>,
+}
+
+/// The test cases to run, along with where to write a JUnit report of the results
+pub struct TestRun {
+    pub tests: Vec<PreparedTest>,
+    pub junit: Option<PathBuf>,
+    pub har: Option<HarCapture>,
+}
+
+enum Outcome {
+    Passed,
+    Failed(Vec<String>),
+    Errored(String),
+}
+
+struct TestResult<'a> {
+    test: &'a PreparedTest,
+    outcome: Outcome,
+    duration: Duration,
+}
+
+impl TestRun {
+    /// Sends the request for each test & checks its assertions, failing if any test doesn't pass
+    pub fn run(&self) -> Result<(), HtrsError> {
+        let client = Client::new();
+        let mut results = vec![];
+        let mut exchanges = vec![];
+        for test in &self.tests {
+            let result = run_test(&client, test, &mut exchanges);
+            match &result.outcome {
+                Outcome::Passed => println!(" PASS {} {} ({}ms)", test.service, test.name, result.duration.as_millis()),
+                Outcome::Failed(failures) => {
+                    println!(" FAIL {} {} ({}ms)", test.service, test.name, result.duration.as_millis());
+                    for failure in failures {
+                        println!("   - {}", failure);
+                    }
+                },
+                Outcome::Errored(error) => {
+                    println!(" ERROR {} {}", test.service, test.name);
+                    println!("   - {}", error);
+                },
+            }
+            results.push(result);
+        }
+
+        if let Some(path) = &self.junit {
+            if let Err(e) = fs::write(path, junit_report(&results)) {
+                return Err(HtrsError::new(format!("Failed to write file `{}`: {e}", path.display()).as_str()));
+            }
+            println!("Wrote {}", path.display());
+        }
+        if let Some(har) = &self.har {
+            har.write(&exchanges)?;
+        }
+
+        let failed = results.iter().filter(|r| !matches!(r.outcome, Outcome::Passed)).count();
+        let summary = format!("{}, {} passed, {} failed", count_tests(results.len()), results.len() - failed, failed);
+        match failed {
+            0 => {
+                println!("{}", summary);
+                Ok(())
+            },
+            _ => Err(HtrsError::new(summary.as_str())),
+        }
+    }
+}
+
+fn count_tests(count: usize) -> String {
+    match count {
+        1 => "1 test".to_string(),
+        n => format!("{} tests", n),
+    }
+}
+
+fn run_test<'a>(client: &Client, test: &'a PreparedTest, exchanges: &mut Vec<Exchange>) -> TestResult<'a> {
+    let errored = |error: &HtrsError| TestResult {
+        test,
+        outcome: Outcome::Errored(error.details.clone()),
+        duration: Duration::ZERO,
+    };
+
+    let request = match &test.request {
+        Ok(request) => request,
+        Err(e) => return errored(e),
+    };
+    let exchange = match send_request(client, &request.method, &request.url, &request.headers, request.body.as_deref()) {
+        Ok(exchange) => exchange,
+        Err(e) => return errored(&e),
+    };
+
+    let duration = exchange.total_time();
+    let body = serde_json::from_str::<Value>(&exchange.response_body).ok();
+    let failures: Vec<String> = test.assertions.iter()
+        .filter_map(|a| check_assertion(a, exchange.status.as_u16(), &exchange.response_headers, body.as_ref(), duration).err())
+        .collect();
+    exchanges.push(exchange);
+
+    TestResult {
+        test,
+        outcome: match failures.is_empty() {
+            true => Outcome::Passed,
+            false => Outcome::Failed(failures),
+        },
+        duration,
+    }
+}
+
+/// Parses an expected status as the inclusive range of codes it allows, the status can be a code
+/// like `200`, a class like `2xx` or a range like `200-299`
+pub fn parse_status_range(expected: &str) -> Option<(u16, u16)> {
+    if let Some((start, end)) = expected.split_once('-') {
+        let (start, end) = (start.trim().parse::<u16>().ok()?, end.trim().parse::<u16>().ok()?);
+        return (start <= end).then_some((start, end));
+    }
+    if let Some(class) = expected.to_lowercase().strip_suffix("xx") {
+        let class = class.parse::<u16>().ok().filter(|c| (1..=5).contains(c))?;
+        return Some((class * 100, class * 100 + 99));
+    }
+    let code = expected.parse::<u16>().ok()?;
+    Some((code, code))
+}
+
+pub fn validate_status(expected: &str) -> Result<(), HtrsError> {
+    match parse_status_range(expected) {
+        Some(_) => Ok(()),
+        None => Err(HtrsError::new(format!("Invalid status `{}`, should be a code like `200`, a class like `2xx` or a range like `200-299`", expected).as_str())),
+    }
+}
+
+/// Checks a response against an assertion, giving the reason it failed if it doesn't hold
+pub fn check_assertion(
+    assertion: &Assertion,
+    status: u16,
+    headers: &[(String, String)],
+    body: Option<&Value>,
+    latency: Duration,
+) -> Result<(), String> {
+    match assertion {
+        Assertion::Status { expected } => match parse_status_range(expected) {
+            Some((start, end)) if (start..=end).contains(&status) => Ok(()),
+            Some(_) => Err(format!("Expected status `{}` but was {}", expected, status)),
+            None => Err(format!("Invalid expected status `{}`", expected)),
+        },
+        Assertion::Header { name, value } => {
+            let actual = headers.iter()
+                .find(|(header, _)| header.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value);
+            match (actual, value) {
+                (None, _) => Err(format!("Expected header `{}` to be present", name)),
+                (Some(actual), Some(value)) if actual != value => Err(format!("Expected header `{}` to be `{}` but was `{}`", name, value, actual)),
+                _ => Ok(()),
+            }
+        },
+        Assertion::JsonEquals { path, value } => match select_json(body, path)? {
+            Some(actual) if actual == value => Ok(()),
+            Some(actual) => Err(format!("Expected json `{}` to be `{}` but was `{}`", path, value, actual)),
+            None => Err(format!("Expected json `{}` to be `{}` but it was missing", path, value)),
+        },
+        Assertion::JsonExists { path } => match select_json(body, path)? {
+            Some(_) => Ok(()),
+            None => Err(format!("Expected json `{}` to exist", path)),
+        },
+        Assertion::JsonMatches { path, pattern } => {
+            let regex = match Regex::new(pattern) {
+                Ok(regex) => regex,
+                Err(e) => return Err(format!("Invalid pattern `{}`: {}", pattern, e)),
+            };
+            let actual = match select_json(body, path)? {
+                Some(Value::String(actual)) => actual.clone(),
+                Some(actual) => actual.to_string(),
+                None => return Err(format!("Expected json `{}` to match `{}` but it was missing", path, pattern)),
+            };
+            match regex.is_match(&actual) {
+                true => Ok(()),
+                false => Err(format!("Expected json `{}` to match `{}` but was `{}`", path, pattern, actual)),
+            }
+        },
+        Assertion::MaxLatency { milliseconds } => match latency.as_millis() <= *milliseconds as u128 {
+            true => Ok(()),
+            false => Err(format!("Expected a response within {}ms but took {}ms", milliseconds, latency.as_millis())),
+        },
+    }
+}
+
+fn select_json<'a>(body: Option<&'a Value>, path: &str) -> Result<Option<&'a Value>, String> {
+    match body {
+        Some(body) => Ok(select(body, &parse_path(path))),
+        None => Err(format!("Expected a json body to check `{}`", path)),
+    }
+}
+
+fn junit_report(results: &[TestResult]) -> String {
+    let mut services: Vec<&str> = vec![];
+    for result in results {
+        if !services.contains(&result.test.service.as_str()) {
+            services.push(&result.test.service);
+        }
+    }
+
+    let total_time: Duration = results.iter().map(|r| r.duration).sum();
+    let mut report = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    report += &format!(
+        "<testsuites name=\"htrs\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{:.3}\">\n",
+        results.len(),
+        results.iter().filter(|r| matches!(r.outcome, Outcome::Failed(_))).count(),
+        results.iter().filter(|r| matches!(r.outcome, Outcome::Errored(_))).count(),
+        total_time.as_secs_f64(),
+    );
+
+    for service in services {
+        let service_results: Vec<&TestResult> = results.iter().filter(|r| r.test.service == service).collect();
+        let service_time: Duration = service_results.iter().map(|r| r.duration).sum();
+        report += &format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{:.3}\">\n",
+            escape_xml(service),
+            service_results.len(),
+            service_results.iter().filter(|r| matches!(r.outcome, Outcome::Failed(_))).count(),
+            service_results.iter().filter(|r| matches!(r.outcome, Outcome::Errored(_))).count(),
+            service_time.as_secs_f64(),
+        );
+        for result in service_results {
+            let test_case = format!(
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+                escape_xml(&result.test.name),
+                escape_xml(service),
+                result.duration.as_secs_f64(),
+            );
+            report += &match &result.outcome {
+                Outcome::Passed => format!("{} />\n", test_case),
+                Outcome::Failed(failures) => format!(
+                    "{}>\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                    test_case,
+                    escape_xml(&failures[0]),
+                    escape_xml(&failures.join("\n")),
+                ),
+                Outcome::Errored(error) => format!(
+                    "{}>\n      <error message=\"{}\" />\n    </testcase>\n",
+                    test_case,
+                    escape_xml(error),
+                ),
+            };
+        }
+        report += "  </testsuite>\n";
+    }
+
+    report += "</testsuites>\n";
+    report
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod testing_tests {
+    use crate::config::current_config::Assertion;
+    use crate::testing::{check_assertion, parse_status_range};
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn given_status_code_class_and_range_when_parse_then_should_get_allowed_codes() {
+        assert_eq!(parse_status_range("201"), Some((201, 201)));
+        assert_eq!(parse_status_range("2xx"), Some((200, 299)));
+        assert_eq!(parse_status_range("200-204"), Some((200, 204)));
+        assert_eq!(parse_status_range("204-200"), None);
+        assert_eq!(parse_status_range("9xx"), None);
+        assert_eq!(parse_status_range("ok"), None);
+    }
+
+    #[test]
+    fn given_json_assertions_when_check_then_should_report_failures() {
+        let body = json!({"id": 1, "name": "foo", "items": [{"id": 2}]});
+        let check = |assertion: Assertion| check_assertion(&assertion, 200, &[], Some(&body), Duration::ZERO);
+
+        assert!(check(Assertion::JsonEquals { path: "$.items[0].id".to_string(), value: json!(2) }).is_ok());
+        assert!(check(Assertion::JsonExists { path: "name".to_string() }).is_ok());
+        assert!(check(Assertion::JsonMatches { path: "$.name".to_string(), pattern: "^f".to_string() }).is_ok());
+        assert_eq!(
+            check(Assertion::JsonEquals { path: "$.id".to_string(), value: json!(2) }),
+            Err("Expected json `$.id` to be `2` but was `1`".to_string()),
+        );
+        assert_eq!(
+            check(Assertion::JsonExists { path: "$.missing".to_string() }),
+            Err("Expected json `$.missing` to exist".to_string()),
+        );
+    }
+
+    #[test]
+    fn given_response_assertions_when_check_then_should_report_failures() {
+        let headers = vec![("content-type".to_string(), "application/json".to_string())];
+        let check = |assertion: Assertion| check_assertion(&assertion, 404, &headers, None, Duration::from_millis(150));
+
+        assert!(check(Assertion::Status { expected: "4xx".to_string() }).is_ok());
+        assert!(check(Assertion::Header { name: "Content-Type".to_string(), value: Some("application/json".to_string()) }).is_ok());
+        assert_eq!(
+            check(Assertion::Status { expected: "200".to_string() }),
+            Err("Expected status `200` but was 404".to_string()),
+        );
+        assert_eq!(
+            check(Assertion::Header { name: "X-Request-Id".to_string(), value: None }),
+            Err("Expected header `X-Request-Id` to be present".to_string()),
+        );
+        assert_eq!(
+            check(Assertion::MaxLatency { milliseconds: 100 }),
+            Err("Expected a response within 100ms but took 150ms".to_string()),
+        );
+        assert_eq!(
+            check(Assertion::JsonExists { path: "$.id".to_string() }),
+            Err("Expected a json body to check `$.id`".to_string()),
+        );
+    }
+}
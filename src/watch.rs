@@ -0,0 +1,67 @@
+use crate::config::current_config::Assertion;
+use crate::formats::har::HarCapture;
+use crate::outcomes::HtrsError;
+use crate::request::{send_request, PreparedRequest};
+use crate::testing::check_assertion;
+use reqwest::blocking::Client;
+use serde_json::Value;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Sends a request repeatedly until all of the conditions hold, or until `max_wait` has passed
+pub struct Watch {
+    pub request: PreparedRequest,
+    pub interval: Duration,
+    pub conditions: Vec<Assertion>,
+    pub max_wait: Option<Duration>,
+    pub show_body: bool,
+    pub har: Option<HarCapture>,
+}
+
+impl Watch {
+    /// Prints the response whenever it differs from the previous poll. Without conditions the
+    /// request is polled until stopped, or until `max_wait` has passed
+    pub fn run(&self) -> Result<(), HtrsError> {
+        let client = Client::new();
+        let started = Instant::now();
+        let mut previous: Option<(u16, String)> = None;
+        let mut polls = 0;
+        let mut exchanges = vec![];
+
+        loop {
+            let request = &self.request;
+            let exchange = send_request(&client, &request.method, &request.url, &request.headers, request.body.as_deref())?;
+            polls += 1;
+
+            let current = (exchange.status.as_u16(), exchange.response_body.clone());
+            if previous.as_ref() != Some(&current) {
+                if !self.show_body {
+                    println!("{} | {} | {}", exchange.status, request.method, request.url);
+                }
+                println!("{}", exchange.response_body);
+                previous = Some(current);
+            }
+
+            let body = serde_json::from_str::<Value>(&exchange.response_body).ok();
+            let condition_met = !self.conditions.is_empty() && self.conditions.iter()
+                .all(|c| check_assertion(c, exchange.status.as_u16(), &exchange.response_headers, body.as_ref(), exchange.total_time()).is_ok());
+            // Written after every poll, as watching is often stopped with Ctrl-C
+            if let Some(har) = &self.har {
+                exchanges.push(exchange);
+                har.write(&exchanges)?;
+            }
+            if condition_met {
+                eprintln!("Condition met after {} polls", polls);
+                return Ok(());
+            }
+
+            if let Some(max_wait) = self.max_wait && started.elapsed() + self.interval > max_wait {
+                return match self.conditions.is_empty() {
+                    true => Ok(()),
+                    false => Err(HtrsError::new(format!("Condition not met after {} polls in {}", polls, humantime::format_duration(max_wait)).as_str())),
+                };
+            }
+            thread::sleep(self.interval);
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::commands::call_command::CallServiceEndpointCommand;
+use crate::config::current_config::{Assertion, Extraction, HtrsConfig, Workflow, WorkflowStep};
+use crate::formats::har::HarCapture;
+use crate::json_path::{parse_path, select};
+use crate::outcomes::HtrsError;
+use crate::request::{send_request, Exchange};
+use crate::testing::check_assertion;
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use reqwest::blocking::Client;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Status a step's response must have when the step doesn't give its own
+pub const DEFAULT_EXPECTED_STATUS: &str = "2xx";
+
+lazy_static! {
+    static ref VALUE_REFERENCE: Regex = Regex::new(r"\{\{\s*([A-Za-z0-9_-]+)\s*}}").unwrap();
+    static ref VALUE_NAME: Regex = Regex::new(r"^[A-Za-z0-9_-]+$").unwrap();
+}
+
+/// A workflow to run against an environment, each service's default environment is used when
+/// no environment is given
+pub struct WorkflowRun {
+    pub workflow: Workflow,
+    pub environment: Option<String>,
+    pub show_body: bool,
+    pub har: Option<HarCapture>,
+}
+
+impl WorkflowRun {
+    /// Runs each step in order, stopping at the first step that fails
+    pub fn run(&self, config: &HtrsConfig) -> Result<(), HtrsError> {
+        let mut exchanges = vec![];
+        let result = self.run_steps(config, &mut exchanges);
+        // Steps up to & including the one that failed are recorded
+        if let Some(har) = &self.har {
+            har.write(&exchanges)?;
+        }
+        result
+    }
+
+    fn run_steps(&self, config: &HtrsConfig, exchanges: &mut Vec<Exchange>) -> Result<(), HtrsError> {
+        let client = Client::new();
+        let mut values: HashMap<String, String> = HashMap::new();
+        let step_count = self.workflow.steps.len();
+
+        for (index, step) in self.workflow.steps.iter().enumerate() {
+            let fail = |reason: &str| HtrsError::new(format!("Workflow `{}` failed at step `{}`: {}", self.workflow.name, step.name, reason).as_str());
+
+            let mut parameters = HashMap::new();
+            for (name, value) in &step.parameters {
+                parameters.insert(name.clone(), substitute_values(value, &values).map_err(|e| fail(&e))?);
+            }
+            let call = CallServiceEndpointCommand::new(
+                step.service.clone(),
+                self.environment.clone(),
+                step.endpoint.clone(),
+                parameters,
+                step.preset.clone(),
+            );
+            let request = call.prepare_request(config).map_err(|e| fail(&e.details))?;
+            let exchange = send_request(&client, &request.method, &request.url, &request.headers, request.body.as_deref())
+                .map_err(|e| fail(&e.details))?;
+            exchanges.push(exchange);
+            let exchange = exchanges.last().unwrap();
+
+            println!(
+                "[{}/{}] {}: {} {} -> {} ({}ms)",
+                index + 1,
+                step_count,
+                step.name,
+                exchange.method,
+                exchange.url,
+                exchange.status.as_u16(),
+                exchange.total_time().as_millis(),
+            );
+
+            let expected_status = Assertion::Status {
+                expected: step.expected_status.clone().unwrap_or(DEFAULT_EXPECTED_STATUS.to_string()),
+            };
+            if let Err(e) = check_assertion(&expected_status, exchange.status.as_u16(), &[], None, exchange.total_time()) {
+                println!("{}", exchange.response_body);
+                return Err(fail(&e));
+            }
+            if self.show_body {
+                println!("{}", exchange.response_body);
+            }
+
+            let body = serde_json::from_str::<Value>(&exchange.response_body).ok();
+            for extraction in &step.extractions {
+                let (name, value) = extract_value(extraction, &exchange.response_headers, body.as_ref()).map_err(|e| fail(&e))?;
+                println!("   {} = {}", name, value);
+                values.insert(name.to_string(), value);
+            }
+        }
+
+        println!("Workflow `{}` completed {} steps", self.workflow.name, step_count);
+        Ok(())
+    }
+}
+
+/// Gets the names of the values referenced as `{{name}}` in the text
+pub fn get_value_references(text: &str) -> Vec<String> {
+    VALUE_REFERENCE.captures_iter(text)
+        .map(|c| c[1].to_string())
+        .collect()
+}
+
+/// Replaces each `{{name}}` in the text with the value of that name
+pub fn substitute_values(text: &str, values: &HashMap<String, String>) -> Result<String, String> {
+    let mut missing = None;
+    let substituted = VALUE_REFERENCE.replace_all(text, |captures: &Captures| match values.get(&captures[1]) {
+        Some(value) => value.clone(),
+        None => {
+            missing.get_or_insert_with(|| captures[1].to_string());
+            String::new()
+        },
+    });
+    match missing {
+        Some(name) => Err(format!("No value named `{}` has been taken from an earlier step", name)),
+        None => Ok(substituted.to_string()),
+    }
+}
+
+/// Takes a value from a response, json values that aren't strings are kept as json text
+pub fn extract_value<'a>(extraction: &'a Extraction, headers: &[(String, String)], body: Option<&Value>) -> Result<(&'a str, String), String> {
+    match extraction {
+        Extraction::Json { name, path } => {
+            let Some(body) = body else {
+                return Err(format!("Response body isn't json so `{}` can't be taken from `{}`", name, path));
+            };
+            match select(body, &parse_path(path)) {
+                Some(Value::String(value)) => Ok((name, value.clone())),
+                Some(value) => Ok((name, value.to_string())),
+                None => Err(format!("No value found at json `{}` for `{}`", path, name)),
+            }
+        },
+        Extraction::Header { name, header } => headers.iter()
+            .find(|(h, _)| h.eq_ignore_ascii_case(header))
+            .map(|(_, value)| (name.as_str(), value.clone()))
+            .ok_or(format!("No header `{}` in response for `{}`", header, name)),
+    }
+}
+
+/// Parses a value to take from a step's response, in the format `name=$.json.path` or
+/// `name=header:Header-Name`
+pub fn parse_extraction(value: &str) -> Result<Extraction, HtrsError> {
+    let invalid = || HtrsError::new(format!("Invalid extraction `{}`, should be in format `name=$.json.path` or `name=header:Header-Name`", value).as_str());
+    let Some((name, source)) = value.split_once('=') else {
+        return Err(invalid());
+    };
+    if source.is_empty() || !VALUE_NAME.is_match(name) {
+        return Err(invalid());
+    }
+
+    match source.strip_prefix("header:") {
+        Some("") => Err(invalid()),
+        Some(header) => Ok(Extraction::Header { name: name.to_string(), header: header.to_string() }),
+        None => Ok(Extraction::Json { name: name.to_string(), path: source.to_string() }),
+    }
+}
+
+/// Names of the values taken from the responses of the given steps
+pub fn get_extracted_names(steps: &[WorkflowStep]) -> Vec<&str> {
+    steps.iter()
+        .flat_map(|s| &s.extractions)
+        .map(|e| match e {
+            Extraction::Json { name, .. } | Extraction::Header { name, .. } => name.as_str(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod workflow_tests {
+    use crate::config::current_config::Extraction;
+    use crate::workflow::{extract_value, parse_extraction, substitute_values};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn given_references_when_substitute_values_then_should_replace_each() {
+        let values = HashMap::from([("order_id".to_string(), "42".to_string())]);
+
+        assert_eq!(substitute_values("{{order_id}}", &values), Ok("42".to_string()));
+        assert_eq!(substitute_values("id-{{ order_id }}-x", &values), Ok("id-42-x".to_string()));
+        assert_eq!(substitute_values("plain", &values), Ok("plain".to_string()));
+        assert_eq!(
+            substitute_values("{{user_id}}", &values),
+            Err("No value named `user_id` has been taken from an earlier step".to_string()),
+        );
+    }
+
+    #[test]
+    fn given_extractions_when_parse_then_should_read_json_and_header_sources() {
+        assert_eq!(
+            parse_extraction("id=$.items[0].id").ok(),
+            Some(Extraction::Json { name: "id".to_string(), path: "$.items[0].id".to_string() }),
+        );
+        assert_eq!(
+            parse_extraction("location=header:Location").ok(),
+            Some(Extraction::Header { name: "location".to_string(), header: "Location".to_string() }),
+        );
+        assert!(parse_extraction("id").is_err());
+        assert!(parse_extraction("bad name=$.id").is_err());
+        assert!(parse_extraction("location=header:").is_err());
+    }
+
+    #[test]
+    fn given_response_when_extract_value_then_should_take_value() {
+        let body = json!({"id": 42, "status": "open"});
+        let headers = vec![("location".to_string(), "/orders/42".to_string())];
+        let id = Extraction::Json { name: "id".to_string(), path: "$.id".to_string() };
+        let status = Extraction::Json { name: "status".to_string(), path: "status".to_string() };
+        let location = Extraction::Header { name: "location".to_string(), header: "Location".to_string() };
+
+        assert_eq!(extract_value(&id, &headers, Some(&body)), Ok(("id", "42".to_string())));
+        assert_eq!(extract_value(&status, &headers, Some(&body)), Ok(("status", "open".to_string())));
+        assert_eq!(extract_value(&location, &headers, Some(&body)), Ok(("location", "/orders/42".to_string())));
+        assert_eq!(
+            extract_value(&id, &headers, None),
+            Err("Response body isn't json so `id` can't be taken from `$.id`".to_string()),
+        );
+    }
+}
@@ -0,0 +1,186 @@
+mod call_command_benchmark_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::config::HtrsConfig;
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use httptest::matchers::request;
+    use httptest::responders::status_code;
+    use httptest::{all_of, Expectation, ServerPool};
+    use predicates::prelude::*;
+    use std::error::Error;
+    use std::fs::File;
+    use uuid::Uuid;
+
+    static SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    fn build_config(host: &str) -> HtrsConfig {
+        HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(host)
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/my/path")
+                    )
+            )
+            .build()
+    }
+
+    #[test]
+    fn given_repeat_when_call_then_should_send_request_repeatedly_and_report() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("GET"),
+                request::path("/my/path"),
+            ]).times(5).respond_with(status_code(200)),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str())));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--repeat")
+            .arg("5")
+            .arg("--concurrency")
+            .arg("2")
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with(format!("GET http://{}/my/path\nRequests: 5 (5 succeeded, 0 failed) in ", server.addr())))
+            .stdout(predicate::str::contains("Statuses:\n - 200: 5\n"))
+            .stdout(predicate::str::is_match(r"Latency:\n - min: [\d.]+ms\n - mean: [\d.]+ms\n - p50: [\d.]+ms\n - p90: [\d.]+ms\n - p99: [\d.]+ms\n - max: [\d.]+ms\n$")?);
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_json_report_when_call_then_should_print_report_as_json() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/my/path"))
+                .times(3)
+                .respond_with(status_code(503)),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str())));
+
+        let output = Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--repeat")
+            .arg("3")
+            .arg("--json-report")
+            .output()?;
+
+        assert!(output.status.success());
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        assert_eq!(report["requests"], 3);
+        assert_eq!(report["succeeded"], 0);
+        assert_eq!(report["failed"], 3);
+        assert_eq!(report["statuses"]["503"], 3);
+        assert!(report["latency_ms"]["p99"].is_number());
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_unreachable_host_when_call_with_repeat_then_should_count_errors_by_kind() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config("127.0.0.1:1")));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--repeat")
+            .arg("2")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Requests: 2 (0 succeeded, 2 failed)"))
+            .stdout(predicate::str::ends_with("Errors:\n - connect: 2\n"));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_invalid_duration_when_call_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config("foo.com")));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--duration")
+            .arg("soon")
+            .assert()
+            .failure()
+            .stdout("Invalid duration `soon`, should be in a format like `500ms` or `2s`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_concurrency_without_repeat_when_call_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config("foo.com")));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--concurrency")
+            .arg("2")
+            .assert()
+            .failure();
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_har_option_when_call_with_repeat_then_should_record_every_request() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/my/path")).times(3).respond_with(status_code(200).body("ok")),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str())));
+        let har_path = format!("{}.har", Uuid::new_v4());
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "foo_endpoint", "--repeat", "3", "--concurrency", "2", "--har", &har_path])
+            .assert()
+            .success();
+
+        let har: serde_json::Value = serde_json::from_reader(File::open(&har_path)?)?;
+        let entries = har["log"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 3);
+        for entry in entries {
+            assert_eq!(entry["request"]["url"], format!("http://{}/my/path", server.addr()));
+            assert_eq!(entry["response"]["status"], 200);
+            assert_eq!(entry["response"]["content"]["text"], "ok");
+        }
+
+        clear_config(&path);
+        clear_config(&har_path);
+        server.verify_and_clear();
+        Ok(())
+    }
+}
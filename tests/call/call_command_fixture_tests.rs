@@ -0,0 +1,133 @@
+mod call_command_fixture_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::config::HtrsConfig;
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use httptest::matchers::request;
+    use httptest::responders::status_code;
+    use httptest::{all_of, Expectation, ServerPool};
+use predicates::prelude::*;
+    use std::error::Error;
+    use std::fs;
+    use uuid::Uuid;
+
+    static SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    fn build_config(host: &str) -> HtrsConfig {
+        HtrsConfigBuilder::new()
+            .with_header("Authorization", "Bearer secret")
+            .with_secret_header("Authorization")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(host)
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_user")
+                            .with_path("/users/{id}")
+                            .with_query_param("expand", false)
+                            .with_query_param("fields", false)
+                    )
+            )
+            .build()
+    }
+
+    #[test]
+    fn given_recorded_response_when_call_with_replay_then_should_not_send_request() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("GET"),
+                request::path("/users/1"),
+            ]).times(1).respond_with(status_code(200).body("{\"id\":1}")),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str())));
+        let directory = Uuid::new_v4().to_string();
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "get_user", "--id", "1", "--expand", "orders", "--fields", "name", "--record", &directory])
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with(format!("200 OK | GET | http://{}/users/1?", server.addr())));
+        let recordings: Vec<_> = fs::read_dir(&directory)?.collect::<Result<_, _>>()?;
+        assert_eq!(recordings.len(), 1);
+        let recording = fs::read_to_string(recordings[0].path())?;
+        assert!(recording.contains("<redacted>"));
+        assert!(!recording.contains("Bearer secret"));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "get_user", "--id", "1", "--fields", "name", "--expand", "orders", "--replay", &directory, "--strict", "--body"])
+            .assert()
+            .success()
+            .stdout("{\"id\":1}\n");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["history", "show", "2"])
+            .assert()
+            .failure();
+
+        clear_config(&path);
+        fs::remove_dir_all(&directory)?;
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_unrecorded_request_when_call_with_strict_replay_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config("foo.com")));
+        let directory = Uuid::new_v4().to_string();
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "get_user", "--id", "2", "--replay", &directory, "--strict"])
+            .assert()
+            .failure()
+            .stdout(format!("No recording of `GET http://foo.com/users/2` found in `{}`\n", directory));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_unrecorded_request_when_call_with_replay_then_should_send_request() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/users/3")).respond_with(status_code(200).body("{\"id\":3}")),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str())));
+        let directory = Uuid::new_v4().to_string();
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "get_user", "--id", "3", "--replay", &directory, "--body"])
+            .assert()
+            .success()
+            .stdout("{\"id\":3}\n");
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_strict_without_replay_when_call_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config("foo.com")));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "get_user", "--id", "1", "--strict"])
+            .assert()
+            .failure();
+
+        clear_config(&path);
+        Ok(())
+    }
+}
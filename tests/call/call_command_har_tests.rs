@@ -0,0 +1,131 @@
+mod call_command_har_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::config::HtrsConfig;
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use httptest::matchers::request;
+    use httptest::responders::status_code;
+    use httptest::{all_of, Expectation, ServerPool};
+    use std::error::Error;
+    use std::fs::File;
+    use uuid::Uuid;
+
+    static SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    fn build_config(host: &str) -> HtrsConfig {
+        HtrsConfigBuilder::new()
+            .with_header("Authorization", "Bearer secret")
+            .with_secret_header("authorization")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(host)
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/my/path")
+                            .with_query_param("foo", false)
+                    )
+            )
+            .build()
+    }
+
+    #[test]
+    fn given_har_option_when_call_then_should_record_exchange() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("GET"),
+                request::path("/my/path"),
+            ]).respond_with(status_code(200).insert_header("Content-Type", "text/plain").body("hello")),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str())));
+        let har_path = format!("{}.har", Uuid::new_v4());
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--foo")
+            .arg("bar")
+            .arg("--har")
+            .arg(&har_path)
+            .assert()
+            .success();
+
+        let har: serde_json::Value = serde_json::from_reader(File::open(&har_path)?)?;
+        assert_eq!(har["log"]["version"], "1.2");
+        let entry = &har["log"]["entries"][0];
+        assert_eq!(entry["request"]["method"], "GET");
+        assert_eq!(entry["request"]["url"], format!("http://{}/my/path?foo=bar", server.addr()));
+        assert_eq!(entry["request"]["queryString"][0]["name"], "foo");
+        assert_eq!(entry["response"]["status"], 200);
+        assert_eq!(entry["response"]["content"]["mimeType"], "text/plain");
+        assert_eq!(entry["response"]["content"]["text"], "hello");
+        let authorization = entry["request"]["headers"].as_array().unwrap().iter()
+            .find(|h| h["name"] == "authorization")
+            .unwrap();
+        assert_eq!(authorization["value"], "Bearer secret");
+
+        clear_config(&path);
+        clear_config(&har_path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_redact_secrets_flag_when_call_then_should_redact_secret_headers() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/my/path")).respond_with(status_code(200)),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str())));
+        let har_path = format!("{}.har", Uuid::new_v4());
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--har")
+            .arg(&har_path)
+            .arg("--redact-secrets")
+            .assert()
+            .success();
+
+        let har: serde_json::Value = serde_json::from_reader(File::open(&har_path)?)?;
+        let authorization = har["log"]["entries"][0]["request"]["headers"].as_array().unwrap().iter()
+            .find(|h| h["name"] == "authorization")
+            .unwrap()
+            .clone();
+        assert_eq!(authorization["value"], "<redacted>");
+
+        clear_config(&path);
+        clear_config(&har_path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_redact_secrets_flag_without_har_when_call_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config("foo.com")));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--redact-secrets")
+            .assert()
+            .failure();
+
+        clear_config(&path);
+        Ok(())
+    }
+}
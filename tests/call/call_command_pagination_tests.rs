@@ -0,0 +1,237 @@
+mod call_command_pagination_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::config::{HtrsConfig, PaginationStrategy};
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use httptest::matchers::{contains, key, not, request, url_decoded};
+    use httptest::responders::status_code;
+    use httptest::{all_of, Expectation, ServerPool};
+    use std::error::Error;
+    use std::fs::File;
+    use uuid::Uuid;
+
+    static SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    fn build_config(host: &str, endpoint: EndpointBuilder) -> HtrsConfig {
+        HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(host)
+                            .with_default()
+                    )
+                    .with_endpoint(endpoint.with_name("list_items").with_path("/items"))
+            )
+            .build()
+    }
+
+    #[test]
+    fn given_cursor_pagination_with_items_when_call_all_pages_then_should_concatenate_items() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::path("/items"),
+                request::query(url_decoded(contains(("status", "open")))),
+                request::query(url_decoded(not(contains(key("cursor"))))),
+            ]).respond_with(status_code(200).body("{\"data\":[1,2],\"next\":\"abc\"}")),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::path("/items"),
+                request::query(url_decoded(contains(("status", "open")))),
+                request::query(url_decoded(contains(("cursor", "abc")))),
+            ]).respond_with(status_code(200).body("{\"data\":[3],\"next\":null}")),
+        );
+        let endpoint = EndpointBuilder::new()
+            .with_query_param("status", false)
+            .with_pagination(PaginationStrategy::Cursor { path: "$.next".to_string(), parameter: "cursor".to_string() }, Some("$.data"));
+        let path = setup(Some(build_config(server.addr().to_string().as_str(), endpoint)));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "list_items", "--status", "open", "--body", "--all-pages"])
+            .assert()
+            .success()
+            .stdout("[1,2,3]\n");
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_link_pagination_without_items_when_call_all_pages_then_should_print_each_page() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::path("/items"),
+                request::query(url_decoded(not(contains(key("page"))))),
+            ]).respond_with(status_code(200)
+                .insert_header("Link", "</items?page=2>; rel=\"next\"")
+                .body("{\"page\":1}")),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::path("/items"),
+                request::query(url_decoded(contains(("page", "2")))),
+            ]).respond_with(status_code(200)
+                .insert_header("Link", "</items?page=1>; rel=\"prev\"")
+                .body("{\"page\":2}")),
+        );
+        let endpoint = EndpointBuilder::new().with_pagination(PaginationStrategy::Link, None);
+        let path = setup(Some(build_config(server.addr().to_string().as_str(), endpoint)));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "list_items", "--body", "--all-pages"])
+            .assert()
+            .success()
+            .stdout("{\"page\":1}\n{\"page\":2}\n");
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_page_pagination_when_call_all_pages_with_max_pages_then_should_stop() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::path("/items"),
+                request::query(url_decoded(contains(("page", "1")))),
+            ]).respond_with(status_code(200).body("[1]")),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::path("/items"),
+                request::query(url_decoded(contains(("page", "2")))),
+            ]).respond_with(status_code(200).body("[2]")),
+        );
+        let endpoint = EndpointBuilder::new().with_pagination(PaginationStrategy::Page { parameter: "page".to_string() }, None);
+        let path = setup(Some(build_config(server.addr().to_string().as_str(), endpoint)));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "list_items", "--body", "--all-pages", "--max-pages", "2"])
+            .assert()
+            .success()
+            .stdout("[1]\n[2]\n")
+            .stderr("Stopped after 2 pages, more pages are available\n");
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_offset_pagination_when_call_all_pages_then_should_stop_at_empty_page() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::path("/items"),
+                request::query(url_decoded(contains(("offset", "5")))),
+            ]).respond_with(status_code(200).body("{\"items\":[\"a\",\"b\"]}")),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::path("/items"),
+                request::query(url_decoded(contains(("offset", "7")))),
+            ]).respond_with(status_code(200).body("{\"items\":[]}")),
+        );
+        let endpoint = EndpointBuilder::new().with_pagination(PaginationStrategy::Offset { parameter: "offset".to_string() }, Some("$.items"));
+        let path = setup(Some(build_config(server.addr().to_string().as_str(), endpoint)));
+
+        let url = format!("http://{}/items", server.addr());
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "list_items", "-q", "offset=5", "--all-pages"])
+            .assert()
+            .success()
+            .stdout(format!("200 OK | GET | {url}?offset=5\n200 OK | GET | {url}?offset=7\n[\"a\",\"b\"]\n"));
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_failed_page_when_call_all_pages_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/items"))
+                .respond_with(status_code(500).body("oops")),
+        );
+        let endpoint = EndpointBuilder::new().with_pagination(PaginationStrategy::Link, Some("$.data"));
+        let path = setup(Some(build_config(server.addr().to_string().as_str(), endpoint)));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "list_items", "--body", "--all-pages"])
+            .assert()
+            .failure()
+            .stdout("oops\nStopped after page 1 responded with 500 Internal Server Error\n");
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_endpoint_without_pagination_when_call_all_pages_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config("localhost", EndpointBuilder::new())));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "list_items", "--all-pages"])
+            .assert()
+            .failure()
+            .stdout("Endpoint `list_items` for service `foo_service` has no pagination set\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_har_option_when_call_all_pages_then_should_record_every_page() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::path("/items"),
+                request::query(url_decoded(contains(("page", "1")))),
+            ]).respond_with(status_code(200).body("[1,2]")),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::path("/items"),
+                request::query(url_decoded(contains(("page", "2")))),
+            ]).respond_with(status_code(200).body("[]")),
+        );
+        let endpoint = EndpointBuilder::new()
+            .with_query_param("page", false)
+            .with_pagination(PaginationStrategy::Page { parameter: "page".to_string() }, None);
+        let path = setup(Some(build_config(server.addr().to_string().as_str(), endpoint)));
+        let har_path = format!("{}.har", Uuid::new_v4());
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "list_items", "--body", "--all-pages", "--har", &har_path])
+            .assert()
+            .success();
+
+        let har: serde_json::Value = serde_json::from_reader(File::open(&har_path)?)?;
+        let entries = har["log"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["request"]["url"], format!("http://{}/items?page=1", server.addr()));
+        assert_eq!(entries[1]["request"]["url"], format!("http://{}/items?page=2", server.addr()));
+        assert_eq!(entries[1]["response"]["content"]["text"], "[]");
+
+        clear_config(&path);
+        clear_config(&har_path);
+        server.verify_and_clear();
+        Ok(())
+    }
+}
@@ -174,6 +174,49 @@ mod call_command_preset_tests {
         Ok(())
     }
 
+    #[test]
+    fn given_no_input_flag_when_call_with_preset_missing_param_then_should_fail_without_prompting() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/my/{foo}/path")
+                    )
+            )
+            .with_preset(
+                PresetBuilder::new()
+                    .with_name("foo_preset")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--preset")
+            .arg("foo_preset")
+            .arg("--no-input")
+            .write_stdin("bar\n")
+            .assert()
+            .failure()
+            .stdout("Parameter `foo` is required but not provided from parameters\n")
+            .stderr("");
+
+        clear_config(&path);
+        Ok(())
+    }
+
     #[test]
     fn given_endpoint_with_required_param_when_call_with_preset_and_param_then_param_used() -> Result<(), Box<dyn Error>> {
         let mut server = SERVER_POOL.get_server();
@@ -224,4 +267,67 @@ mod call_command_preset_tests {
         server.verify_and_clear();
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn given_dry_run_flag_when_call_with_preset_then_should_print_values_with_sources() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_header("X-Global", "global")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_header("X-Service", "service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                            .with_header("X-Service", "environment")
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_method("POST")
+                            .with_path("/my/{id}/path")
+                            .with_query_param("limit", true)
+                            .with_body("{}")
+                    )
+            )
+            .with_preset(
+                PresetBuilder::new()
+                    .with_name("foo_preset")
+                    .with_value("id", "1")
+                    .with_value("limit", "10")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--preset")
+            .arg("foo_preset")
+            .arg("--id")
+            .arg("2")
+            .arg("--dry-run")
+            .assert()
+            .success()
+            .stdout(format!("POST http://foo.com/my/2/path?limit=10
+Host: foo.com (environment `foo_environment`)
+Path: /my/{{id}}/path (endpoint `foo_endpoint`)
+Parameters:
+ - id = 2 (argument)
+ - limit = 10 (preset `foo_preset`)
+Headers:
+ - User-Agent: htrs/{} (default)
+ - X-Global: global (global header)
+ - X-Service: environment (environment header)
+Body (endpoint `foo_endpoint`):
+{{}}
+", env!("CARGO_PKG_VERSION")));
+
+        clear_config(&path);
+        Ok(())
+    }
+}
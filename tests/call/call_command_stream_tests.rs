@@ -0,0 +1,127 @@
+mod call_command_stream_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::config::HtrsConfig;
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use httptest::matchers::request;
+    use httptest::responders::status_code;
+    use httptest::{Expectation, ServerPool};
+    use std::error::Error;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::{SocketAddr, TcpListener};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    static SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    fn build_config(host: &str) -> HtrsConfig {
+        HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(host)
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("events")
+                            .with_path("/events")
+                    )
+            )
+            .build()
+    }
+
+    /// Serves an event stream that sends `body` then stays open without sending anything else
+    fn serve_open_stream(body: &'static str) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                line.clear();
+            }
+            stream.write_all(format!("HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\n\r\n{}", body).as_bytes()).unwrap();
+            stream.flush().unwrap();
+            thread::sleep(Duration::from_secs(10));
+        });
+        address
+    }
+
+    #[test]
+    fn given_event_stream_when_call_then_should_print_each_event() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/events"))
+                .respond_with(status_code(200)
+                    .insert_header("Content-Type", "text/event-stream")
+                    .body(": connected\n\nid: 1\nevent: update\ndata: {\"a\":1}\n\ndata: first\ndata: second\n\n")),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str())));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "events", "--body"])
+            .assert()
+            .success()
+            .stdout("1 | update | {\"a\":1}\n1 | message | first\nsecond\n");
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_open_event_stream_when_call_with_max_events_then_should_stop_after_events() -> Result<(), Box<dyn Error>> {
+        let address = serve_open_stream("data: one\n\ndata: two\n\ndata: three\n\n");
+        let path = setup(Some(build_config(address.to_string().as_str())));
+
+        let started = Instant::now();
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "events", "--max-events", "2"])
+            .assert()
+            .success()
+            .stdout(format!("200 OK | GET | http://{}/events\n- | message | one\n- | message | two\n", address));
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_open_event_stream_when_call_with_idle_timeout_then_should_stop_when_idle() -> Result<(), Box<dyn Error>> {
+        let address = serve_open_stream("id: 7\ndata: one\n\n");
+        let path = setup(Some(build_config(address.to_string().as_str())));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "events", "--body", "--idle-timeout", "200ms"])
+            .assert()
+            .success()
+            .stdout("7 | message | one\n")
+            .stderr("Stopped after receiving nothing for 200ms\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_invalid_idle_timeout_when_call_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config("localhost")));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "events", "--idle-timeout", "soon"])
+            .assert()
+            .failure()
+            .stdout("Invalid duration `soon`, should be in a format like `500ms` or `2s`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ mod call_command_tests {
     use httptest::matchers::{contains, request, url_decoded};
     use httptest::responders::status_code;
     use httptest::{all_of, Expectation, ServerPool};
+    use predicates::prelude::*;
     use std::error::Error;
 
     static SERVER_POOL: ServerPool = ServerPool::new(1);
@@ -170,4 +171,372 @@ mod call_command_tests {
         server.verify_and_clear();
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn given_endpoint_with_method_headers_and_body_when_call_then_should_send_them() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("POST"),
+                request::path("/my/path"),
+                request::headers(contains(("content-type", "application/json"))),
+                request::body("{\"foo\":\"bar\"}"),
+            ]).respond_with(status_code(201)),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(server.addr().to_string().as_str())
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_method("POST")
+                            .with_path("/my/path")
+                            .with_header("Content-Type", "application/json")
+                            .with_body("{\"foo\":\"bar\"}")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .assert()
+            .success();
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_headers_at_each_scope_when_call_then_should_send_with_environment_precedence() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("GET"),
+                request::path("/my/path"),
+                request::headers(contains(("x-global", "global"))),
+                request::headers(contains(("x-service", "environment"))),
+            ]).respond_with(status_code(200)),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_header("X-Global", "global")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_header("X-Service", "service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(server.addr().to_string().as_str())
+                            .with_default()
+                            .with_header("X-Service", "environment")
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/my/path")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .assert()
+            .success();
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_as_curl_flag_when_call_then_should_print_curl_command() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_method("PUT")
+                            .with_path("/my/{param}/path")
+                            .with_query_param("foo", true)
+                            .with_header("X-Foo", "bar")
+                            .with_body("{}")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--param")
+            .arg("value")
+            .arg("--foo")
+            .arg("bar")
+            .arg("--as-curl")
+            .assert()
+            .success()
+            .stdout(format!(
+                "curl \\\n  -X PUT \\\n  'http://foo.com/my/value/path?foo=bar' \\\n  -H 'User-Agent: htrs/{}' \\\n  -H 'X-Foo: bar' \\\n  --data-raw '{{}}'\n",
+                env!("CARGO_PKG_VERSION"),
+            ));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_endpoint_with_metadata_when_call_service_help_then_should_show_description_and_alias() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_user")
+                            .with_path("/users/{id}")
+                            .with_description("Get a single user")
+                            .with_alias("user")
+                            .with_tag("users")
+                            .with_parameter_description("id", "The id of the user")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("--help")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("get_user  Get a single user [aliases: user]"));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("user")
+            .arg("--help")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("--id <id>").and(predicate::str::contains("The id of the user")).and(predicate::str::contains("Tags: users")));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_params_named_as_call_flags_when_call_then_should_take_values_from_query_param_args() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("GET"),
+                request::path("/limits/5"),
+                request::query(url_decoded(contains(("duration", "10s")))),
+                request::query(url_decoded(contains(("id", "1")))),
+            ]).respond_with(status_code(200)),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(server.addr().to_string().as_str())
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/limits/{rate}")
+                            .with_query_param("duration", true)
+                            .with_query_param("id", false)
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--help")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("--id <id>"));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("-q")
+            .arg("rate=5")
+            .arg("-q")
+            .arg("duration=10s")
+            .arg("--id")
+            .arg("1")
+            .assert()
+            .success();
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_param_named_format_when_call_then_should_not_clash_with_output_format() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::path("/report"),
+                request::query(url_decoded(contains(("format", "csv")))),
+            ]).respond_with(status_code(200)),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(server.addr().to_string().as_str())
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/report")
+                            .with_query_param("format", true)
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--format")
+            .arg("csv")
+            .assert()
+            .success();
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_missing_required_query_param_without_preset_when_call_then_should_name_parameter() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/my/path")
+                            .with_query_param("duration", true)
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .assert()
+            .failure()
+            .stdout("Query parameter `duration` is required but not provided from parameters\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_missing_path_param_when_call_then_should_fail_with_same_error_as_no_input() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/users/{id}")
+                            .with_parameter_description("id", "Id of the user")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        for args in [vec![], vec!["--no-input"]] {
+            Command::cargo_bin("htrs")?
+                .env("HTRS_CONFIG_PATH", &path)
+                .arg("call")
+                .arg("foo_service")
+                .arg("foo_endpoint")
+                .args(args)
+                .assert()
+                .failure()
+                .stdout("Parameter `id` is required but not provided from parameters\n");
+        }
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--help")
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(r"--id <id> +Id of the user \[required\]\n")?);
+
+        clear_config(&path);
+        Ok(())
+    }
+}
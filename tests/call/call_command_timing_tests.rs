@@ -0,0 +1,169 @@
+mod call_command_timing_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, get_config, setup};
+    use assert_cmd::Command;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use httptest::matchers::request;
+    use httptest::responders::status_code;
+    use httptest::{Expectation, ServerPool};
+    use predicates::prelude::*;
+    use std::error::Error;
+    use std::io::Write;
+
+    static SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    fn build_config(host: &str) -> HtrsConfigBuilder {
+        HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(host)
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/my/path")
+                    )
+            )
+    }
+
+    #[test]
+    fn given_timing_flag_when_call_then_should_print_timing_to_stderr() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/my/path"))
+                .respond_with(status_code(200).insert_header("Content-Type", "text/plain; charset=iso-8859-1").body(vec![0x63, 0x61, 0x66, 0xe9])),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str()).build()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--body")
+            .arg("--timing")
+            .assert()
+            .success()
+            .stdout("café\n")
+            .stderr(predicate::str::is_match(r"^connect \d+ms \| first byte \d+ms \| total \d+ms \| size 4 bytes \| decoded 5 bytes\n$")?);
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_no_timing_flag_when_call_then_should_not_print_timing() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/my/path")).respond_with(status_code(200).body("hello")),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str()).build()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .assert()
+            .success()
+            .stderr("");
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_gzip_response_when_call_with_timing_then_should_decompress_and_print_both_sizes() -> Result<(), Box<dyn Error>> {
+        let body = "hello ".repeat(100);
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(body.as_bytes())?;
+        let compressed = encoder.finish()?;
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/my/path"))
+                .respond_with(status_code(200).insert_header("Content-Encoding", "gzip").body(compressed.clone())),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str()).build()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--body")
+            .arg("--timing")
+            .assert()
+            .success()
+            .stdout(format!("{}\n", body))
+            .stderr(predicate::str::ends_with(format!("| size {} bytes | decoded 600 bytes\n", compressed.len())));
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_timing_always_on_when_call_then_should_print_timing_and_record_in_history() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/my/path")).respond_with(status_code(200).body("hello")),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str()).with_show_timing().build()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("| size 5 bytes | decoded 5 bytes\n"));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("history")
+            .arg("show")
+            .arg("1")
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(r"\nConnect: \d+ms\nFirst byte: \d+ms\nSize: 5 bytes \(5 bytes decoded\)\n")?);
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_set_timing_command_then_should_update_config() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("set")
+            .arg("timing")
+            .arg("on")
+            .assert()
+            .success();
+        assert!(get_config(&path).show_timing);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("set")
+            .arg("timing")
+            .arg("off")
+            .assert()
+            .success();
+        assert!(!get_config(&path).show_timing);
+
+        clear_config(&path);
+        Ok(())
+    }
+}
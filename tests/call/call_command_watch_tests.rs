@@ -0,0 +1,155 @@
+mod call_command_watch_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::config::HtrsConfig;
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use httptest::matchers::request;
+    use httptest::responders::status_code;
+    use httptest::{cycle, Expectation, ServerPool};
+    use predicates::prelude::*;
+    use std::error::Error;
+    use std::fs::File;
+    use uuid::Uuid;
+
+    static SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    fn build_config(host: &str) -> HtrsConfig {
+        HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(host)
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_job")
+                            .with_path("/jobs/{id}")
+                    )
+            )
+            .build()
+    }
+
+    #[test]
+    fn given_until_condition_when_watch_then_should_print_changes_until_condition_met() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/jobs/1"))
+                .times(3)
+                .respond_with(cycle![
+                    status_code(200).body("{\"state\":\"running\"}"),
+                    status_code(200).body("{\"state\":\"running\"}"),
+                    status_code(200).body("{\"state\":\"done\"}"),
+                ]),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str())));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "get_job", "--id", "1", "--body", "--watch", "10ms", "--until", "status=200", "--until", "$.state=done"])
+            .assert()
+            .success()
+            .stdout("{\"state\":\"running\"}\n{\"state\":\"done\"}\n")
+            .stderr("Condition met after 3 polls\n");
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_unmet_condition_when_watch_with_max_wait_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/jobs/2"))
+                .times(1..)
+                .respond_with(status_code(200).body("{\"state\":\"running\"}")),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str())));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "get_job", "--id", "2", "--body", "--watch", "50ms", "--until", "$.state=done", "--max-wait", "200ms"])
+            .assert()
+            .failure()
+            .stdout(predicate::str::starts_with("{\"state\":\"running\"}\nCondition not met after "))
+            .stdout(predicate::str::ends_with(" polls in 200ms\n"));
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_invalid_condition_when_watch_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config("foo.com")));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "get_job", "--id", "1", "--watch", "1s", "--until", "done"])
+            .assert()
+            .failure()
+            .stdout("Invalid condition `done`, should be in format `status=<status>` or `<json path>=<value>`\n");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "get_job", "--id", "1", "--watch", "1s", "--until", "status=ok"])
+            .assert()
+            .failure()
+            .stdout("Invalid status `ok`, should be a code like `200`, a class like `2xx` or a range like `200-299`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_until_without_watch_when_call_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config("foo.com")));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "get_job", "--id", "1", "--until", "status=200"])
+            .assert()
+            .failure();
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_har_option_when_watch_then_should_record_every_poll() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/jobs/1"))
+                .times(3)
+                .respond_with(cycle![
+                    status_code(200).body("{\"state\":\"running\"}"),
+                    status_code(200).body("{\"state\":\"running\"}"),
+                    status_code(200).body("{\"state\":\"done\"}"),
+                ]),
+        );
+        let path = setup(Some(build_config(server.addr().to_string().as_str())));
+        let har_path = format!("{}.har", Uuid::new_v4());
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["call", "foo_service", "get_job", "--id", "1", "--watch", "10ms", "--until", "$.state=done", "--har", &har_path])
+            .assert()
+            .success();
+
+        let har: serde_json::Value = serde_json::from_reader(File::open(&har_path)?)?;
+        let entries = har["log"]["entries"].as_array().unwrap();
+        let bodies: Vec<&str> = entries.iter()
+            .map(|e| e["response"]["content"]["text"].as_str().unwrap())
+            .collect();
+        assert_eq!(bodies, vec!["{\"state\":\"running\"}", "{\"state\":\"running\"}", "{\"state\":\"done\"}"]);
+
+        clear_config(&path);
+        clear_config(&har_path);
+        server.verify_and_clear();
+        Ok(())
+    }
+}
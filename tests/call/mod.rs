@@ -1,2 +1,9 @@
 mod call_command_tests;
-mod call_command_preset_tests;
\ No newline at end of file
+mod call_command_preset_tests;
+mod call_command_har_tests;
+mod call_command_benchmark_tests;
+mod call_command_timing_tests;
+mod call_command_fixture_tests;
+mod call_command_watch_tests;
+mod call_command_pagination_tests;
+mod call_command_stream_tests;
\ No newline at end of file
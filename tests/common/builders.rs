@@ -1,4 +1,4 @@
-use crate::common::config::{Endpoint, Environment, HtrsConfig, Preset, QueryParameter, Service};
+use crate::common::config::{Assertion, Endpoint, Environment, Extraction, HtrsConfig, MockResponse, Pagination, PaginationStrategy, Preset, QueryParameter, Service, TestCase, Workflow, WorkflowStep};
 use std::collections::HashMap;
 
 pub struct HtrsConfigBuilder {
@@ -6,6 +6,9 @@ pub struct HtrsConfigBuilder {
     pub services: Vec<Service>,
     pub presets: Vec<Preset>,
     pub headers: HashMap<String, String>,
+    pub secret_headers: Vec<String>,
+    pub workflows: Vec<Workflow>,
+    pub show_timing: bool,
 }
 
 pub struct ServiceBuilder {
@@ -14,6 +17,8 @@ pub struct ServiceBuilder {
     pub endpoints: Vec<Endpoint>,
     pub environments: Vec<Environment>,
     pub headers: HashMap<String, String>,
+    pub tests: Vec<TestCase>,
+    pub health_endpoint: Option<String>,
 }
 
 pub struct PresetBuilder {
@@ -24,8 +29,40 @@ pub struct PresetBuilder {
 
 pub struct EndpointBuilder {
     pub name: Option<String>,
+    pub method: String,
     pub path: Option<String>,
     pub query_params: Vec<QueryParameter>,
+    pub headers: HashMap<String, String>,
+    pub body: Option<String>,
+    pub description: Option<String>,
+    pub alias: Option<String>,
+    pub tags: Vec<String>,
+    pub parameter_descriptions: HashMap<String, String>,
+    pub mock: Option<MockResponse>,
+    pub pagination: Option<Pagination>,
+}
+
+pub struct TestCaseBuilder {
+    pub name: Option<String>,
+    pub endpoint: Option<String>,
+    pub preset: Option<String>,
+    pub parameters: HashMap<String, String>,
+    pub assertions: Vec<Assertion>,
+}
+
+pub struct WorkflowBuilder {
+    pub name: Option<String>,
+    pub steps: Vec<WorkflowStep>,
+}
+
+pub struct WorkflowStepBuilder {
+    pub name: Option<String>,
+    pub service: Option<String>,
+    pub endpoint: Option<String>,
+    pub preset: Option<String>,
+    pub parameters: HashMap<String, String>,
+    pub expected_status: Option<String>,
+    pub extractions: Vec<Extraction>,
 }
 
 pub struct EnvironmentBuilder {
@@ -39,10 +76,13 @@ pub struct EnvironmentBuilder {
 impl HtrsConfigBuilder {
     pub fn new() -> Self {
         Self {
-            version: "V2".to_string(),
+            version: "V3".to_string(),
             services: vec![],
             presets: vec![],
             headers: HashMap::new(),
+            secret_headers: vec![],
+            workflows: vec![],
+            show_timing: false,
         }
     }
 
@@ -61,12 +101,30 @@ impl HtrsConfigBuilder {
         self
     }
 
+    pub fn with_secret_header(mut self, name: &str) -> Self {
+        self.secret_headers.push(name.to_string());
+        self
+    }
+
+    pub fn with_workflow(mut self, builder: WorkflowBuilder) -> Self {
+        self.workflows.push(builder.build());
+        self
+    }
+
+    pub fn with_show_timing(mut self) -> Self {
+        self.show_timing = true;
+        self
+    }
+
     pub fn build(self) -> HtrsConfig {
         HtrsConfig {
             version: self.version,
             services: self.services,
             headers: self.headers,
             presets: self.presets,
+            secret_headers: self.secret_headers,
+            workflows: self.workflows,
+            show_timing: self.show_timing,
         }
     }
 }
@@ -79,6 +137,8 @@ impl ServiceBuilder {
             endpoints: vec![],
             environments: vec![],
             headers: HashMap::new(),
+            tests: vec![],
+            health_endpoint: None,
         }
     }
 
@@ -107,6 +167,16 @@ impl ServiceBuilder {
         self
     }
 
+    pub fn with_health_endpoint(mut self, endpoint: &str) -> Self {
+        self.health_endpoint = Some(endpoint.to_string());
+        self
+    }
+
+    pub fn with_test(mut self, builder: TestCaseBuilder) -> Self {
+        self.tests.push(builder.build());
+        self
+    }
+
     pub fn build(self) -> Service {
         Service {
             name: self.name.unwrap(),
@@ -114,6 +184,55 @@ impl ServiceBuilder {
             headers: self.headers,
             endpoints: self.endpoints,
             environments: self.environments,
+            tests: self.tests,
+            health_endpoint: self.health_endpoint,
+        }
+    }
+}
+
+impl TestCaseBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            endpoint: None,
+            preset: None,
+            parameters: HashMap::new(),
+            assertions: vec![],
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn with_endpoint(mut self, endpoint: &str) -> Self {
+        self.endpoint = Some(endpoint.to_string());
+        self
+    }
+
+    pub fn with_preset(mut self, preset: &str) -> Self {
+        self.preset = Some(preset.to_string());
+        self
+    }
+
+    pub fn with_parameter(mut self, name: &str, value: &str) -> Self {
+        self.parameters.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn with_assertion(mut self, assertion: Assertion) -> Self {
+        self.assertions.push(assertion);
+        self
+    }
+
+    pub fn build(self) -> TestCase {
+        TestCase {
+            name: self.name.unwrap(),
+            endpoint: self.endpoint.unwrap(),
+            preset: self.preset,
+            parameters: self.parameters,
+            assertions: self.assertions,
         }
     }
 }
@@ -155,8 +274,17 @@ impl EndpointBuilder {
     pub fn new() -> Self {
         EndpointBuilder {
             name: None,
+            method: "GET".to_string(),
             path: None,
             query_params: vec![],
+            headers: HashMap::new(),
+            body: None,
+            description: None,
+            alias: None,
+            tags: vec![],
+            parameter_descriptions: HashMap::new(),
+            mock: None,
+            pagination: None,
         }
     }
 
@@ -170,6 +298,21 @@ impl EndpointBuilder {
         self
     }
 
+    pub fn with_method(mut self, method: &str) -> Self {
+        self.method = method.to_string();
+        self
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn with_body(mut self, body: &str) -> Self {
+        self.body = Some(body.to_string());
+        self
+    }
+
     pub fn with_query_param(mut self, name: &str, required: bool) -> Self {
         self.query_params.push(QueryParameter {
             name: name.to_string(),
@@ -178,11 +321,63 @@ impl EndpointBuilder {
         self
     }
 
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn with_alias(mut self, alias: &str) -> Self {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    pub fn with_parameter_description(mut self, name: &str, description: &str) -> Self {
+        self.parameter_descriptions.insert(name.to_string(), description.to_string());
+        self
+    }
+
+    pub fn with_mock(mut self, status: u16, body: Option<&str>, file: Option<&str>) -> Self {
+        self.mock = Some(MockResponse {
+            status,
+            headers: HashMap::new(),
+            body: body.map(|b| b.to_string()),
+            file: file.map(|f| f.to_string()),
+        });
+        self
+    }
+
+    pub fn with_mock_header(mut self, name: &str, value: &str) -> Self {
+        self.mock.as_mut().unwrap().headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn with_pagination(mut self, strategy: PaginationStrategy, items: Option<&str>) -> Self {
+        self.pagination = Some(Pagination {
+            strategy,
+            items: items.map(|i| i.to_string()),
+        });
+        self
+    }
+
     pub fn build(self) -> Endpoint {
         Endpoint {
             name: self.name.unwrap(),
+            method: self.method,
             path_template: self.path.unwrap(),
             query_parameters: self.query_params,
+            headers: self.headers,
+            body: self.body,
+            description: self.description,
+            alias: self.alias,
+            tags: self.tags,
+            parameter_descriptions: self.parameter_descriptions,
+            mock: self.mock,
+            pagination: self.pagination,
         }
     }
 }
@@ -232,4 +427,90 @@ impl EnvironmentBuilder {
             headers: self.headers,
         }
     }
-}
\ No newline at end of file
+}
+impl WorkflowBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            steps: vec![],
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn with_step(mut self, builder: WorkflowStepBuilder) -> Self {
+        self.steps.push(builder.build());
+        self
+    }
+
+    pub fn build(self) -> Workflow {
+        Workflow {
+            name: self.name.unwrap(),
+            steps: self.steps,
+        }
+    }
+}
+
+impl WorkflowStepBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            service: None,
+            endpoint: None,
+            preset: None,
+            parameters: HashMap::new(),
+            expected_status: None,
+            extractions: vec![],
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn with_service(mut self, service: &str) -> Self {
+        self.service = Some(service.to_string());
+        self
+    }
+
+    pub fn with_endpoint(mut self, endpoint: &str) -> Self {
+        self.endpoint = Some(endpoint.to_string());
+        self
+    }
+
+    pub fn with_preset(mut self, preset: &str) -> Self {
+        self.preset = Some(preset.to_string());
+        self
+    }
+
+    pub fn with_parameter(mut self, name: &str, value: &str) -> Self {
+        self.parameters.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn with_expected_status(mut self, expected_status: &str) -> Self {
+        self.expected_status = Some(expected_status.to_string());
+        self
+    }
+
+    pub fn with_extraction(mut self, extraction: Extraction) -> Self {
+        self.extractions.push(extraction);
+        self
+    }
+
+    pub fn build(self) -> WorkflowStep {
+        WorkflowStep {
+            name: self.name.unwrap(),
+            service: self.service.unwrap(),
+            endpoint: self.endpoint.unwrap(),
+            preset: self.preset,
+            parameters: self.parameters,
+            expected_status: self.expected_status,
+            extractions: self.extractions,
+        }
+    }
+}
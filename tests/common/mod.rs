@@ -28,6 +28,12 @@ pub mod test_helpers {
         path_str
     }
 
+    pub fn setup_file(contents: &str, extension: &str) -> String {
+        let path_str = format!("{}.{}", Uuid::new_v4(), extension);
+        std::fs::write(&path_str, contents).expect("Failed to write test input file");
+        path_str
+    }
+
     pub fn get_config(path: &str) -> HtrsConfig {
         let path = PathBuf::from(path);
         serde_json::from_reader(File::open(path).unwrap()).unwrap()
@@ -35,5 +41,8 @@ pub mod test_helpers {
 
     pub fn clear_config(path: &str) {
         remove_file(path).expect("Failed to clean up test config file");
+        for extension in ["history", "shell_history"] {
+            let _ = remove_file(PathBuf::from(path).with_extension(extension));
+        }
     }
 }
\ No newline at end of file
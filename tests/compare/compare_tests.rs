@@ -0,0 +1,242 @@
+mod compare_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use httptest::matchers::request;
+    use httptest::responders::status_code;
+    use httptest::{all_of, Expectation, ServerPool};
+    use std::error::Error;
+    use std::fs::File;
+    use uuid::Uuid;
+
+    // Servers are always taken from the staging pool first so that tests can't deadlock
+    static STAGING_SERVER_POOL: ServerPool = ServerPool::new(1);
+    static PRODUCTION_SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    #[test]
+    fn given_single_environment_when_compare_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("staging")
+                            .with_host("staging.foo.com")
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/foo")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("compare")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--env")
+            .arg("staging")
+            .assert()
+            .failure()
+            .stdout("At least two environments are needed to compare, use `--env <environment>` for each\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_different_responses_when_compare_then_should_print_differences_except_ignored() -> Result<(), Box<dyn Error>> {
+        let mut staging = STAGING_SERVER_POOL.get_server();
+        staging.expect(
+            Expectation::matching(all_of![
+                request::method("GET"),
+                request::path("/users/1"),
+            ]).respond_with(
+                status_code(200)
+                    .insert_header("X-Version", "1")
+                    .body(r#"{"id":1,"name":"foo","roles":["admin"],"updated_at":"2024-01-01"}"#)
+            ),
+        );
+        let mut production = PRODUCTION_SERVER_POOL.get_server();
+        production.expect(
+            Expectation::matching(all_of![
+                request::method("GET"),
+                request::path("/users/1"),
+            ]).respond_with(
+                status_code(200)
+                    .insert_header("X-Version", "2")
+                    .body(r#"{"id":1,"name":"bar","roles":[],"updated_at":"2024-02-01"}"#)
+            ),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("staging")
+                            .with_host(staging.addr().to_string().as_str())
+                    )
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("production")
+                            .with_host(production.addr().to_string().as_str())
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_user")
+                            .with_path("/users/{id}")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("compare")
+            .arg("foo_service")
+            .arg("get_user")
+            .arg("--env")
+            .arg("staging")
+            .arg("--env")
+            .arg("production")
+            .arg("--id")
+            .arg("1")
+            .arg("--header")
+            .arg("X-Version")
+            .arg("--ignore")
+            .arg("$.updated_at")
+            .assert()
+            .failure()
+            .stdout(
+                "staging (200) vs production (200)
+ ~ header x-version: 1 vs 2
+ ~ $.name: \"foo\" vs \"bar\"
+ ~ $.roles[0]: \"admin\" vs (missing)
+Found 3 differences between environments
+");
+
+        clear_config(&path);
+        staging.verify_and_clear();
+        production.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_same_responses_when_compare_then_should_succeed() -> Result<(), Box<dyn Error>> {
+        let mut staging = STAGING_SERVER_POOL.get_server();
+        staging.expect(
+            Expectation::matching(request::path("/foo")).respond_with(status_code(200).body(r#"{"ok":true}"#)),
+        );
+        let mut production = PRODUCTION_SERVER_POOL.get_server();
+        production.expect(
+            Expectation::matching(request::path("/foo")).respond_with(status_code(200).body(r#"{ "ok": true }"#)),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("staging")
+                            .with_host(staging.addr().to_string().as_str())
+                    )
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("production")
+                            .with_host(production.addr().to_string().as_str())
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/foo")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("compare")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("-e")
+            .arg("staging")
+            .arg("-e")
+            .arg("production")
+            .assert()
+            .success()
+            .stdout("staging (200) vs production (200)\n No differences\n");
+
+        clear_config(&path);
+        staging.verify_and_clear();
+        production.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_har_option_when_compare_then_should_record_each_environment() -> Result<(), Box<dyn Error>> {
+        let mut staging = STAGING_SERVER_POOL.get_server();
+        staging.expect(
+            Expectation::matching(request::path("/foo")).respond_with(status_code(200).body(r#"{"ok":true}"#)),
+        );
+        let mut production = PRODUCTION_SERVER_POOL.get_server();
+        production.expect(
+            Expectation::matching(request::path("/foo")).respond_with(status_code(200).body(r#"{"ok":true}"#)),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("staging")
+                            .with_host(staging.addr().to_string().as_str())
+                    )
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("production")
+                            .with_host(production.addr().to_string().as_str())
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/foo")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+        let har_path = format!("{}.har", Uuid::new_v4());
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("compare")
+            .arg("foo_service")
+            .arg("foo_endpoint")
+            .arg("--env")
+            .arg("staging")
+            .arg("--env")
+            .arg("production")
+            .arg("--har")
+            .arg(&har_path)
+            .assert()
+            .success();
+
+        let har: serde_json::Value = serde_json::from_reader(File::open(&har_path)?)?;
+        let entries = har["log"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["request"]["url"], format!("http://{}/foo", staging.addr()));
+        assert_eq!(entries[1]["request"]["url"], format!("http://{}/foo", production.addr()));
+        assert_eq!(entries[1]["response"]["content"]["text"], r#"{"ok":true}"#);
+
+        clear_config(&path);
+        clear_config(&har_path);
+        staging.verify_and_clear();
+        production.verify_and_clear();
+        Ok(())
+    }
+}
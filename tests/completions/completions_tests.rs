@@ -0,0 +1,78 @@
+mod completions_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, PresetBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use rstest::rstest;
+    use std::error::Error;
+
+    #[rstest]
+    #[case("bash", "complete -o default -F _htrs htrs")]
+    #[case("zsh", "compdef _htrs htrs")]
+    #[case("fish", "complete -c htrs -f -a '(__htrs_complete)'")]
+    #[case("powershell", "Register-ArgumentCompleter -Native -CommandName htrs")]
+    fn given_shell_when_completions_then_should_print_script(#[case] shell: &str, #[case] expected: &str) -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        let output = Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("completions")
+            .arg(shell)
+            .output()?;
+        assert!(output.status.success());
+        assert!(String::from_utf8(output.stdout)?.contains(expected));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&["call", ""], "foo_service\nfs\nhelp\n")]
+    #[case(&["call", "foo_service", "f"], "foo_endpoint\n")]
+    #[case(&["call", "fs", "foo_endpoint", "-e", ""], "foo_environment\nbar_environment\nbe\n")]
+    #[case(&["call", "foo_service", "foo_endpoint", "--preset", ""], "foo_preset\n")]
+    #[case(&["call", "foo_service", "foo_endpoint", "--i"], "--idle-timeout\n--id\n")]
+    #[case(&["delete", "environment", "--service", "foo_service", "b"], "bar_environment\nbe\n")]
+    #[case(&["view", "preset", ""], "foo_preset\n")]
+    fn given_partial_command_when_complete_then_should_print_candidates(#[case] words: &[&str], #[case] expected: &str) -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_alias("fs")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                            .with_default()
+                    )
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("bar_environment")
+                            .with_alias("be")
+                            .with_host("bar.com")
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/orders/{id}")
+                    )
+            )
+            .with_preset(
+                PresetBuilder::new()
+                    .with_name("foo_preset")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("__complete")
+            .args(words)
+            .assert()
+            .success()
+            .stdout(expected.to_string());
+
+        clear_config(&path);
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+mod completions_tests;
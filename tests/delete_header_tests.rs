@@ -1,4 +1,4 @@
-
+#[allow(dead_code)]
 mod common;
 
 #[cfg(test)]
@@ -212,4 +212,46 @@ mod delete_header_tests {
         clear_config(&path);
         Ok(())
     }
+
+    #[test]
+    fn given_secret_header_when_delete_last_header_with_name_then_should_unmark_header() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_header("X-Api-Key", "global_key")
+            .with_secret_header("x-api-key")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_header("x-api-key", "service_key")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("delete")
+            .arg("header")
+            .arg("X-Api-Key")
+            .assert()
+            .success();
+
+        // The service still sends the header, so it stays secret
+        let config = get_config(&path);
+        assert_eq!(config.secret_headers, vec!["x-api-key".to_string()]);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("delete")
+            .arg("header")
+            .arg("x-api-key")
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        assert!(config.secret_headers.is_empty());
+
+        clear_config(&path);
+        Ok(())
+    }
 }
@@ -71,7 +71,7 @@ mod create_new_endpoint_tests {
         assert_eq!(endpoint.query_parameters.len(), 1);
         let query_param = &endpoint.query_parameters[0];
         assert_eq!(query_param.name, "param");
-        assert_eq!(query_param.required, false);
+        assert!(!query_param.required);
 
         clear_config(&path);
         Ok(())
@@ -111,10 +111,10 @@ mod create_new_endpoint_tests {
         assert_eq!(endpoint.query_parameters.len(), 2);
         let query_param1 = &endpoint.query_parameters[0];
         assert_eq!(query_param1.name, "param1");
-        assert_eq!(query_param1.required, false);
+        assert!(!query_param1.required);
         let query_param2 = &endpoint.query_parameters[1];
         assert_eq!(query_param2.name, "param2");
-        assert_eq!(query_param2.required, false);
+        assert!(!query_param2.required);
 
         clear_config(&path);
         Ok(())
@@ -152,7 +152,7 @@ mod create_new_endpoint_tests {
         assert_eq!(endpoint.query_parameters.len(), 1);
         let query_param = &endpoint.query_parameters[0];
         assert_eq!(query_param.name, "param");
-        assert_eq!(query_param.required, true);
+        assert!(query_param.required);
 
         clear_config(&path);
         Ok(())
@@ -192,10 +192,10 @@ mod create_new_endpoint_tests {
         assert_eq!(endpoint.query_parameters.len(), 2);
         let query_param1 = &endpoint.query_parameters[0];
         assert_eq!(query_param1.name, "param1");
-        assert_eq!(query_param1.required, true);
+        assert!(query_param1.required);
         let query_param2 = &endpoint.query_parameters[1];
         assert_eq!(query_param2.name, "param2");
-        assert_eq!(query_param2.required, true);
+        assert!(query_param2.required);
 
         clear_config(&path);
         Ok(())
@@ -422,4 +422,169 @@ mod create_new_endpoint_tests {
         clear_config(&path);
         Ok(())
     }
+
+    #[test]
+    fn given_new_endpoint_command_with_method_when_execute_then_should_create_endpoint_with_method() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("endpoint")
+            .arg("foo_endpoint")
+            .arg("/my/path")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--method")
+            .arg("post")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let endpoint = &config.services[0].endpoints[0];
+        assert_eq!(endpoint.method, "POST");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_new_endpoint_command_with_invalid_method_when_execute_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("endpoint")
+            .arg("foo_endpoint")
+            .arg("/my/path")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--method")
+            .arg("NOT A METHOD")
+            .assert()
+            .failure()
+            .stdout("Invalid http method `NOT A METHOD`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_new_endpoint_command_with_metadata_when_execute_then_should_create_endpoint_with_metadata() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("endpoint")
+            .arg("get_user")
+            .arg("/users/{id}")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--query")
+            .arg("expand")
+            .arg("--description")
+            .arg("Get a single user")
+            .arg("--alias")
+            .arg("user")
+            .arg("--tag")
+            .arg("users")
+            .arg("--param-help")
+            .arg("id=The id of the user")
+            .arg("--param-help")
+            .arg("expand=Related items to include")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let endpoint = &config.services[0].endpoints[0];
+        assert_eq!(endpoint.description, Some("Get a single user".to_string()));
+        assert_eq!(endpoint.alias, Some("user".to_string()));
+        assert_eq!(endpoint.tags, vec!["users".to_string()]);
+        assert_eq!(endpoint.parameter_descriptions.get("id"), Some(&"The id of the user".to_string()));
+        assert_eq!(endpoint.parameter_descriptions.get("expand"), Some(&"Related items to include".to_string()));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_new_endpoint_command_with_help_for_unknown_param_when_execute_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("endpoint")
+            .arg("foo_endpoint")
+            .arg("/my/path")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--param-help")
+            .arg("id=The id")
+            .assert()
+            .failure()
+            .stdout("Endpoint `foo_endpoint` has no path or query parameter named `id`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_new_endpoint_command_with_alias_of_existing_endpoint_when_execute_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/foo")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("endpoint")
+            .arg("bar_endpoint")
+            .arg("/bar")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--alias")
+            .arg("foo_endpoint")
+            .assert()
+            .failure()
+            .stdout("Service `foo_service` already has an endpoint named `foo_endpoint`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
 }
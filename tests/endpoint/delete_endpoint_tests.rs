@@ -1,7 +1,7 @@
 
 #[cfg(test)]
 mod delete_endpoint_tests {
-    use crate::common::builders::{EndpointBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::builders::{EndpointBuilder, HtrsConfigBuilder, ServiceBuilder, TestCaseBuilder, WorkflowBuilder, WorkflowStepBuilder};
     use crate::common::test_helpers::{clear_config, get_config, setup};
     use assert_cmd::Command;
     use std::error::Error;
@@ -82,4 +82,55 @@ mod delete_endpoint_tests {
         clear_config(&path);
         Ok(())
     }
+
+    #[test]
+    fn given_endpoint_used_by_tests_and_workflows_when_delete_endpoint_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_alias("foo")
+                    .with_health_endpoint("foo_endpoint")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_alias("fe")
+                            .with_path("/path/")
+                    )
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("foo_test")
+                            .with_endpoint("fe")
+                    )
+            )
+            .with_workflow(
+                WorkflowBuilder::new()
+                    .with_name("foo_workflow")
+                    .with_step(
+                        WorkflowStepBuilder::new()
+                            .with_name("first")
+                            .with_service("foo")
+                            .with_endpoint("foo_endpoint")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("delete")
+            .arg("endpoint")
+            .arg("foo_endpoint")
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout("Endpoint `foo_endpoint` is still used by the health endpoint of service `foo_service`, test `foo_test`, step `first` of workflow `foo_workflow`, change or delete them first\n");
+
+        let config = get_config(&path);
+        assert_eq!(config.services[0].endpoints.len(), 1);
+
+        clear_config(&path);
+        Ok(())
+    }
 }
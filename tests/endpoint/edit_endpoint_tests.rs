@@ -1,7 +1,7 @@
 
 #[cfg(test)]
 mod edit_endpoint_tests {
-    use crate::common::builders::{EndpointBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::builders::{EndpointBuilder, HtrsConfigBuilder, ServiceBuilder, TestCaseBuilder, WorkflowBuilder, WorkflowStepBuilder};
     use crate::common::test_helpers::{clear_config, get_config, setup};
     use assert_cmd::Command;
     use std::error::Error;
@@ -164,9 +164,9 @@ mod edit_endpoint_tests {
         assert_eq!(endpoint.path_template, "/path");
         assert_eq!(endpoint.query_parameters.len(), 2);
         assert_eq!(endpoint.query_parameters[0].name, "existing_param");
-        assert_eq!(endpoint.query_parameters[0].required, true);
+        assert!(endpoint.query_parameters[0].required);
         assert_eq!(endpoint.query_parameters[1].name, "new_param");
-        assert_eq!(endpoint.query_parameters[1].required, false);
+        assert!(!endpoint.query_parameters[1].required);
 
         clear_config(&path);
         Ok(())
@@ -208,9 +208,9 @@ mod edit_endpoint_tests {
         assert_eq!(endpoint.path_template, "/path");
         assert_eq!(endpoint.query_parameters.len(), 2);
         assert_eq!(endpoint.query_parameters[0].name, "existing_param");
-        assert_eq!(endpoint.query_parameters[0].required, true);
+        assert!(endpoint.query_parameters[0].required);
         assert_eq!(endpoint.query_parameters[1].name, "new_param");
-        assert_eq!(endpoint.query_parameters[1].required, true);
+        assert!(endpoint.query_parameters[1].required);
 
         clear_config(&path);
         Ok(())
@@ -253,7 +253,156 @@ mod edit_endpoint_tests {
         assert_eq!(endpoint.path_template, "/path");
         assert_eq!(endpoint.query_parameters.len(), 1);
         assert_eq!(endpoint.query_parameters[0].name, "param2");
-        assert_eq!(endpoint.query_parameters[0].required, true);
+        assert!(endpoint.query_parameters[0].required);
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_edit_endpoint_command_with_metadata_then_should_update_endpoint() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/foo/{id}")
+                            .with_tag("old")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("edit")
+            .arg("endpoint")
+            .arg("foo_endpoint")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--new-description")
+            .arg("Get a foo")
+            .arg("--new-alias")
+            .arg("foo")
+            .arg("--new-tag")
+            .arg("new")
+            .arg("--del-tag")
+            .arg("old")
+            .arg("--param-help")
+            .arg("id=The id of the foo")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let endpoint = &config.services[0].endpoints[0];
+        assert_eq!(endpoint.description, Some("Get a foo".to_string()));
+        assert_eq!(endpoint.alias, Some("foo".to_string()));
+        assert_eq!(endpoint.tags, vec!["new".to_string()]);
+        assert_eq!(endpoint.parameter_descriptions.get("id"), Some(&"The id of the foo".to_string()));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_edit_endpoint_command_when_edit_path_then_should_drop_descriptions_of_removed_params() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/foo/{id}")
+                            .with_query_param("limit", false)
+                            .with_parameter_description("id", "The id of the foo")
+                            .with_parameter_description("limit", "How many to return")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("edit")
+            .arg("endpoint")
+            .arg("foo_endpoint")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--new-path")
+            .arg("/foo/{foo_id}")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let endpoint = &config.services[0].endpoints[0];
+        assert_eq!(endpoint.path_template, "/foo/{foo_id}");
+        assert_eq!(endpoint.parameter_descriptions.get("id"), None);
+        assert_eq!(endpoint.parameter_descriptions.get("limit"), Some(&"How many to return".to_string()));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_endpoint_used_by_tests_and_workflows_when_edit_name_then_should_rename_references() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_alias("foo")
+                    .with_health_endpoint("foo_endpoint")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_alias("fe")
+                            .with_path("/path/")
+                    )
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("by_name")
+                            .with_endpoint("foo_endpoint")
+                    )
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("by_alias")
+                            .with_endpoint("fe")
+                    )
+            )
+            .with_workflow(
+                WorkflowBuilder::new()
+                    .with_name("foo_workflow")
+                    .with_step(
+                        WorkflowStepBuilder::new()
+                            .with_name("first")
+                            .with_service("foo")
+                            .with_endpoint("foo_endpoint")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("edit")
+            .arg("endpoint")
+            .arg("foo_endpoint")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--new-name")
+            .arg("bar_endpoint")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let service = &config.services[0];
+        assert_eq!(service.health_endpoint, Some("bar_endpoint".to_string()));
+        assert_eq!(service.tests[0].endpoint, "bar_endpoint");
+        // The alias still names the endpoint, so is left alone
+        assert_eq!(service.tests[1].endpoint, "fe");
+        assert_eq!(config.workflows[0].steps[0].endpoint, "bar_endpoint");
 
         clear_config(&path);
         Ok(())
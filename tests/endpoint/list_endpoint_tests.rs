@@ -117,4 +117,56 @@ mod list_endpoint_tests {
         clear_config(&path);
         Ok(())
     }
+
+    #[test]
+    fn given_list_endpoint_command_with_tag_then_should_list_tagged_endpoints() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_user")
+                            .with_path("/users/{id}")
+                            .with_alias("user")
+                            .with_description("Get a single user")
+                            .with_tag("users")
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_order")
+                            .with_path("/orders/{id}")
+                            .with_tag("orders")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("endpoint")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--tag")
+            .arg("users")
+            .assert()
+            .success()
+            .stdout(" - get_user (user) ~ Get a single user\n");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("endpoint")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--tag")
+            .arg("admin")
+            .assert()
+            .success()
+            .stdout("No endpoints found for service `foo_service` with tag `admin`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
 }
@@ -1,4 +1,6 @@
 mod create_new_endpoint_tests;
 mod delete_endpoint_tests;
 mod edit_endpoint_tests;
-mod list_endpoint_tests;
\ No newline at end of file
+mod list_endpoint_tests;
+mod view_endpoint_tests;
+mod set_pagination_tests;
\ No newline at end of file
@@ -0,0 +1,87 @@
+mod set_pagination_tests {
+    use crate::common::builders::{EndpointBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::config::{HtrsConfig, PaginationStrategy};
+    use crate::common::test_helpers::{clear_config, get_config, setup};
+    use assert_cmd::Command;
+    use predicates::prelude::*;
+    use std::error::Error;
+
+    fn build_config() -> HtrsConfig {
+        HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/foo")
+                    )
+            )
+            .build()
+    }
+
+    #[test]
+    fn given_set_pagination_command_then_should_set_endpoint_pagination() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["set", "pagination", "foo_endpoint", "-s", "foo_service", "--strategy", "cursor", "--cursor", "$.next", "--parameter", "after", "--items", "$.data"])
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let pagination = config.services[0].endpoints[0].pagination.as_ref().unwrap();
+        assert_eq!(pagination.strategy, PaginationStrategy::Cursor { path: "$.next".to_string(), parameter: "after".to_string() });
+        assert_eq!(pagination.items, Some("$.data".to_string()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["view", "endpoint", "foo_endpoint", "-s", "foo_service"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Pagination: cursor from `$.next` as `after` (items at `$.data`)\n"));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["delete", "pagination", "foo_endpoint", "-s", "foo_service"])
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        assert!(config.services[0].endpoints[0].pagination.is_none());
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_page_strategy_without_parameter_when_set_pagination_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["set", "pagination", "foo_endpoint", "-s", "foo_service", "--strategy", "page"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--parameter <query param>"));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_endpoint_without_pagination_when_delete_pagination_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .args(["delete", "pagination", "foo_endpoint", "-s", "foo_service"])
+            .assert()
+            .failure()
+            .stdout("Endpoint `foo_endpoint` for service `foo_service` has no pagination set\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod view_endpoint_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::config::PaginationStrategy;
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use serde_json::json;
+    use std::error::Error;
+
+    #[test]
+    fn given_view_endpoint_command_with_unknown_endpoint_then_should_error() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("view")
+            .arg("endpoint")
+            .arg("unknown_endpoint")
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout("No endpoint could be found with name `unknown_endpoint` for service `foo_service`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_view_endpoint_command_with_known_endpoint_then_should_show_request_shape() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_header("X-Global", "global")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                    )
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("bar_environment")
+                            .with_host("bar.com")
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_method("POST")
+                            .with_path("/orders/{id}")
+                            .with_query_param("required_param", true)
+                            .with_query_param("optional_param", false)
+                            .with_header("Content-Type", "application/json")
+                            .with_body("{}")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("view")
+            .arg("endpoint")
+            .arg("foo_endpoint")
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .success()
+            .stdout("Name: foo_endpoint
+Method: POST
+Path: /orders/{id}
+Path Parameters:
+ - id
+Query Parameters:
+ - *required_param
+ - optional_param
+Headers:
+ - Content-Type: application/json (endpoint)
+ - X-Global: global (global)
+Body:
+{}
+Sample URLs:
+ - foo_environment: http://foo.com/orders/{id}?required_param={required_param}
+ - bar_environment: http://bar.com/orders/{id}?required_param={required_param}
+
+");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_view_endpoint_command_with_json_format_then_should_print_endpoint_view() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_header("X-Global", "global")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/orders/{id}")
+                            .with_query_param("cursor", false)
+                            .with_parameter_description("id", "Id of the order")
+                            .with_pagination(PaginationStrategy::Cursor { path: "$.next".to_string(), parameter: "cursor".to_string() }, Some("$.items"))
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        // The format is global to the view commands so can be given before the subcommand
+        let output = Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("view")
+            .arg("--format")
+            .arg("json")
+            .arg("endpoint")
+            .arg("foo_endpoint")
+            .arg("--service")
+            .arg("foo_service")
+            .output()?;
+        assert!(output.status.success());
+
+        let endpoint: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        assert_eq!(endpoint["name"], "foo_endpoint");
+        assert_eq!(endpoint["method"], "GET");
+        assert_eq!(endpoint["path"], "/orders/{id}");
+        assert_eq!(endpoint["path_parameters"], json!([{ "name": "id", "required": true, "description": "Id of the order" }]));
+        assert_eq!(endpoint["query_parameters"], json!([{ "name": "cursor", "required": false, "description": null }]));
+        assert_eq!(endpoint["pagination"], json!({ "strategy": "cursor", "parameter": "cursor", "cursor": "$.next", "items": "$.items" }));
+        assert_eq!(endpoint["effective_headers"], json!([{ "name": "X-Global", "value": "global", "scope": "global" }]));
+        assert_eq!(endpoint["sample_urls"], json!([{ "environment": "foo_environment", "url": "http://foo.com/orders/{id}" }]));
+        assert!(endpoint.get("path_template").is_none());
+        assert!(endpoint.get("parameter_descriptions").is_none());
+
+        clear_config(&path);
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod copy_environment_tests {
+    use crate::common::builders::{EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, get_config, setup};
+    use assert_cmd::Command;
+    use std::error::Error;
+
+    #[test]
+    fn given_unknown_environment_when_copy_environment_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("copy")
+            .arg("environment")
+            .arg("foo_environment")
+            .arg("bar_environment")
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout("Unable to find environment with name or alias `foo_environment` for service `foo_service`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_existing_environment_name_when_copy_environment_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                    )
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("bar_environment")
+                            .with_host("bar.com")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("copy")
+            .arg("environment")
+            .arg("foo_environment")
+            .arg("bar_environment")
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout("Service `foo_service` already has an environment with name or alias `bar_environment`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_known_environment_when_copy_environment_with_host_then_should_copy_headers() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_alias("foo")
+                            .with_host("eu.foo.com")
+                            .with_header("X-Region", "eu")
+                            .with_default()
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("copy")
+            .arg("env")
+            .arg("foo")
+            .arg("bar_environment")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--host")
+            .arg("us.foo.com")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let environments = &config.services[0].environments;
+        assert_eq!(environments.len(), 2);
+        assert!(environments[0].default);
+        let environment = &environments[1];
+        assert_eq!(environment.name, "bar_environment");
+        assert_eq!(environment.alias, None);
+        assert_eq!(environment.host, "us.foo.com");
+        assert!(!environment.default);
+        assert_eq!(environment.headers.get("X-Region"), Some(&"eu".to_string()));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_to_service_when_copy_environment_then_should_create_in_other_service() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                    )
+            )
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("bar_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("bar_environment")
+                            .with_host("bar.com")
+                            .with_default()
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("copy")
+            .arg("environment")
+            .arg("foo_environment")
+            .arg("foo_environment")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--to-service")
+            .arg("bar_service")
+            .arg("--default")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        assert_eq!(config.services[0].environments.len(), 1);
+        let environments = &config.services[1].environments;
+        assert_eq!(environments.len(), 2);
+        assert!(!environments[0].default);
+        assert_eq!(environments[1].name, "foo_environment");
+        assert_eq!(environments[1].host, "foo.com");
+        assert!(environments[1].default);
+
+        clear_config(&path);
+        Ok(())
+    }
+}
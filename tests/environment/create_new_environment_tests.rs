@@ -85,7 +85,7 @@ mod create_new_environment_tests {
         assert_eq!(environment.name, "foo_environment");
         assert_eq!(environment.alias, None);
         assert_eq!(environment.host, "google.com");
-        assert_eq!(environment.default, false);
+        assert!(!environment.default);
 
         clear_config(&path);
         Ok(())
@@ -121,7 +121,7 @@ mod create_new_environment_tests {
         assert_eq!(environment.name, "foo_environment");
         assert_eq!(environment.alias, Some("foo_alias".to_string()));
         assert_eq!(environment.host, "google.com");
-        assert_eq!(environment.default, false);
+        assert!(!environment.default);
 
         clear_config(&path);
         Ok(())
@@ -156,7 +156,7 @@ mod create_new_environment_tests {
         assert_eq!(environment.name, "foo_environment");
         assert_eq!(environment.alias, None);
         assert_eq!(environment.host, "google.com");
-        assert_eq!(environment.default, true);
+        assert!(environment.default);
 
         clear_config(&path);
         Ok(())
@@ -263,12 +263,12 @@ mod create_new_environment_tests {
         let service = &config.services[0];
         assert_eq!(service.environments.len(), 2);
         let existing_environment = &service.environments[0];
-        assert_eq!(existing_environment.default, false);
+        assert!(!existing_environment.default);
         let new_environment = &service.environments[1];
         assert_eq!(new_environment.name, "new_environment");
         assert_eq!(new_environment.alias, None);
         assert_eq!(new_environment.host, "google.com");
-        assert_eq!(new_environment.default, true);
+        assert!(new_environment.default);
 
         clear_config(&path);
         Ok(())
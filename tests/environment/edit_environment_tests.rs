@@ -110,7 +110,7 @@ mod edit_environment_tests {
         assert_eq!(environment.name, "new_name");
         assert_eq!(environment.alias, Some("new_alias".to_string()));
         assert_eq!(environment.host, "newhost.com");
-        assert_eq!(environment.default, true);
+        assert!(environment.default);
 
         clear_config(&path);
         Ok(())
@@ -231,10 +231,10 @@ mod edit_environment_tests {
         let service = &config.services[0];
         let existing_default_environment = &service.environments[0];
         assert_eq!(existing_default_environment.name, "existing_default");
-        assert_eq!(existing_default_environment.default, false);
+        assert!(!existing_default_environment.default);
         let new_default_environment = &service.environments[1];
         assert_eq!(new_default_environment.name, "foo_environment");
-        assert_eq!(new_default_environment.default, true);
+        assert!(new_default_environment.default);
 
         clear_config(&path);
         Ok(())
@@ -184,4 +184,37 @@ mod list_environments_tests {
         clear_config(&path);
         Ok(())
     }
+
+    #[test]
+    fn given_list_environments_command_with_yaml_format_then_should_print_environment_views() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_alias("foo")
+                            .with_host("foo.com")
+                            .with_default()
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("environment")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--format")
+            .arg("yaml")
+            .assert()
+            .success()
+            .stdout("- alias: foo\n  default: true\n  headers: {}\n  host: foo.com\n  name: foo_environment\n");
+
+        clear_config(&path);
+        Ok(())
+    }
 }
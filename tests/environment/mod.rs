@@ -1,4 +1,5 @@
 mod create_new_environment_tests;
 mod delete_environment_tests;
 mod edit_environment_tests;
-mod list_environments_tests;
\ No newline at end of file
+mod list_environments_tests;
+mod view_environment_tests;mod copy_environment_tests;
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod view_environment_tests {
+    use crate::common::builders::{EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use std::error::Error;
+
+    #[test]
+    fn given_view_environment_command_with_unknown_environment_then_should_error() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("view")
+            .arg("environment")
+            .arg("unknown_environment")
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout("Unable to find environment with name or alias `unknown_environment` for service `foo_service`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_view_environment_command_with_known_environment_then_should_show_headers_with_scope() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_header("X-Global", "global")
+            .with_header("X-Overridden", "global")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_header("X-Service", "service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_alias("foo_env_alias")
+                            .with_host("foo.com")
+                            .with_default()
+                            .with_header("X-Overridden", "environment")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("view")
+            .arg("environment")
+            .arg("foo_env_alias")
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .success()
+            .stdout("Name: foo_environment
+ Alias: foo_env_alias
+Host: foo.com
+Default: true
+Headers:
+ - X-Global: global (global)
+ - X-Overridden: environment (environment)
+ - X-Service: service (service)
+
+");
+
+        clear_config(&path);
+        Ok(())
+    }
+}
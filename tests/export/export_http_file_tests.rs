@@ -0,0 +1,148 @@
+mod export_http_file_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, get_config, setup};
+    use assert_cmd::Command;
+    use std::error::Error;
+    use uuid::Uuid;
+
+    #[test]
+    fn given_service_without_default_environment_when_export_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("export")
+            .arg("http-file")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout("Service `foo_service` has no default environment, use `--environment <name>` to choose one\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_known_service_when_export_then_should_print_http_file() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_header("X-Global", "global")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_method("POST")
+                            .with_path("/orders/{id}")
+                            .with_query_param("required_param", true)
+                            .with_query_param("optional_param", false)
+                            .with_header("Content-Type", "application/json")
+                            .with_body("{}")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("export")
+            .arg("http-file")
+            .arg("foo_service")
+            .arg("--environment")
+            .arg("foo_environment")
+            .assert()
+            .success()
+            .stdout("@host = http://foo.com
+
+### foo_endpoint
+# @name foo_endpoint
+POST {{host}}/orders/{{id}}?required_param={{required_param}}
+# &optional_param={{optional_param}}
+Content-Type: application/json
+X-Global: global
+
+{}
+");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_exported_http_file_when_import_then_should_recreate_query_parameters() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_orders")
+                            .with_path("/orders")
+                            .with_query_param("page", false)
+                            .with_query_param("size", false)
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_order")
+                            .with_path("/orders/{id}")
+                            .with_query_param("expand", true)
+                            .with_query_param("debug", false)
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+        let http_file_path = format!("{}.http", Uuid::new_v4());
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("export")
+            .arg("http-file")
+            .arg("foo_service")
+            .arg("--output")
+            .arg(&http_file_path)
+            .assert()
+            .success();
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("http-file")
+            .arg(&http_file_path)
+            .arg("--service")
+            .arg("bar_service")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let original = &config.services[0];
+        let imported = &config.services[1];
+        assert_eq!(imported.endpoints.len(), 2);
+        for (original, imported) in original.endpoints.iter().zip(&imported.endpoints) {
+            assert_eq!(imported.name, original.name);
+            assert_eq!(imported.path_template, original.path_template);
+            let names: Vec<(&str, bool)> = imported.query_parameters.iter().map(|q| (q.name.as_str(), q.required)).collect();
+            let expected: Vec<(&str, bool)> = original.query_parameters.iter().map(|q| (q.name.as_str(), q.required)).collect();
+            assert_eq!(names, expected);
+        }
+
+        clear_config(&path);
+        clear_config(&http_file_path);
+        Ok(())
+    }
+}
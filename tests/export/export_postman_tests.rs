@@ -0,0 +1,75 @@
+mod export_postman_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use std::error::Error;
+
+    #[test]
+    fn given_unknown_service_when_export_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("export")
+            .arg("postman")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout("No service could be found with name or alias `foo_service`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_known_service_when_export_then_should_print_collection() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_header("X-Global", "global")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_method("POST")
+                            .with_path("/orders/{id}")
+                            .with_query_param("required_param", true)
+                            .with_query_param("optional_param", false)
+                            .with_body("{}")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        let output = Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("export")
+            .arg("postman")
+            .arg("foo_service")
+            .output()?;
+        assert!(output.status.success());
+
+        let collection: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        assert_eq!(collection["info"]["name"], "foo_service");
+        assert_eq!(collection["variable"][0]["key"], "baseUrl");
+        assert_eq!(collection["variable"][0]["value"], "http://foo.com");
+
+        let request = &collection["item"][0]["request"];
+        assert_eq!(collection["item"][0]["name"], "foo_endpoint");
+        assert_eq!(request["method"], "POST");
+        assert_eq!(request["url"]["raw"], "{{baseUrl}}/orders/{{id}}?required_param={{required_param}}");
+        assert_eq!(request["url"]["query"][1]["key"], "optional_param");
+        assert_eq!(request["url"]["query"][1]["disabled"], serde_json::Value::Bool(true));
+        assert_eq!(request["header"][0]["key"], "X-Global");
+        assert_eq!(request["body"]["raw"], "{}");
+
+        clear_config(&path);
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+mod export_postman_tests;
+mod export_http_file_tests;
@@ -0,0 +1,152 @@
+mod history_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use httptest::matchers::request;
+    use httptest::responders::status_code;
+    use httptest::{all_of, Expectation, ServerPool};
+    use predicates::prelude::*;
+    use std::error::Error;
+
+    static SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    #[test]
+    fn given_no_requests_sent_when_list_history_then_should_print_empty_message() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("history")
+            .arg("list")
+            .assert()
+            .success()
+            .stdout("No requests in history\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_unknown_id_when_show_history_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("history")
+            .arg("show")
+            .arg("1")
+            .assert()
+            .failure()
+            .stdout("No request could be found in history with id `1`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_sent_request_when_show_and_replay_history_then_should_redact_secrets_and_resend() -> Result<(), Box<dyn Error>> {
+        let server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("GET"),
+                request::path("/users/1"),
+                request::headers(httptest::matchers::contains(("authorization", "Bearer secret"))),
+            ])
+            .times(2)
+            .respond_with(status_code(200).body("{\"id\":1}")),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_header("Authorization", "Bearer secret")
+            .with_secret_header("Authorization")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host(server.addr().to_string().as_str())
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_user")
+                            .with_path("/users/{id}")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("call")
+            .arg("foo_service")
+            .arg("get_user")
+            .arg("--id")
+            .arg("1")
+            .arg("--save-response")
+            .assert()
+            .success();
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("history")
+            .arg("list")
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with(" 1 | ").and(predicate::str::contains(" | 200 | GET foo_service get_user @ foo_environment | ")));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("history")
+            .arg("show")
+            .arg("1")
+            .assert()
+            .success()
+            .stdout(
+                predicate::str::contains(format!("Request: GET http://{}/users/1\n", server.addr()))
+                    .and(predicate::str::contains(" - id = 1\n"))
+                    .and(predicate::str::contains(" - authorization: <redacted>\n"))
+                    .and(predicate::str::contains("Response:\n{\"id\":1}\n"))
+                    .and(predicate::str::contains("Bearer secret").not())
+            );
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("history")
+            .arg("replay")
+            .arg("1")
+            .arg("--body")
+            .assert()
+            .success()
+            .stdout("{\"id\":1}\n");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("history")
+            .arg("list")
+            .arg("--format")
+            .arg("json")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"id\": 2").and(predicate::str::contains("\"id\": 1")));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("history")
+            .arg("clear")
+            .assert()
+            .success()
+            .stdout("Cleared request history\n");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("history")
+            .arg("list")
+            .assert()
+            .success()
+            .stdout("No requests in history\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+}
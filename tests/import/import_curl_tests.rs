@@ -0,0 +1,232 @@
+mod import_curl_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, get_config, setup};
+    use assert_cmd::Command;
+    use std::error::Error;
+
+    const CURL: &str = "curl -X POST 'http://api.foo.com/orders?limit=10' -H 'Content-Type: application/json' -d '{\"id\": 1}'";
+
+    #[test]
+    fn given_unknown_service_when_import_curl_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("curl")
+            .arg(CURL)
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("foo_endpoint")
+            .assert()
+            .failure()
+            .stdout("Unable to find service with name or alias `foo_service`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_curl_command_when_import_then_should_create_endpoint() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("curl")
+            .arg(CURL)
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("foo_endpoint")
+            .assert()
+            .success()
+            .stdout("Created endpoint `foo_endpoint` for service `foo_service`\nNo environment for service `foo_service` has host `api.foo.com`, use `--environment <name>` to create one\n");
+
+        let config = get_config(&path);
+        let endpoint = &config.services[0].endpoints[0];
+        assert_eq!(endpoint.name, "foo_endpoint");
+        assert_eq!(endpoint.method, "POST");
+        assert_eq!(endpoint.path_template, "/orders");
+        assert_eq!(endpoint.query_parameters.len(), 1);
+        assert_eq!(endpoint.query_parameters[0].name, "limit");
+        assert_eq!(endpoint.headers.get("Content-Type"), Some(&"application/json".to_string()));
+        assert_eq!(endpoint.body, Some("{\"id\": 1}".to_string()));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_curl_command_with_repeated_query_parameter_when_import_then_should_add_it_once() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("curl")
+            .arg("curl 'http://api.foo.com/orders?a=1&b=2&a=3'")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("foo_endpoint")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let names: Vec<&str> = config.services[0].endpoints[0].query_parameters.iter().map(|q| q.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_https_curl_command_when_import_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("curl")
+            .arg("curl https://api.foo.com/orders")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("foo_endpoint")
+            .assert()
+            .failure()
+            .stdout("Curl url `https://api.foo.com/orders` uses scheme `https`, but endpoints are always called over http\n");
+        assert!(get_config(&path).services[0].endpoints.is_empty());
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_curl_command_with_environment_and_preset_when_import_then_should_create_them() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("curl")
+            .arg(CURL)
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("foo_endpoint")
+            .arg("--environment")
+            .arg("prod")
+            .arg("--preset")
+            .arg("foo_preset")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let environment = &config.services[0].environments[0];
+        assert_eq!(environment.name, "prod");
+        assert_eq!(environment.host, "api.foo.com");
+        assert!(environment.default);
+        let preset = &config.presets[0];
+        assert_eq!(preset.name, "foo_preset");
+        assert_eq!(preset.values.get("limit"), Some(&"10".to_string()));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_curl_command_with_host_matching_environment_when_import_then_should_report_match() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("prod")
+                            .with_host("api.foo.com")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("curl")
+            .arg(CURL)
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("foo_endpoint")
+            .arg("--environment")
+            .arg("other")
+            .assert()
+            .success()
+            .stdout("Created endpoint `foo_endpoint` for service `foo_service`\nHost `api.foo.com` matches environment `prod`\n");
+
+        let config = get_config(&path);
+        assert_eq!(config.services[0].environments.len(), 1);
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_existing_endpoint_when_import_curl_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/path")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("curl")
+            .arg(CURL)
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("foo_endpoint")
+            .assert()
+            .failure()
+            .stdout("Service `foo_service` already has an endpoint named `foo_endpoint`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+}
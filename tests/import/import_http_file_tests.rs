@@ -0,0 +1,97 @@
+mod import_http_file_tests {
+    use crate::common::builders::{EndpointBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, get_config, setup, setup_file};
+    use assert_cmd::Command;
+    use std::error::Error;
+
+    const HTTP_FILE: &str = "@host = https://api.foo.com
+
+### Get Order
+GET {{host}}/orders/{{id}}?expand={{expand}}&debug=true
+Accept: application/json
+
+###
+# @name create-order
+POST {{host}}/orders
+Content-Type: application/json
+
+{\"item\": 1}
+";
+
+    #[test]
+    fn given_http_file_when_import_then_should_create_service_and_endpoints() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+        let http_file_path = setup_file(HTTP_FILE, "http");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("http-file")
+            .arg(&http_file_path)
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .success()
+            .stdout("Created service `foo_service`\nImported 2 endpoints into service `foo_service`\nCreated environment `default` with host `api.foo.com`\n");
+
+        let config = get_config(&path);
+        let service = &config.services[0];
+        assert_eq!(service.name, "foo_service");
+        assert_eq!(service.environments[0].name, "default");
+        assert_eq!(service.environments[0].host, "api.foo.com");
+        assert!(service.environments[0].default);
+
+        let get_order = &service.endpoints[0];
+        assert_eq!(get_order.name, "get-order");
+        assert_eq!(get_order.method, "GET");
+        assert_eq!(get_order.path_template, "/orders/{id}");
+        assert_eq!(get_order.query_parameters.len(), 2);
+        assert!(get_order.query_parameters[0].required);
+        assert!(!get_order.query_parameters[1].required);
+        assert_eq!(get_order.headers.get("Accept"), Some(&"application/json".to_string()));
+
+        let create_order = &service.endpoints[1];
+        assert_eq!(create_order.name, "create-order");
+        assert_eq!(create_order.method, "POST");
+        assert_eq!(create_order.body, Some("{\"item\": 1}".to_string()));
+
+        clear_config(&path);
+        clear_config(&http_file_path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_existing_endpoint_when_import_http_file_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get-order")
+                            .with_path("/")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+        let http_file_path = setup_file(HTTP_FILE, "http");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("http-file")
+            .arg(&http_file_path)
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout("Service `foo_service` already has an endpoint named `get-order`\n");
+
+        let config = get_config(&path);
+        assert_eq!(config.services[0].endpoints.len(), 1);
+
+        clear_config(&path);
+        clear_config(&http_file_path);
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+mod import_postman_tests {
+    use crate::common::builders::{HtrsConfigBuilder, PresetBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, get_config, setup, setup_file};
+    use assert_cmd::Command;
+    use std::error::Error;
+
+    const COLLECTION: &str = r#"{
+        "info": { "name": "Order Service", "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json" },
+        "item": [
+            {
+                "name": "Orders",
+                "item": [
+                    {
+                        "name": "Get Order",
+                        "request": {
+                            "method": "GET",
+                            "header": [{ "key": "Accept", "value": "application/json" }],
+                            "url": {
+                                "raw": "{{baseUrl}}/orders/:orderId?expand={{expand}}&debug=true",
+                                "host": ["{{baseUrl}}"],
+                                "path": ["orders", ":orderId"],
+                                "query": [
+                                    { "key": "expand", "value": "{{expand}}" },
+                                    { "key": "debug", "value": "true", "disabled": true }
+                                ]
+                            }
+                        }
+                    }
+                ]
+            },
+            {
+                "name": "Create Order",
+                "request": {
+                    "method": "POST",
+                    "url": "{{baseUrl}}/orders/{{customerId}}",
+                    "body": { "mode": "raw", "raw": "{\"item\": 1}" }
+                }
+            }
+        ],
+        "variable": [{ "key": "baseUrl", "value": "https://orders.example.com" }]
+    }"#;
+
+    const LITERAL_QUERY_COLLECTION: &str = r#"{
+        "info": { "name": "Search", "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json" },
+        "item": [
+            {
+                "name": "???",
+                "item": [
+                    {
+                        "name": "Find Items",
+                        "request": { "method": "GET", "url": "https://search.example.com/items?limit=20&sort=name&q={{q}}" }
+                    }
+                ]
+            },
+            {
+                "name": "!!",
+                "request": { "method": "GET", "url": "https://search.example.com/health?verbose=true" }
+            }
+        ]
+    }"#;
+
+    const ENVIRONMENT: &str = r#"{
+        "name": "staging",
+        "values": [{ "key": "baseUrl", "value": "https://staging.orders.example.com/", "enabled": true }]
+    }"#;
+
+    #[test]
+    fn given_postman_collection_when_import_then_should_create_service_with_endpoints() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+        let collection_path = setup_file(COLLECTION, "json");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("postman")
+            .arg(&collection_path)
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        assert_eq!(config.services.len(), 1);
+        let service = &config.services[0];
+        assert_eq!(service.name, "order-service");
+        assert_eq!(service.endpoints.len(), 2);
+
+        let get_order = &service.endpoints[0];
+        assert_eq!(get_order.name, "orders-get-order");
+        assert_eq!(get_order.method, "GET");
+        assert_eq!(get_order.path_template, "/orders/{orderId}");
+        assert_eq!(get_order.headers.get("Accept"), Some(&"application/json".to_string()));
+        assert_eq!(get_order.query_parameters.len(), 2);
+        assert_eq!(get_order.query_parameters[0].name, "expand");
+        assert!(get_order.query_parameters[0].required);
+        assert_eq!(get_order.query_parameters[1].name, "debug");
+        assert!(!get_order.query_parameters[1].required);
+
+        let create_order = &service.endpoints[1];
+        assert_eq!(create_order.name, "create-order");
+        assert_eq!(create_order.method, "POST");
+        assert_eq!(create_order.path_template, "/orders/{customerId}");
+        assert_eq!(create_order.body, Some("{\"item\": 1}".to_string()));
+
+        assert_eq!(service.environments.len(), 1);
+        assert_eq!(service.environments[0].name, "default");
+        assert_eq!(service.environments[0].host, "orders.example.com");
+        assert!(service.environments[0].default);
+
+        clear_config(&path);
+        clear_config(&collection_path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_postman_environment_when_import_then_should_create_environment() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+        let collection_path = setup_file(COLLECTION, "json");
+        let environment_path = setup_file(ENVIRONMENT, "json");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("postman")
+            .arg(&collection_path)
+            .arg("--name")
+            .arg("orders")
+            .arg("--environment")
+            .arg(&environment_path)
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let service = &config.services[0];
+        assert_eq!(service.name, "orders");
+        assert_eq!(service.environments.len(), 1);
+        assert_eq!(service.environments[0].name, "staging");
+        assert_eq!(service.environments[0].host, "staging.orders.example.com");
+        assert!(service.environments[0].default);
+
+        clear_config(&path);
+        clear_config(&collection_path);
+        clear_config(&environment_path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_existing_service_name_when_import_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("order-service")
+            )
+            .build();
+        let path = setup(Some(config));
+        let collection_path = setup_file(COLLECTION, "json");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("postman")
+            .arg(&collection_path)
+            .assert()
+            .failure()
+            .stdout("A service already exists with the name or alias 'order-service'\n");
+
+        clear_config(&path);
+        clear_config(&collection_path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_missing_collection_file_when_import_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("postman")
+            .arg("missing_collection.json")
+            .assert()
+            .failure();
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_literal_query_values_when_import_then_should_create_preset_with_values() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+        let collection_path = setup_file(LITERAL_QUERY_COLLECTION, "json");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("postman")
+            .arg(&collection_path)
+            .assert()
+            .success()
+            .stdout("Created preset `search` with the collection's query parameter values\n");
+
+        let config = get_config(&path);
+        let service = &config.services[0];
+        assert_eq!(service.endpoints.len(), 2);
+        assert_eq!(service.endpoints[0].name, "find-items");
+        assert_eq!(service.endpoints[1].name, "request-2");
+
+        assert_eq!(config.presets.len(), 1);
+        let preset = &config.presets[0];
+        assert_eq!(preset.name, "search");
+        assert_eq!(preset.values.len(), 3);
+        assert_eq!(preset.values.get("limit"), Some(&"20".to_string()));
+        assert_eq!(preset.values.get("sort"), Some(&"name".to_string()));
+        assert_eq!(preset.values.get("verbose"), Some(&"true".to_string()));
+
+        clear_config(&path);
+        clear_config(&collection_path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_existing_preset_name_when_import_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_preset(
+                PresetBuilder::new()
+                    .with_name("search")
+            )
+            .build();
+        let path = setup(Some(config));
+        let collection_path = setup_file(LITERAL_QUERY_COLLECTION, "json");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("postman")
+            .arg(&collection_path)
+            .assert()
+            .failure()
+            .stdout("A preset with name or alias `search` already exists, use `--preset` to name the preset for the collection's query values\n");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("postman")
+            .arg(&collection_path)
+            .arg("--preset")
+            .arg("search-defaults")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        assert_eq!(config.presets.len(), 2);
+        assert_eq!(config.presets[1].name, "search-defaults");
+
+        clear_config(&path);
+        clear_config(&collection_path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_collection_name_without_command_characters_when_import_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+        let collection_path = setup_file(&COLLECTION.replace("Order Service", "???"), "json");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("import")
+            .arg("postman")
+            .arg(&collection_path)
+            .assert()
+            .failure()
+            .stdout("Collection name `???` can't be used as a service name, use `--name` to give one\n");
+
+        clear_config(&path);
+        clear_config(&collection_path);
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+mod import_postman_tests;
+mod import_curl_tests;
+mod import_http_file_tests;
@@ -0,0 +1,66 @@
+#[allow(dead_code)]
+mod common;
+
+#[cfg(test)]
+mod list_header_tests {
+    use crate::common::builders::{EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use rstest::rstest;
+    use std::error::Error;
+
+    #[test]
+    fn given_list_header_command_with_unknown_service_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("header")
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout("No service could be found with name or alias `foo_service`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&[], " - X-Global: global\n - X-Shared: global\n")]
+    #[case(&["--service", "foo_service"], " - X-Service: service\n")]
+    #[case(&["--service", "foo_service", "--environment", "foo_environment"], " - X-Shared: environment\n")]
+    #[case(&["--service", "foo_service", "--effective"], " - X-Global: global (global)\n - X-Service: service (service)\n - X-Shared: global (global)\n")]
+    #[case(&["--service", "foo_service", "--environment", "foo_environment", "--effective"], " - X-Global: global (global)\n - X-Service: service (service)\n - X-Shared: environment (environment)\n")]
+    fn given_headers_at_each_scope_when_list_header_then_should_print_scope(#[case] args: &[&str], #[case] expected: &str) -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_header("X-Global", "global")
+            .with_header("X-Shared", "global")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_header("X-Service", "service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                            .with_header("X-Shared", "environment")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("header")
+            .args(args)
+            .assert()
+            .success()
+            .stdout(expected.to_string());
+
+        clear_config(&path);
+        Ok(())
+    }
+}
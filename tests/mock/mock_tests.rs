@@ -0,0 +1,211 @@
+mod mock_tests {
+    use crate::common::builders::{EndpointBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, setup, setup_file};
+    use assert_cmd::cargo::CommandCargoExt;
+    use assert_cmd::Command;
+    use predicates::prelude::*;
+    use std::error::Error;
+    use std::fs::remove_file;
+    use std::io::{BufRead, BufReader, Lines, Read, Write};
+    use std::net::{Shutdown, TcpStream};
+    use std::process::{Child, ChildStdout, Stdio};
+
+    /// A running `htrs mock` process which is killed when dropped
+    struct RunningMock {
+        process: Child,
+        output: Lines<BufReader<ChildStdout>>,
+        url: String,
+    }
+
+    impl RunningMock {
+        fn start(config_path: &str, service: &str) -> Result<RunningMock, Box<dyn Error>> {
+            let mut process = std::process::Command::cargo_bin("htrs")?
+                .env("HTRS_CONFIG_PATH", config_path)
+                .arg("mock")
+                .arg(service)
+                .arg("--port")
+                .arg("0")
+                .stdout(Stdio::piped())
+                .spawn()?;
+            let mut output = BufReader::new(process.stdout.take().unwrap()).lines();
+            let started = output.next().unwrap()?;
+            let url = started.split_once(" on ").unwrap().1.to_string();
+            Ok(RunningMock { process, output, url })
+        }
+
+        fn next_log(&mut self) -> String {
+            self.output.next().unwrap().unwrap()
+        }
+    }
+
+    impl Drop for RunningMock {
+        fn drop(&mut self) {
+            let _ = self.process.kill();
+            let _ = self.process.wait();
+        }
+    }
+
+    #[test]
+    fn given_endpoints_with_mocks_when_mock_then_should_serve_matching_responses() -> Result<(), Box<dyn Error>> {
+        let file_path = setup_file("[{\"id\":1},{\"id\":2}]", "json");
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_user")
+                            .with_path("/users/{id}")
+                            .with_mock(200, Some("{\"id\":1,\"name\":\"foo\"}"), None)
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_me")
+                            .with_path("/users/me")
+                            .with_mock(401, Some("Unauthorized"), None)
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("search_users")
+                            .with_path("/users")
+                            .with_query_param("q", true)
+                            .with_mock(200, None, Some(&file_path))
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+        let mut mock = RunningMock::start(&path, "foo_service")?;
+
+        let response = reqwest::blocking::get(format!("{}/users/1", mock.url))?;
+        assert_eq!(response.status().as_u16(), 200);
+        assert_eq!(response.headers()["content-type"], "application/json");
+        assert_eq!(response.text()?, "{\"id\":1,\"name\":\"foo\"}");
+        assert_eq!(mock.next_log(), "GET /users/1 -> 200 get_user");
+
+        let response = reqwest::blocking::get(format!("{}/users/me", mock.url))?;
+        assert_eq!(response.status().as_u16(), 401);
+        assert_eq!(mock.next_log(), "GET /users/me -> 401 get_me");
+
+        let response = reqwest::blocking::get(format!("{}/users?q=foo", mock.url))?;
+        assert_eq!(response.text()?, "[{\"id\":1},{\"id\":2}]");
+        assert_eq!(mock.next_log(), "GET /users?q=foo -> 200 search_users");
+
+        drop(mock);
+        clear_config(&path);
+        remove_file(&file_path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn given_unmatched_request_when_mock_then_should_respond_not_found_and_log() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("search_users")
+                            .with_path("/users")
+                            .with_query_param("q", true)
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+        let mut mock = RunningMock::start(&path, "foo_service")?;
+
+        let response = reqwest::blocking::get(format!("{}/users", mock.url))?;
+        assert_eq!(response.status().as_u16(), 404);
+        assert_eq!(response.text()?, "No endpoint matches `GET /users`");
+        assert_eq!(mock.next_log(), "GET /users -> 404 (unmatched)");
+
+        let response = reqwest::blocking::Client::new().post(format!("{}/users?q=foo", mock.url)).body("{}").send()?;
+        assert_eq!(response.status().as_u16(), 404);
+        assert_eq!(mock.next_log(), "POST /users?q=foo -> 404 (unmatched)");
+
+        let response = reqwest::blocking::get(format!("{}/users?q=foo", mock.url))?;
+        assert_eq!(response.status().as_u16(), 200);
+        assert_eq!(mock.next_log(), "GET /users?q=foo -> 200 search_users (no mock response set)");
+
+        drop(mock);
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_mock_with_content_length_header_when_mock_then_should_send_it_once() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("get_user")
+                            .with_path("/users/{id}")
+                            .with_mock(200, Some("hello"), None)
+                            .with_mock_header("Content-Length", "5")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+        let mock = RunningMock::start(&path, "foo_service")?;
+
+        let mut stream = TcpStream::connect(mock.url.trim_start_matches("http://"))?;
+        stream.write_all(b"GET /users/1 HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        assert_eq!(response.to_lowercase().matches("content-length:").count(), 1);
+        assert!(response.ends_with("\r\n\r\nhello"));
+
+        drop(mock);
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_request_with_huge_content_length_when_mock_then_should_not_allocate_it() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("create_user")
+                            .with_path("/users")
+                            .with_method("POST")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+        let mock = RunningMock::start(&path, "foo_service")?;
+
+        let mut stream = TcpStream::connect(mock.url.trim_start_matches("http://"))?;
+        stream.write_all(b"POST /users HTTP/1.1\r\nHost: localhost\r\nContent-Length: 1000000000000000\r\n\r\n{}")?;
+        stream.shutdown(Shutdown::Write)?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+
+        let response = reqwest::blocking::Client::new().post(format!("{}/users", mock.url)).body("{}").send()?;
+        assert_eq!(response.status().as_u16(), 200);
+
+        drop(mock);
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_unknown_service_when_mock_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("mock")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout(predicate::eq("No service could be found with name or alias `foo_service`\n"));
+
+        clear_config(&path);
+        Ok(())
+    }
+}
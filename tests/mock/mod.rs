@@ -0,0 +1,2 @@
+mod mock_tests;
+mod set_mock_tests;
\ No newline at end of file
@@ -0,0 +1,104 @@
+mod set_mock_tests {
+    use crate::common::builders::{EndpointBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::config::HtrsConfig;
+    use crate::common::test_helpers::{clear_config, get_config, setup};
+    use assert_cmd::Command;
+    use std::error::Error;
+
+    fn build_config() -> HtrsConfig {
+        HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/foo")
+                    )
+            )
+            .build()
+    }
+
+    #[test]
+    fn given_set_mock_command_then_should_set_endpoint_mock() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("set")
+            .arg("mock")
+            .arg("foo_endpoint")
+            .arg("-s")
+            .arg("foo_service")
+            .arg("--status")
+            .arg("201")
+            .arg("--body")
+            .arg("created")
+            .arg("--header")
+            .arg("X-Foo=bar")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let mock = config.services[0].endpoints[0].mock.as_ref().unwrap();
+        assert_eq!(mock.status, 201);
+        assert_eq!(mock.body, Some("created".to_string()));
+        assert_eq!(mock.headers.get("X-Foo"), Some(&"bar".to_string()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("delete")
+            .arg("mock")
+            .arg("foo_endpoint")
+            .arg("-s")
+            .arg("foo_service")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        assert!(config.services[0].endpoints[0].mock.is_none());
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_missing_file_when_set_mock_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("set")
+            .arg("mock")
+            .arg("foo_endpoint")
+            .arg("-s")
+            .arg("foo_service")
+            .arg("--file")
+            .arg("missing.json")
+            .assert()
+            .failure()
+            .stdout("No file could be found at `missing.json`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_endpoint_without_mock_when_delete_mock_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(build_config()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("delete")
+            .arg("mock")
+            .arg("foo_endpoint")
+            .arg("-s")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout("Endpoint `foo_endpoint` for service `foo_service` has no mock response\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+}
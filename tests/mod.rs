@@ -1,7 +1,20 @@
+#![allow(clippy::module_inception)]
+
 mod environment;
 mod endpoint;
+#[allow(dead_code)]
 mod common;
 mod service;
 mod presets;
+mod import;
+mod export;
+mod completions;
+mod shell;
+mod history;
+mod compare;
+mod testing;
+mod workflow;
+mod ping;
+mod mock;
 
 mod call;
\ No newline at end of file
@@ -0,0 +1,190 @@
+mod ping_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use httptest::matchers::request;
+    use httptest::responders::status_code;
+    use httptest::{all_of, Expectation, ServerPool};
+    use predicates::prelude::*;
+    use std::error::Error;
+    use std::fs::File;
+    use uuid::Uuid;
+
+    // Servers are always taken from the healthy pool first so that tests can't deadlock
+    static HEALTHY_SERVER_POOL: ServerPool = ServerPool::new(1);
+    static UNHEALTHY_SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    fn get_health_endpoint() -> EndpointBuilder {
+        EndpointBuilder::new()
+            .with_name("health")
+            .with_path("/health")
+    }
+
+    #[test]
+    fn given_healthy_environments_when_ping_then_should_succeed() -> Result<(), Box<dyn Error>> {
+        let mut server = HEALTHY_SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("GET"),
+                request::path("/health"),
+            ]).respond_with(status_code(200)),
+        );
+        let host = server.addr().to_string();
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_health_endpoint("health")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("local")
+                            .with_host(&host)
+                    )
+                    .with_endpoint(get_health_endpoint())
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("ping")
+            .arg("foo_service")
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with("ENVIRONMENT  HOST"))
+            .stdout(predicate::str::contains(format!("local        {}  200     ", host)))
+            .stdout(predicate::str::ends_with("1 of 1 environments healthy\n"));
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_unhealthy_environments_when_ping_then_should_print_errors_and_fail() -> Result<(), Box<dyn Error>> {
+        let mut healthy = HEALTHY_SERVER_POOL.get_server();
+        healthy.expect(
+            Expectation::matching(request::path("/status")).respond_with(status_code(204)),
+        );
+        let mut unhealthy = UNHEALTHY_SERVER_POOL.get_server();
+        unhealthy.expect(
+            Expectation::matching(request::path("/status")).respond_with(status_code(503)),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("staging")
+                            .with_host(healthy.addr().to_string().as_str())
+                    )
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("production")
+                            .with_host(unhealthy.addr().to_string().as_str())
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("status")
+                            .with_path("/status")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("ping")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("status")
+            .assert()
+            .failure()
+            .stdout(predicate::str::is_match(r"\nstaging     [^\n]* 204 +\d+ms\n")?)
+            .stdout(predicate::str::is_match(r"\nproduction  [^\n]* 503 +\d+ms +Expected status `2xx` but was 503\n")?)
+            .stdout(predicate::str::ends_with("1 of 2 environments unhealthy\n"));
+
+        clear_config(&path);
+        healthy.verify_and_clear();
+        unhealthy.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_no_health_endpoint_when_ping_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("local")
+                            .with_host("foo.com")
+                    )
+                    .with_endpoint(get_health_endpoint())
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("ping")
+            .arg("foo_service")
+            .assert()
+            .failure()
+            .stdout("No health endpoint set for service `foo_service`, set one with `htrs edit service foo_service --health-endpoint <endpoint>` or use `--endpoint`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_har_option_when_ping_then_should_record_each_environment_with_redacted_secrets() -> Result<(), Box<dyn Error>> {
+        let mut server = HEALTHY_SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/health")).respond_with(status_code(200)),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_header("Authorization", "Bearer secret")
+            .with_secret_header("authorization")
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_health_endpoint("health")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("local")
+                            .with_host(server.addr().to_string().as_str())
+                    )
+                    .with_endpoint(get_health_endpoint())
+            )
+            .build();
+        let path = setup(Some(config));
+        let har_path = format!("{}.har", Uuid::new_v4());
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("ping")
+            .arg("foo_service")
+            .arg("--har")
+            .arg(&har_path)
+            .arg("--redact-secrets")
+            .assert()
+            .success();
+
+        let har: serde_json::Value = serde_json::from_reader(File::open(&har_path)?)?;
+        let entries = har["log"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["response"]["status"], 200);
+        let authorization = entries[0]["request"]["headers"].as_array().unwrap().iter()
+            .find(|h| h["name"] == "authorization")
+            .unwrap();
+        assert_eq!(authorization["value"], "<redacted>");
+
+        clear_config(&path);
+        clear_config(&har_path);
+        server.verify_and_clear();
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+mod list_preset_tests {
+    use crate::common::builders::{HtrsConfigBuilder, PresetBuilder};
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use rstest::rstest;
+    use std::error::Error;
+
+    #[test]
+    fn given_no_presets_when_list_then_should_print_none_found() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("preset")
+            .assert()
+            .success()
+            .stdout("No presets found\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(None, " - foo_preset (fp)\n - bar_preset\n")]
+    #[case(Some("fp"), " - foo_preset (fp)\n")]
+    #[case(Some("bar"), " - bar_preset\n")]
+    #[case(Some("customer_id"), " - bar_preset\n")]
+    #[case(Some("unknown"), "No presets found with name, alias or value key containing `unknown`\n")]
+    fn given_presets_when_list_with_filter_then_should_print_matching(#[case] filter: Option<&str>, #[case] expected: &str) -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_preset(
+                PresetBuilder::new()
+                    .with_name("foo_preset")
+                    .with_alias("fp")
+                    .with_value("order_id", "1")
+            )
+            .with_preset(
+                PresetBuilder::new()
+                    .with_name("bar_preset")
+                    .with_value("customer_id", "2")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        let mut command = Command::cargo_bin("htrs")?;
+        command.env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("preset");
+        if let Some(filter) = filter {
+            command.arg("--filter").arg(filter);
+        }
+        command.assert()
+            .success()
+            .stdout(expected.to_string());
+
+        clear_config(&path);
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 mod create_new_preset_tests;
 mod delete_preset_tests;
 mod edit_preset_tests;
-mod view_preset_tests;
\ No newline at end of file
+mod view_preset_tests;
+mod list_preset_tests;
\ No newline at end of file
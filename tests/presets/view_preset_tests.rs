@@ -77,4 +77,31 @@ mod view_preset_tests {
         clear_config(&path);
         Ok(())
     }
+
+    #[test]
+    pub fn given_known_preset_when_view_with_yaml_format_then_should_print_yaml() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_preset(
+                PresetBuilder::new()
+                    .with_name("foo_preset")
+                    .with_value("b_key", "b")
+                    .with_value("a_key", "a")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("view")
+            .arg("preset")
+            .arg("foo_preset")
+            .arg("--format")
+            .arg("yaml")
+            .assert()
+            .success()
+            .stdout("alias: null\nname: foo_preset\nvalues:\n  a_key: a\n  b_key: b\n");
+
+        clear_config(&path);
+        Ok(())
+    }
 }
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod copy_service_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, get_config, setup};
+    use assert_cmd::Command;
+    use std::error::Error;
+
+    #[test]
+    fn given_unknown_service_when_copy_service_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("copy")
+            .arg("service")
+            .arg("foo_service")
+            .arg("bar_service")
+            .assert()
+            .failure()
+            .stdout("No service found with name or alias `foo_service`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_existing_service_name_when_copy_service_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+            )
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("bar_service")
+                    .with_alias("bar")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("copy")
+            .arg("service")
+            .arg("foo_service")
+            .arg("bar")
+            .assert()
+            .failure()
+            .stdout("A service already exists with the name or alias 'bar'\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_known_service_when_copy_service_then_should_copy_everything_but_alias() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_alias("foo")
+                    .with_header("X-Foo", "foo")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                            .with_header("X-Environment", "bar")
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/foo")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("cp")
+            .arg("service")
+            .arg("foo")
+            .arg("bar_service")
+            .arg("--alias")
+            .arg("bar")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        assert_eq!(config.services.len(), 2);
+        let service = &config.services[1];
+        assert_eq!(service.name, "bar_service");
+        assert_eq!(service.alias, Some("bar".to_string()));
+        assert_eq!(service.headers.get("X-Foo"), Some(&"foo".to_string()));
+        assert_eq!(service.environments.len(), 1);
+        assert_eq!(service.environments[0].name, "foo_environment");
+        assert_eq!(service.environments[0].host, "foo.com");
+        assert!(service.environments[0].default);
+        assert_eq!(service.environments[0].headers.get("X-Environment"), Some(&"bar".to_string()));
+        assert_eq!(service.endpoints.len(), 1);
+        assert_eq!(service.endpoints[0].path_template, "/foo");
+        assert_eq!(config.services[0].alias, Some("foo".to_string()));
+
+        clear_config(&path);
+        Ok(())
+    }
+}
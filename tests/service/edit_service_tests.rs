@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod edit_service_tests {
-    use crate::common::builders::{HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::builders::{EndpointBuilder, HtrsConfigBuilder, ServiceBuilder};
     use crate::common::test_helpers::{clear_config, get_config, setup};
     use assert_cmd::Command;
     use std::error::Error;
@@ -97,4 +97,47 @@ mod edit_service_tests {
         clear_config(&path);
         Ok(())
     }
+
+    #[test]
+    fn given_edit_command_with_existing_endpoint_when_edit_health_endpoint_should_succeed() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("existing_name")
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("health")
+                            .with_alias("hc")
+                            .with_path("/health")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("edit")
+            .arg("service")
+            .arg("existing_name")
+            .arg("--health-endpoint")
+            .arg("hc")
+            .assert()
+            .success();
+
+        assert_eq!(get_config(&path).services[0].health_endpoint, Some("health".to_string()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("edit")
+            .arg("service")
+            .arg("existing_name")
+            .arg("--health-endpoint")
+            .arg("status")
+            .assert()
+            .failure()
+            .stdout("No endpoint could be found with name `status` for service `existing_name`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
 }
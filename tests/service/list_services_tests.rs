@@ -105,4 +105,53 @@ mod list_services_tests {
         clear_config(&path);
         Ok(())
     }
+
+    #[test]
+    fn given_list_services_command_with_json_format_then_should_print_services_as_json() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("service1")
+                    .with_alias("alias1")
+            )
+            .build();
+        let path = setup(Some(config));
+
+        let output = Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("service")
+            .arg("--format")
+            .arg("json")
+            .output()?;
+        assert!(output.status.success());
+
+        let services: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        assert_eq!(services[0]["name"], "service1");
+        assert_eq!(services[0]["alias"], "alias1");
+        assert_eq!(services[0]["environments"], serde_json::json!([]));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_list_services_command_with_json_format_and_no_matches_then_should_print_empty_list() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("--format")
+            .arg("json")
+            .arg("service")
+            .arg("--filter")
+            .arg("foo")
+            .assert()
+            .success()
+            .stdout("[]\n");
+
+        clear_config(&path);
+        Ok(())
+    }
 }
@@ -2,4 +2,4 @@ mod create_new_service_tests;
 mod delete_service_tests;
 mod edit_service_tests;
 mod list_services_tests;
-mod view_service_tests;
\ No newline at end of file
+mod view_service_tests;mod copy_service_tests;
@@ -1,4 +1,4 @@
-
+#[allow(dead_code)]
 mod common;
 
 #[cfg(test)]
@@ -301,4 +301,51 @@ mod set_header_tests {
         clear_config(&path);
         Ok(())
     }
+
+    #[test]
+    fn given_set_header_with_secret_flag_then_should_mark_header_as_secret() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("set")
+            .arg("header")
+            .arg("Authorization")
+            .arg("Bearer token")
+            .arg("--secret")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        assert_eq!(config.headers.get("Authorization"), Some(&"Bearer token".to_string()));
+        assert_eq!(config.secret_headers, vec!["Authorization".to_string()]);
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_secret_header_when_set_header_with_no_secret_flag_then_should_unmark_header() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_header("Authorization", "Bearer token")
+            .with_secret_header("authorization")
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("set")
+            .arg("header")
+            .arg("Authorization")
+            .arg("--no-secret")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        assert_eq!(config.headers.get("Authorization"), Some(&"Bearer token".to_string()));
+        assert!(config.secret_headers.is_empty());
+
+        clear_config(&path);
+        Ok(())
+    }
 }
@@ -0,0 +1 @@
+mod shell_tests;
@@ -0,0 +1,80 @@
+mod shell_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder};
+    use crate::common::test_helpers::{clear_config, get_config, setup};
+    use assert_cmd::Command;
+    use std::error::Error;
+
+    #[test]
+    fn given_service_and_environment_in_use_when_endpoint_entered_then_should_call_endpoint() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("foo_service")
+                    .with_alias("fs")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("foo_environment")
+                            .with_host("foo.com")
+                            .with_default()
+                    )
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("bar_environment")
+                            .with_host("bar.com")
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("foo_endpoint")
+                            .with_path("/orders/{id}")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("shell")
+            .write_stdin("use fs\nenv bar_environment\nfoo_endpoint --id 1 --as-curl\nexit\n")
+            .assert()
+            .success()
+            .stdout(format!("curl \\\n  'http://bar.com/orders/1' \\\n  -H 'User-Agent: htrs/{}'\n", env!("CARGO_PKG_VERSION")));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_config_command_when_entered_then_should_save_config() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("shell")
+            .write_stdin("new service foo_service\nuse foo_service\nlist service\n")
+            .assert()
+            .success()
+            .stdout(" - foo_service\n");
+
+        let config = get_config(&path);
+        assert_eq!(config.services[0].name, "foo_service");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_unknown_service_when_use_then_should_print_error_and_continue() -> Result<(), Box<dyn Error>> {
+        let path = setup(None);
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("shell")
+            .write_stdin("use foo_service\nenv foo_environment\n")
+            .assert()
+            .success()
+            .stdout("No service could be found with name or alias `foo_service`\nNo service in use, select one with `use <service>`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+}
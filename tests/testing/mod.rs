@@ -0,0 +1,2 @@
+mod new_test_tests;
+mod test_command_tests;
@@ -0,0 +1,258 @@
+mod new_test_tests {
+    use crate::common::builders::{EndpointBuilder, HtrsConfigBuilder, ServiceBuilder, TestCaseBuilder};
+    use crate::common::config::Assertion;
+    use crate::common::test_helpers::{clear_config, get_config, setup};
+    use assert_cmd::Command;
+    use serde_json::json;
+    use std::error::Error;
+
+    fn get_service_config() -> ServiceBuilder {
+        ServiceBuilder::new()
+            .with_name("foo_service")
+            .with_endpoint(
+                EndpointBuilder::new()
+                    .with_name("get_user")
+                    .with_path("/users/{id}")
+                    .with_query_param("expand", false)
+            )
+    }
+
+    #[test]
+    fn given_expectations_when_new_test_then_should_add_test_to_service() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(get_service_config())
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("test")
+            .arg("user_ok")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("get_user")
+            .arg("--param")
+            .arg("id=1")
+            .arg("--expect-status")
+            .arg("2xx")
+            .arg("--expect-header")
+            .arg("Content-Type=application/json")
+            .arg("--expect-json")
+            .arg("$.id=1")
+            .arg("--expect-json")
+            .arg("$.name=foo")
+            .arg("--expect-json-exists")
+            .arg("$.roles")
+            .arg("--expect-json-matches")
+            .arg("$.email=@example.com$")
+            .arg("--max-latency")
+            .arg("1s")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let test = &config.services[0].tests[0];
+        assert_eq!(test.name, "user_ok");
+        assert_eq!(test.endpoint, "get_user");
+        assert_eq!(test.parameters.get("id"), Some(&"1".to_string()));
+        assert_eq!(test.assertions, vec![
+            Assertion::Status { expected: "2xx".to_string() },
+            Assertion::Header { name: "Content-Type".to_string(), value: Some("application/json".to_string()) },
+            Assertion::JsonEquals { path: "$.id".to_string(), value: json!(1) },
+            Assertion::JsonEquals { path: "$.name".to_string(), value: json!("foo") },
+            Assertion::JsonExists { path: "$.roles".to_string() },
+            Assertion::JsonMatches { path: "$.email".to_string(), pattern: "@example.com$".to_string() },
+            Assertion::MaxLatency { milliseconds: 1000 },
+        ]);
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_invalid_status_when_new_test_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(get_service_config())
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("test")
+            .arg("user_ok")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("get_user")
+            .arg("--expect-status")
+            .arg("ok")
+            .assert()
+            .failure()
+            .stdout("Invalid status `ok`, should be a code like `200`, a class like `2xx` or a range like `200-299`\n");
+
+        assert!(get_config(&path).services[0].tests.is_empty());
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_unknown_parameter_when_new_test_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(get_service_config())
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("test")
+            .arg("user_ok")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("get_user")
+            .arg("--param")
+            .arg("limit=1")
+            .assert()
+            .failure()
+            .stdout("Endpoint `get_user` has no path or query parameter named `limit`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_existing_test_name_when_new_test_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                get_service_config()
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("user_ok")
+                            .with_endpoint("get_user")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("test")
+            .arg("user_ok")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--endpoint")
+            .arg("get_user")
+            .assert()
+            .failure()
+            .stdout("Service `foo_service` already has a test named `user_ok`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_existing_test_when_delete_test_then_should_remove_test() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                get_service_config()
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("user_ok")
+                            .with_endpoint("get_user")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("delete")
+            .arg("test")
+            .arg("user_ok")
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .success();
+
+        assert!(get_config(&path).services[0].tests.is_empty());
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_tests_when_list_test_then_should_print_tests_and_assertions() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                get_service_config()
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("user_ok")
+                            .with_endpoint("get_user")
+                            .with_assertion(Assertion::Status { expected: "200".to_string() })
+                            .with_assertion(Assertion::JsonExists { path: "$.id".to_string() })
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("test")
+            .arg("--service")
+            .arg("foo_service")
+            .assert()
+            .success()
+            .stdout(" - user_ok (get_user)\n    status is `200`\n    json `$.id` exists\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_tests_when_list_test_with_json_format_then_should_print_assertions_as_text() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                get_service_config()
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("user_ok")
+                            .with_endpoint("get_user")
+                            .with_parameter("id", "1")
+                            .with_assertion(Assertion::Status { expected: "200".to_string() })
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        let output = Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("test")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--format")
+            .arg("json")
+            .output()?;
+        assert!(output.status.success());
+
+        let tests: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        assert_eq!(tests, json!([{
+            "name": "user_ok",
+            "endpoint": "get_user",
+            "preset": null,
+            "parameters": { "id": "1" },
+            "assertions": ["status is `200`"],
+        }]));
+
+        clear_config(&path);
+        Ok(())
+    }
+}
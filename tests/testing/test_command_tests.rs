@@ -0,0 +1,254 @@
+mod test_command_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder, TestCaseBuilder};
+    use crate::common::config::Assertion;
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use httptest::matchers::request;
+    use httptest::responders::status_code;
+    use httptest::{all_of, Expectation, ServerPool};
+    use predicates::prelude::*;
+    use serde_json::json;
+    use std::error::Error;
+    use std::fs;
+    use uuid::Uuid;
+
+    static SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    fn get_service_config(host: &str) -> ServiceBuilder {
+        ServiceBuilder::new()
+            .with_name("foo_service")
+            .with_environment(
+                EnvironmentBuilder::new()
+                    .with_name("local")
+                    .with_host(host)
+                    .with_default()
+            )
+            .with_endpoint(
+                EndpointBuilder::new()
+                    .with_name("get_user")
+                    .with_path("/users/{id}")
+            )
+    }
+
+    #[test]
+    fn given_passing_tests_when_test_then_should_print_summary() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("GET"),
+                request::path("/users/1"),
+            ]).respond_with(
+                status_code(200)
+                    .insert_header("Content-Type", "application/json")
+                    .body(r#"{"id":1,"name":"foo"}"#)
+            ),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                get_service_config(server.addr().to_string().as_str())
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("user_ok")
+                            .with_endpoint("get_user")
+                            .with_parameter("id", "1")
+                            .with_assertion(Assertion::Status { expected: "2xx".to_string() })
+                            .with_assertion(Assertion::Header { name: "content-type".to_string(), value: None })
+                            .with_assertion(Assertion::JsonEquals { path: "$.name".to_string(), value: json!("foo") })
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("test")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(" PASS foo_service user_ok ("))
+            .stdout(predicate::str::ends_with("1 test, 1 passed, 0 failed\n"));
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_failing_tests_when_test_then_should_fail_and_write_junit_report() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("GET"),
+                request::path("/users/1"),
+            ]).respond_with(
+                status_code(500).body(r#"{"error":"oops"}"#)
+            ),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                get_service_config(server.addr().to_string().as_str())
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("user_ok")
+                            .with_endpoint("get_user")
+                            .with_parameter("id", "1")
+                            .with_assertion(Assertion::Status { expected: "200".to_string() })
+                            .with_assertion(Assertion::JsonExists { path: "$.id".to_string() })
+                    )
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("missing_parameter")
+                            .with_endpoint("get_user")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+        let report = format!("{}.xml", Uuid::new_v4());
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("test")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--junit")
+            .arg(&report)
+            .assert()
+            .failure()
+            .stdout(predicate::str::contains(
+                "   - Expected status `200` but was 500
+   - Expected json `$.id` to exist
+ ERROR foo_service missing_parameter
+   - Parameter `id` is required but not provided from parameters
+"))
+            .stdout(predicate::str::ends_with("2 tests, 0 passed, 2 failed\n"));
+
+        let report_contents = fs::read_to_string(&report)?;
+        assert!(report_contents.contains(r#"<testsuites name="htrs" tests="2" failures="1" errors="1""#));
+        assert!(report_contents.contains(r#"<failure message="Expected status `200` but was 500">"#));
+        assert!(report_contents.contains(r#"<error message="Parameter `id` is required but not provided from parameters" />"#));
+
+        fs::remove_file(&report)?;
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_test_for_deleted_endpoint_when_test_then_should_report_error() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                get_service_config("foo.com")
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("deleted_ok")
+                            .with_endpoint("deleted_endpoint")
+                            .with_assertion(Assertion::Status { expected: "2xx".to_string() })
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("test")
+            .assert()
+            .failure()
+            .stdout(" ERROR foo_service deleted_ok
+   - No endpoint could be found with name `deleted_endpoint` for service `foo_service`
+1 test, 0 passed, 1 failed
+");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_unknown_environment_when_test_service_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(get_service_config("foo.com"))
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("test")
+            .arg("--service")
+            .arg("foo_service")
+            .arg("--environment")
+            .arg("production")
+            .assert()
+            .failure()
+            .stdout("Unable to find environment with name or alias `production` for service `foo_service`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_no_tests_when_test_then_should_print_message() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(get_service_config("foo.com"))
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("test")
+            .assert()
+            .success()
+            .stdout("No tests defined\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_har_option_when_test_then_should_record_each_test() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/users/1")).respond_with(status_code(200).body(r#"{"id":1}"#)),
+        );
+        server.expect(
+            Expectation::matching(request::path("/users/2")).respond_with(status_code(404)),
+        );
+        let config = HtrsConfigBuilder::new()
+            .with_service(
+                get_service_config(server.addr().to_string().as_str())
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("user_ok")
+                            .with_endpoint("get_user")
+                            .with_parameter("id", "1")
+                            .with_assertion(Assertion::Status { expected: "200".to_string() })
+                    )
+                    .with_test(
+                        TestCaseBuilder::new()
+                            .with_name("user_missing")
+                            .with_endpoint("get_user")
+                            .with_parameter("id", "2")
+                            .with_assertion(Assertion::Status { expected: "200".to_string() })
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+        let har_path = format!("{}.har", Uuid::new_v4());
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("test")
+            .arg("--har")
+            .arg(&har_path)
+            .assert()
+            .failure();
+
+        let har: serde_json::Value = serde_json::from_reader(fs::File::open(&har_path)?)?;
+        let entries = har["log"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["response"]["status"], 200);
+        assert_eq!(entries[1]["response"]["status"], 404);
+
+        clear_config(&path);
+        clear_config(&har_path);
+        server.verify_and_clear();
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+mod new_workflow_tests;
+mod run_workflow_tests;
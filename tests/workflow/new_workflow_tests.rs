@@ -0,0 +1,266 @@
+mod new_workflow_tests {
+    use crate::common::builders::{EndpointBuilder, HtrsConfigBuilder, ServiceBuilder, WorkflowBuilder, WorkflowStepBuilder};
+    use crate::common::config::Extraction;
+    use crate::common::test_helpers::{clear_config, get_config, setup};
+    use assert_cmd::Command;
+    use std::error::Error;
+
+    fn get_service_config() -> ServiceBuilder {
+        ServiceBuilder::new()
+            .with_name("orders")
+            .with_endpoint(
+                EndpointBuilder::new()
+                    .with_name("create_order")
+                    .with_path("/orders")
+                    .with_method("POST")
+            )
+            .with_endpoint(
+                EndpointBuilder::new()
+                    .with_name("get_order")
+                    .with_path("/orders/{id}")
+            )
+    }
+
+    fn get_create_step() -> WorkflowStepBuilder {
+        WorkflowStepBuilder::new()
+            .with_name("create")
+            .with_service("orders")
+            .with_endpoint("create_order")
+            .with_extraction(Extraction::Json { name: "order_id".to_string(), path: "$.id".to_string() })
+    }
+
+    #[test]
+    fn given_new_workflow_when_new_step_then_should_add_steps_in_order() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(get_service_config())
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("workflow")
+            .arg("order_lifecycle")
+            .assert()
+            .success();
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("step")
+            .arg("create")
+            .arg("--workflow")
+            .arg("order_lifecycle")
+            .arg("--service")
+            .arg("orders")
+            .arg("--endpoint")
+            .arg("create_order")
+            .arg("--extract")
+            .arg("order_id=$.id")
+            .arg("--extract")
+            .arg("location=header:Location")
+            .arg("--expect-status")
+            .arg("201")
+            .assert()
+            .success();
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("step")
+            .arg("fetch")
+            .arg("--workflow")
+            .arg("order_lifecycle")
+            .arg("--service")
+            .arg("orders")
+            .arg("--endpoint")
+            .arg("get_order")
+            .arg("--param")
+            .arg("id={{order_id}}")
+            .assert()
+            .success();
+
+        let config = get_config(&path);
+        let steps = &config.workflows[0].steps;
+        assert_eq!(config.workflows[0].name, "order_lifecycle");
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].name, "create");
+        assert_eq!(steps[0].expected_status, Some("201".to_string()));
+        assert_eq!(steps[0].extractions, vec![
+            Extraction::Json { name: "order_id".to_string(), path: "$.id".to_string() },
+            Extraction::Header { name: "location".to_string(), header: "Location".to_string() },
+        ]);
+        assert_eq!(steps[1].name, "fetch");
+        assert_eq!(steps[1].parameters.get("id"), Some(&"{{order_id}}".to_string()));
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_reference_to_value_not_taken_when_new_step_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(get_service_config())
+            .with_workflow(
+                WorkflowBuilder::new()
+                    .with_name("order_lifecycle")
+                    .with_step(get_create_step())
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("step")
+            .arg("fetch")
+            .arg("--workflow")
+            .arg("order_lifecycle")
+            .arg("--service")
+            .arg("orders")
+            .arg("--endpoint")
+            .arg("get_order")
+            .arg("--param")
+            .arg("id={{user_id}}")
+            .assert()
+            .failure()
+            .stdout("No value named `user_id` is taken by an earlier step of workflow `order_lifecycle`\n");
+
+        assert_eq!(get_config(&path).workflows[0].steps.len(), 1);
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_invalid_extraction_when_new_step_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(get_service_config())
+            .with_workflow(WorkflowBuilder::new().with_name("order_lifecycle"))
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("new")
+            .arg("step")
+            .arg("create")
+            .arg("--workflow")
+            .arg("order_lifecycle")
+            .arg("--service")
+            .arg("orders")
+            .arg("--endpoint")
+            .arg("create_order")
+            .arg("--extract")
+            .arg("$.id")
+            .assert()
+            .failure()
+            .stdout("Invalid extraction `$.id`, should be in format `name=$.json.path` or `name=header:Header-Name`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_step_whose_value_is_used_later_when_delete_step_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(get_service_config())
+            .with_workflow(
+                WorkflowBuilder::new()
+                    .with_name("order_lifecycle")
+                    .with_step(get_create_step())
+                    .with_step(
+                        WorkflowStepBuilder::new()
+                            .with_name("fetch")
+                            .with_service("orders")
+                            .with_endpoint("get_order")
+                            .with_parameter("id", "{{order_id}}")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("delete")
+            .arg("step")
+            .arg("create")
+            .arg("--workflow")
+            .arg("order_lifecycle")
+            .assert()
+            .failure()
+            .stdout("Step `fetch` uses a value taken by step `create`\n");
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("delete")
+            .arg("step")
+            .arg("fetch")
+            .arg("--workflow")
+            .arg("order_lifecycle")
+            .assert()
+            .success();
+
+        assert_eq!(get_config(&path).workflows[0].steps.len(), 1);
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_workflows_when_list_workflow_then_should_print_steps() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(get_service_config())
+            .with_workflow(
+                WorkflowBuilder::new()
+                    .with_name("order_lifecycle")
+                    .with_step(get_create_step())
+                    .with_step(
+                        WorkflowStepBuilder::new()
+                            .with_name("fetch")
+                            .with_service("orders")
+                            .with_endpoint("get_order")
+                            .with_parameter("id", "{{order_id}}")
+                    )
+            )
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("list")
+            .arg("workflow")
+            .assert()
+            .success()
+            .stdout(" - order_lifecycle
+    1. create: orders create_order
+       order_id = json `$.id`
+    2. fetch: orders get_order (id={{order_id}})
+");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_existing_workflow_when_delete_workflow_then_should_remove_workflow() -> Result<(), Box<dyn Error>> {
+        let config = HtrsConfigBuilder::new()
+            .with_service(get_service_config())
+            .with_workflow(WorkflowBuilder::new().with_name("order_lifecycle"))
+            .build();
+        let path = setup(Some(config));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("delete")
+            .arg("workflow")
+            .arg("order_lifecycle")
+            .assert()
+            .success();
+
+        assert!(get_config(&path).workflows.is_empty());
+
+        clear_config(&path);
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+mod run_workflow_tests {
+    use crate::common::builders::{EndpointBuilder, EnvironmentBuilder, HtrsConfigBuilder, ServiceBuilder, WorkflowBuilder, WorkflowStepBuilder};
+    use crate::common::config::Extraction;
+    use crate::common::test_helpers::{clear_config, setup};
+    use assert_cmd::Command;
+    use httptest::matchers::{request, url_decoded};
+    use httptest::responders::status_code;
+    use httptest::{all_of, Expectation, ServerPool};
+    use predicates::prelude::*;
+    use std::error::Error;
+    use std::fs::File;
+    use uuid::Uuid;
+
+    static SERVER_POOL: ServerPool = ServerPool::new(1);
+
+    fn get_config(host: &str) -> HtrsConfigBuilder {
+        HtrsConfigBuilder::new()
+            .with_service(
+                ServiceBuilder::new()
+                    .with_name("orders")
+                    .with_environment(
+                        EnvironmentBuilder::new()
+                            .with_name("local")
+                            .with_host(host)
+                            .with_default()
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("create_order")
+                            .with_path("/orders")
+                            .with_method("POST")
+                    )
+                    .with_endpoint(
+                        EndpointBuilder::new()
+                            .with_name("cancel_order")
+                            .with_path("/orders/{id}/cancel")
+                            .with_method("POST")
+                            .with_query_param("reason", false)
+                    )
+            )
+            .with_workflow(
+                WorkflowBuilder::new()
+                    .with_name("order_lifecycle")
+                    .with_step(
+                        WorkflowStepBuilder::new()
+                            .with_name("create")
+                            .with_service("orders")
+                            .with_endpoint("create_order")
+                            .with_expected_status("201")
+                            .with_extraction(Extraction::Json { name: "order_id".to_string(), path: "$.id".to_string() })
+                            .with_extraction(Extraction::Header { name: "trace".to_string(), header: "X-Trace".to_string() })
+                    )
+                    .with_step(
+                        WorkflowStepBuilder::new()
+                            .with_name("cancel")
+                            .with_service("orders")
+                            .with_endpoint("cancel_order")
+                            .with_parameter("id", "{{order_id}}")
+                            .with_parameter("reason", "trace-{{trace}}")
+                    )
+            )
+    }
+
+    #[test]
+    fn given_workflow_when_run_then_should_pass_values_between_steps() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("POST"),
+                request::path("/orders"),
+            ]).respond_with(
+                status_code(201)
+                    .insert_header("X-Trace", "abc")
+                    .body(r#"{"id":42}"#)
+            ),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("POST"),
+                request::path("/orders/42/cancel"),
+                request::query(url_decoded(httptest::matchers::contains(("reason", "trace-abc")))),
+            ]).respond_with(status_code(204)),
+        );
+        let path = setup(Some(get_config(server.addr().to_string().as_str()).build()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("run")
+            .arg("order_lifecycle")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("[1/2] create: POST http://"))
+            .stdout(predicate::str::contains("/orders -> 201 ("))
+            .stdout(predicate::str::contains("   order_id = 42\n   trace = abc\n[2/2] cancel: POST http://"))
+            .stdout(predicate::str::contains("/orders/42/cancel?reason=trace-abc -> 204 ("))
+            .stdout(predicate::str::ends_with("Workflow `order_lifecycle` completed 2 steps\n"));
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_failing_step_when_run_then_should_stop_workflow() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("POST"),
+                request::path("/orders"),
+            ]).respond_with(
+                status_code(400).body(r#"{"error":"out of stock"}"#)
+            ),
+        );
+        let path = setup(Some(get_config(server.addr().to_string().as_str()).build()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("run")
+            .arg("order_lifecycle")
+            .assert()
+            .failure()
+            .stdout(predicate::str::contains("/orders -> 400 ("))
+            .stdout(predicate::str::ends_with(r#"{"error":"out of stock"}
+Workflow `order_lifecycle` failed at step `create`: Expected status `201` but was 400
+"#));
+
+        clear_config(&path);
+        server.verify_and_clear();
+        Ok(())
+    }
+
+    #[test]
+    fn given_unknown_environment_when_run_then_should_fail_before_any_step() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(get_config("foo.com").build()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("run")
+            .arg("order_lifecycle")
+            .arg("--environment")
+            .arg("production")
+            .assert()
+            .failure()
+            .stdout("Unable to find environment with name or alias `production` for service `orders`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_unknown_workflow_when_run_then_should_fail() -> Result<(), Box<dyn Error>> {
+        let path = setup(Some(get_config("foo.com").build()));
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("run")
+            .arg("checkout")
+            .assert()
+            .failure()
+            .stdout("No workflow could be found with name `checkout`\n");
+
+        clear_config(&path);
+        Ok(())
+    }
+
+    #[test]
+    fn given_har_option_when_step_fails_then_should_record_steps_run() -> Result<(), Box<dyn Error>> {
+        let mut server = SERVER_POOL.get_server();
+        server.expect(
+            Expectation::matching(request::path("/orders")).respond_with(status_code(500).body("unavailable")),
+        );
+        let path = setup(Some(get_config(server.addr().to_string().as_str()).build()));
+        let har_path = format!("{}.har", Uuid::new_v4());
+
+        Command::cargo_bin("htrs")?
+            .env("HTRS_CONFIG_PATH", &path)
+            .arg("run")
+            .arg("order_lifecycle")
+            .arg("--har")
+            .arg(&har_path)
+            .assert()
+            .failure();
+
+        let har: serde_json::Value = serde_json::from_reader(File::open(&har_path)?)?;
+        let entries = har["log"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["request"]["method"], "POST");
+        assert_eq!(entries[0]["response"]["status"], 500);
+        assert_eq!(entries[0]["response"]["content"]["text"], "unavailable");
+
+        clear_config(&path);
+        clear_config(&har_path);
+        server.verify_and_clear();
+        Ok(())
+    }
+}